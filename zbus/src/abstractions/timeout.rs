@@ -0,0 +1,27 @@
+//! Runtime-agnostic timeout abstraction.
+
+use std::{future::Future, time::Duration};
+
+/// Await `future` for at most `duration`.
+///
+/// Returns `None` if `future` didn't resolve in time.
+pub(crate) async fn timeout<F, T>(duration: Duration, future: F) -> Option<T>
+where
+    F: Future<Output = T>,
+{
+    #[cfg(not(feature = "tokio"))]
+    {
+        use futures_util::future::{select, Either};
+
+        let future = std::pin::pin!(future);
+        match select(future, async_io::Timer::after(duration)).await {
+            Either::Left((output, _)) => Some(output),
+            Either::Right(_) => None,
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    {
+        tokio::time::timeout(duration, future).await.ok()
+    }
+}
@@ -1,8 +1,15 @@
+use std::time::Duration;
+
 use static_assertions::assert_impl_all;
-use zbus_names::{BusName, InterfaceName};
+use zbus_names::{BusName, InterfaceName, UniqueName};
 use zvariant::ObjectPath;
 
-use crate::{blocking::Connection, proxy::CacheProperties, utils::block_on, Error, Result};
+use crate::{
+    blocking::Connection,
+    proxy::{CacheProperties, MetricsHook},
+    utils::block_on,
+    Error, Result,
+};
 
 pub use crate::proxy::ProxyDefault;
 
@@ -22,6 +29,14 @@ impl<'a, T> Builder<'a, T> {
         crate::proxy::Builder::destination(self.0, destination).map(Self)
     }
 
+    /// Don't set any destination, even if the proxy type has a default one.
+    ///
+    /// See [`crate::proxy::Builder::no_destination`] for details.
+    #[must_use]
+    pub fn no_destination(self) -> Self {
+        Self(self.0.no_destination())
+    }
+
     /// Set the proxy path.
     pub fn path<P>(self, path: P) -> Result<Self>
     where
@@ -52,6 +67,41 @@ impl<'a, T> Builder<'a, T> {
         Self(self.0.uncached_properties(properties))
     }
 
+    /// Set how long method calls wait for a reply.
+    ///
+    /// See [`crate::proxy::Builder::method_timeout`] for details.
+    #[must_use]
+    pub fn method_timeout(self, timeout: Duration) -> Self {
+        Self(self.0.method_timeout(timeout))
+    }
+
+    /// Set the hook to report method calls to.
+    ///
+    /// See [`crate::proxy::Builder::metrics_hook`] for details.
+    #[must_use]
+    pub fn metrics_hook(self, hook: MetricsHook) -> Self {
+        Self(self.0.metrics_hook(hook))
+    }
+
+    /// Set whether to cache the introspection XML description.
+    ///
+    /// See [`crate::proxy::Builder::cache_introspection`] for details.
+    #[must_use]
+    pub fn cache_introspection(self, cache: bool) -> Self {
+        Self(self.0.cache_introspection(cache))
+    }
+
+    /// Only accept signals and method replies from the given unique name.
+    ///
+    /// See [`crate::proxy::Builder::sender`] for details.
+    pub fn sender<S>(self, sender: S) -> Result<Self>
+    where
+        S: TryInto<UniqueName<'a>>,
+        S::Error: Into<Error>,
+    {
+        crate::proxy::Builder::sender(self.0, sender).map(Self)
+    }
+
     /// Build a proxy from the builder.
     ///
     /// # Panics
@@ -3,14 +3,14 @@
 use enumflags2::BitFlags;
 use futures_util::StreamExt;
 use static_assertions::assert_impl_all;
-use std::{fmt, ops::Deref};
-use zbus_names::{BusName, InterfaceName, MemberName, UniqueName};
-use zvariant::{ObjectPath, OwnedValue, Value};
+use std::{collections::HashMap, fmt, ops::Deref, time::Duration};
+use zbus_names::{BusName, InterfaceName, MemberName, OwnedUniqueName, UniqueName};
+use zvariant::{serialized::ArrayIter, ObjectPath, OwnedValue, Value};
 
 use crate::{
     blocking::Connection,
     message::Message,
-    proxy::{MethodFlags, ProxyDefault},
+    proxy::{CacheProperties, MethodFlags, MetricsHook, ProxyDefault},
     utils::block_on,
     Error, Result,
 };
@@ -143,27 +143,95 @@ impl<'a> Proxy<'a> {
     }
 
     /// Get a reference to the destination service name.
+    ///
+    /// # Panics
+    ///
+    /// If the proxy has no destination, i.e it was built with [`Builder::no_destination`]. Use
+    /// [`Proxy::destination_opt`] for such proxies.
+    ///
+    /// [`Builder::no_destination`]: crate::blocking::proxy::Builder::no_destination
     pub fn destination(&self) -> &BusName<'_> {
         self.inner().destination()
     }
 
+    /// Get a reference to the destination service name, if any.
+    ///
+    /// This is only `None` for proxies on peer-to-peer connections, that were built with
+    /// [`Builder::no_destination`].
+    ///
+    /// [`Builder::no_destination`]: crate::blocking::proxy::Builder::no_destination
+    pub fn destination_opt(&self) -> Option<&BusName<'_>> {
+        self.inner().destination_opt()
+    }
+
     /// Get a reference to the object path.
     pub fn path(&self) -> &ObjectPath<'_> {
         self.inner().path()
     }
 
+    /// Get a reference to the only sender signals and method replies are accepted from, if any.
+    ///
+    /// See [`Builder::sender`](crate::blocking::proxy::Builder::sender) for details.
+    pub fn sender(&self) -> Option<&UniqueName<'_>> {
+        self.inner().sender()
+    }
+
     /// Get a reference to the interface.
     pub fn interface(&self) -> &InterfaceName<'_> {
         self.inner().interface()
     }
 
+    /// The properties caching mode.
+    ///
+    /// See [`Builder::cache_properties`](crate::blocking::proxy::Builder::cache_properties) for
+    /// details.
+    pub fn cache_properties(&self) -> CacheProperties {
+        self.inner().cache_properties()
+    }
+
+    /// How long method calls wait for a reply, if limited.
+    ///
+    /// See [`Builder::method_timeout`](crate::blocking::proxy::Builder::method_timeout) for
+    /// details.
+    pub fn method_timeout(&self) -> Option<Duration> {
+        self.inner().method_timeout()
+    }
+
+    /// The hook method calls are reported to, if any.
+    ///
+    /// See [`Builder::metrics_hook`](crate::blocking::proxy::Builder::metrics_hook) for details.
+    pub fn metrics_hook(&self) -> Option<&MetricsHook> {
+        self.inner().metrics_hook()
+    }
+
+    /// Create a variant of this proxy, that waits for method replies for at most `timeout`.
+    ///
+    /// The returned proxy shares the connection, as well as the property cache, with `self`.
+    /// `self` itself is left unchanged.
+    pub fn with_timeout(&self, timeout: Duration) -> Proxy<'a> {
+        self.inner().with_timeout(timeout).into()
+    }
+
     /// Introspect the associated object, and return the XML description.
     ///
+    /// If introspection caching was enabled through [`Builder::cache_introspection`], only the
+    /// first call fetches the XML description, and the subsequent calls return the cached copy,
+    /// until [`Proxy::refresh_introspection`] is called.
+    ///
     /// See the [xml](xml/index.html) module for parsing the result.
+    ///
+    /// [`Builder::cache_introspection`]: crate::blocking::proxy::Builder::cache_introspection
     pub fn introspect(&self) -> fdo::Result<String> {
         block_on(self.inner().introspect())
     }
 
+    /// Introspect the associated object, bypassing the introspection cache.
+    ///
+    /// If introspection caching is enabled, the cache is updated with the fetched XML description.
+    pub fn refresh_introspection(&self) -> fdo::Result<String> {
+        block_on(self.inner().refresh_introspection())
+    }
+
     /// Get the cached value of the property `property_name`.
     ///
     /// This returns `None` if the property is not in the cache.  This could be because the cache
@@ -201,6 +269,40 @@ impl<'a> Proxy<'a> {
         block_on(self.inner().get_property(property_name))
     }
 
+    /// Get the property `property_name`, as the variant it's transported in.
+    ///
+    /// Unlike [`Proxy::get_property`], this never consults the cache and always calls the `Get`
+    /// method of the `org.freedesktop.DBus.Properties` interface. The returned value is a
+    /// [`Value::Value`], wrapping the value of the property as is.
+    pub fn get_property_variant(&self, property_name: &str) -> Result<OwnedValue> {
+        block_on(self.inner().get_property_variant(property_name))
+    }
+
+    /// Get the array property `property_name`, as an iterator over its elements.
+    ///
+    /// Unlike [`Proxy::get_property`], this never consults the cache and always calls the `Get`
+    /// method of the `org.freedesktop.DBus.Properties` interface. The elements are deserialized
+    /// from the reply only as the returned iterator is advanced, which avoids deserializing all of
+    /// them upfront for large arrays. Keep in mind that the iterator keeps the whole reply in
+    /// memory, until it's dropped.
+    pub fn get_property_iter<T>(
+        &self,
+        property_name: &str,
+    ) -> Result<ArrayIter<'static, 'static, T>>
+    where
+        T: serde::de::DeserializeOwned + zvariant::Type,
+    {
+        block_on(self.inner().get_property_iter(property_name))
+    }
+
+    /// Get all the properties of the interface.
+    ///
+    /// Unlike [`Proxy::get_property`], this never consults the cache. Effectively, call the
+    /// `GetAll` method of the `org.freedesktop.DBus.Properties` interface.
+    pub fn get_all_properties(&self) -> fdo::Result<HashMap<String, OwnedValue>> {
+        block_on(self.inner().get_all_properties())
+    }
+
     /// Set the property `property_name`.
     ///
     /// Effectively, call the `Set` method of the `org.freedesktop.DBus.Properties` interface.
@@ -211,6 +313,18 @@ impl<'a> Proxy<'a> {
         block_on(self.inner().set_property(property_name, value))
     }
 
+    /// Set the property `property_name`, then get its value back from the server.
+    ///
+    /// See [`crate::Proxy::set_property_confirmed`] for details.
+    pub fn set_property_confirmed<'t, T, R>(&self, property_name: &str, value: T) -> Result<R>
+    where
+        T: 't + Into<Value<'t>>,
+        R: TryFrom<OwnedValue>,
+        R::Error: Into<Error>,
+    {
+        block_on(self.inner().set_property_confirmed(property_name, value))
+    }
+
     /// Call a method and return the reply.
     ///
     /// Typically, you would want to use [`call`] method instead. Use this method if you need to
@@ -227,6 +341,28 @@ impl<'a> Proxy<'a> {
         block_on(self.inner().call_method(method_name, body))
     }
 
+    /// Call a method of another interface of the object and return the reply.
+    ///
+    /// See [`crate::Proxy::call_interface_method`] for details.
+    pub fn call_interface_method<'i, 'm, I, M, B>(
+        &self,
+        interface_name: I,
+        method_name: M,
+        body: &B,
+    ) -> Result<Message>
+    where
+        I: TryInto<InterfaceName<'i>>,
+        I::Error: Into<Error>,
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        block_on(
+            self.inner()
+                .call_interface_method(interface_name, method_name, body),
+        )
+    }
+
     /// Call a method and return the reply body.
     ///
     /// Use [`call_method`] instead if you need to deserialize the reply manually/separately.
@@ -278,6 +414,23 @@ impl<'a> Proxy<'a> {
         block_on(self.inner().call_noreply(method_name, body))
     }
 
+    /// Queue a method call, not expecting a reply, to be sent on the next [`Proxy::flush`].
+    ///
+    /// See [`crate::Proxy::call_pipelined`] for details, including the ordering guarantees.
+    pub fn call_pipelined<'m, M, B>(&self, method_name: M, body: &B) -> Result<()>
+    where
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        self.inner().call_pipelined(method_name, body)
+    }
+
+    /// Send all the method calls queued by [`Proxy::call_pipelined`].
+    pub fn flush(&self) -> Result<()> {
+        block_on(self.inner().flush())
+    }
+
     /// Create a stream for signal named `signal_name`.
     ///
     /// # Errors
@@ -328,6 +481,19 @@ impl<'a> Proxy<'a> {
             .map(SignalIterator)
     }
 
+    /// Subscribe to the next signal named `signal_name` emitted by this service.
+    ///
+    /// See [`crate::Proxy::next_signal`] for details.
+    pub fn next_signal<'m, M>(&self, signal_name: M) -> Result<NextSignal>
+    where
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+    {
+        block_on(self.inner().next_signal(signal_name))
+            .map(Some)
+            .map(NextSignal)
+    }
+
     /// Get an iterator to receive owner changed events.
     ///
     /// If the proxy destination is a unique name, the stream will be notified of the peer
@@ -354,6 +520,20 @@ impl<'a> Proxy<'a> {
         block_on(self.inner().receive_owner_changed()).map(OwnerChangedIterator)
     }
 
+    /// The unique name of the connection currently owning the destination, if any.
+    ///
+    /// See [`crate::Proxy::owner`] for details.
+    pub fn owner(&self) -> Result<Option<OwnedUniqueName>> {
+        block_on(self.inner().owner())
+    }
+
+    /// Wait for the destination to be owned by a peer on the bus.
+    ///
+    /// See [`crate::Proxy::wait_for_owner`] for details.
+    pub fn wait_for_owner(&self, timeout: Duration) -> Result<()> {
+        block_on(self.inner().wait_for_owner(timeout))
+    }
+
     /// Get a reference to the underlying async Proxy.
     pub fn inner(&self) -> &crate::Proxy<'a> {
         self.azync.as_ref().expect("Inner proxy is `None`")
@@ -427,6 +607,47 @@ impl std::ops::Drop for SignalIterator<'_> {
     }
 }
 
+/// A subscription to the next signal of a given name.
+///
+/// Use [`Proxy::next_signal`] to create an instance of this type. See
+/// [`crate::proxy::NextSignal`] for details.
+#[derive(Debug)]
+pub struct NextSignal<T = Message>(Option<crate::proxy::NextSignal<T>>);
+
+assert_impl_all!(NextSignal: Send, Sync, Unpin);
+
+impl<T> NextSignal<T> {
+    /// Yield the decoded arguments of the signal, rather than the message.
+    ///
+    /// Multiple arguments are decoded as a tuple.
+    pub fn args<A>(mut self) -> NextSignal<A>
+    where
+        A: serde::de::DeserializeOwned + zvariant::Type,
+    {
+        NextSignal(self.0.take().map(crate::proxy::NextSignal::args))
+    }
+
+    /// Wait for the signal.
+    ///
+    /// If no signal is received within `timeout`, an [`std::io::ErrorKind::TimedOut`] I/O error is
+    /// returned.
+    pub fn wait(mut self, timeout: Duration) -> Result<T> {
+        let next = self.0.take().expect("`NextSignal` is `None`");
+
+        block_on(next.wait(timeout))
+    }
+}
+
+impl<T> std::ops::Drop for NextSignal<T> {
+    fn drop(&mut self) {
+        block_on(async {
+            if let Some(azync) = self.0.take() {
+                crate::AsyncDrop::async_drop(azync.into_inner()).await;
+            }
+        });
+    }
+}
+
 /// An [`std::iter::Iterator`] implementation that yields property change notifications.
 ///
 /// Use [`Proxy::receive_property_changed`] to create an instance of this type.
@@ -283,6 +283,25 @@ impl Connection {
         write.send_message(msg).await
     }
 
+    /// Send multiple messages back to back, in the given order.
+    ///
+    /// Unlike calling [`Connection::send`] for each message, the socket is only locked once so no
+    /// other message can get sent in between.
+    pub(crate) async fn send_all(&self, msgs: &[Message]) -> Result<()> {
+        #[cfg(unix)]
+        if !self.inner.cap_unix_fd && msgs.iter().any(|msg| !msg.data().fds().is_empty()) {
+            return Err(Error::Unsupported);
+        }
+
+        self.inner.activity_event.notify(usize::MAX);
+        let mut write = self.inner.socket_write.lock().await;
+        for msg in msgs {
+            write.send_message(msg).await?;
+        }
+
+        Ok(())
+    }
+
     /// Send a method call.
     ///
     /// Create a method-call message, send it over the connection, then wait for the reply.
@@ -382,6 +401,32 @@ impl Connection {
         }
     }
 
+    /// Send method calls back to back, in the given order.
+    ///
+    /// Like [`Connection::send_all`] but also returns objects that allow the replies to be
+    /// retrieved, in the same order as the calls. None of the calls must have the
+    /// `NoReplyExpected` flag set.
+    pub(crate) async fn call_all(&self, msgs: &[Message]) -> Result<Vec<PendingMethodCall>> {
+        let pending = msgs
+            .iter()
+            .map(|msg| {
+                let msg_receiver = self.inner.method_return_receiver.activate_cloned();
+                let stream = Some(MessageStream::for_subscription_channel(
+                    msg_receiver,
+                    // This is a lie but we only use the stream internally so it's fine.
+                    None,
+                    self,
+                ));
+                let serial = msg.primary_header().serial_num();
+
+                PendingMethodCall { stream, serial }
+            })
+            .collect();
+        self.send_all(msgs).await?;
+
+        Ok(pending)
+    }
+
     /// Emit a signal.
     ///
     /// Create a signal message, and send it over the connection.
@@ -171,6 +171,8 @@ pub use connection::Socket;
 
 pub mod blocking;
 
+#[cfg(feature = "proxy-from-xml")]
+pub use zbus_macros::proxy_from_xml;
 pub use zbus_macros::{interface, proxy, DBusError};
 // Old names used for backwards compatibility
 pub use zbus_macros::{dbus_interface, dbus_proxy};
@@ -182,6 +184,7 @@ extern crate self as zbus;
 #[doc(hidden)]
 pub mod export {
     pub use async_trait;
+    pub use enumflags2;
     pub use futures_core;
     pub use futures_util;
     pub use ordered_stream;
@@ -863,6 +866,2345 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[timeout(15000)]
+    fn all_properties() {
+        block_on(test_all_properties()).unwrap();
+    }
+
+    async fn test_all_properties() -> Result<()> {
+        struct ServiceAllPropertiesTest {
+            name: String,
+            count: u32,
+        }
+        #[crate::interface(name = "org.freedesktop.zbus.AllPropertiesTest")]
+        impl ServiceAllPropertiesTest {
+            #[zbus(property)]
+            fn name(&self) -> &str {
+                &self.name
+            }
+            #[zbus(property)]
+            fn count(&self) -> u32 {
+                self.count
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.AllPropertiesTest",
+            default_path = "/org/freedesktop/zbus/AllPropertiesTest",
+            gen_properties = true
+        )]
+        trait AllPropertiesTest {
+            #[zbus(property)]
+            fn name(&self) -> zbus::Result<String>;
+
+            #[zbus(property)]
+            fn count(&self) -> zbus::Result<u32>;
+        }
+
+        // Same interface but with a property the service doesn't provide.
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.AllPropertiesTest",
+            default_path = "/org/freedesktop/zbus/AllPropertiesTest",
+            gen_blocking = false,
+            gen_properties = true
+        )]
+        trait MissingPropertyTest {
+            #[zbus(property)]
+            fn name(&self) -> zbus::Result<String>;
+
+            #[zbus(property)]
+            fn missing(&self) -> zbus::Result<bool>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(
+                "/org/freedesktop/zbus/AllPropertiesTest",
+                ServiceAllPropertiesTest {
+                    name: String::from("zbus"),
+                    count: 42,
+                },
+            )
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let dest = service.unique_name().unwrap().to_owned();
+
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = AllPropertiesTestProxy::new(&client_conn, dest.clone())
+            .await
+            .unwrap();
+        let AllPropertiesTestProperties { name, count } = client.properties().await.unwrap();
+        assert_eq!(name, "zbus");
+        assert_eq!(count, 42);
+
+        let blocking_conn = blocking::Connection::from(client_conn.clone());
+        let client = AllPropertiesTestProxyBlocking::new(&blocking_conn, dest.clone()).unwrap();
+        let properties = client.properties().unwrap();
+        assert_eq!(properties.name, "zbus");
+        assert_eq!(properties.count, 42);
+
+        let client = MissingPropertyTestProxy::new(&client_conn, dest)
+            .await
+            .unwrap();
+        match client.properties().await {
+            Err(crate::Error::FDO(e)) => {
+                assert!(matches!(*e, crate::fdo::Error::UnknownProperty(_)))
+            }
+            Err(e) => panic!("unexpected error: {e}"),
+            Ok(_) => panic!("missing property not reported"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn pipelined_calls() {
+        block_on(test_pipelined_calls()).unwrap();
+    }
+
+    async fn test_pipelined_calls() -> Result<()> {
+        #[derive(Default)]
+        struct ServicePipelinedTest(Vec<u32>);
+        #[crate::interface(name = "org.freedesktop.zbus.PipelinedTest")]
+        impl ServicePipelinedTest {
+            fn ping(&mut self, n: u32) {
+                self.0.push(n);
+            }
+            fn pings(&self) -> Vec<u32> {
+                self.0.clone()
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.PipelinedTest",
+            default_path = "/org/freedesktop/zbus/PipelinedTest"
+        )]
+        trait PipelinedTest {
+            #[zbus(pipelined)]
+            fn ping(&self, n: u32) -> zbus::Result<()>;
+
+            fn pings(&self) -> zbus::Result<Vec<u32>>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(
+                "/org/freedesktop/zbus/PipelinedTest",
+                ServicePipelinedTest::default(),
+            )
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let dest = service.unique_name().unwrap().to_owned();
+
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = PipelinedTestProxy::new(&client_conn, dest.clone())
+            .await
+            .unwrap();
+        for n in 0..3 {
+            client.ping_pipelined(n).unwrap();
+        }
+        // Nothing is sent until the flush, while regular calls go through right away.
+        client.ping(100).await.unwrap();
+        assert_eq!(client.pings().await.unwrap(), [100]);
+        client.flush().await.unwrap();
+        assert_eq!(client.pings().await.unwrap(), [100, 0, 1, 2]);
+        // Nothing left to send.
+        client.flush().await.unwrap();
+
+        let blocking_conn = blocking::Connection::from(client_conn);
+        let client = PipelinedTestProxyBlocking::new(&blocking_conn, dest).unwrap();
+        client.ping_pipelined(3).unwrap();
+        client.ping_pipelined(4).unwrap();
+        client.flush().unwrap();
+        assert_eq!(client.pings().unwrap(), [100, 0, 1, 2, 3, 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn property_changed_values() {
+        block_on(test_property_changed_values()).unwrap();
+    }
+
+    async fn test_property_changed_values() -> Result<()> {
+        use crate::proxy::CacheProperties;
+        use futures_util::StreamExt;
+        use zvariant::Value;
+
+        const PATH: &str = "/org/freedesktop/zbus/ChangedValuesTest";
+        const IFACE: &str = "org.freedesktop.zbus.ChangedValuesTest";
+
+        struct ServiceChangedValuesTest;
+        #[crate::interface(name = "org.freedesktop.zbus.ChangedValuesTest")]
+        impl ServiceChangedValuesTest {
+            #[zbus(property)]
+            fn level(&self) -> u32 {
+                1
+            }
+            #[zbus(property)]
+            fn other(&self) -> u32 {
+                2
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.ChangedValuesTest",
+            default_path = "/org/freedesktop/zbus/ChangedValuesTest"
+        )]
+        trait ChangedValuesTest {
+            #[zbus(property(changed_values))]
+            fn level(&self) -> zbus::Result<u32>;
+            #[zbus(property)]
+            fn other(&self) -> zbus::Result<u32>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(PATH, ServiceChangedValuesTest)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = ChangedValuesTestProxy::builder(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .cache_properties(CacheProperties::Yes)
+            .build()
+            .await
+            .unwrap();
+        let mut levels = client.receive_level_changed().await;
+
+        // Fake change notifications, independent of the actual values served.
+        async fn emit_changed(
+            conn: &Connection,
+            changed: HashMap<&str, Value<'_>>,
+            invalidated: Vec<&str>,
+        ) -> Result<()> {
+            conn.emit_signal(
+                None::<()>,
+                PATH,
+                "org.freedesktop.DBus.Properties",
+                "PropertiesChanged",
+                &(IFACE, changed, invalidated),
+            )
+            .await
+        }
+
+        emit_changed(&service, HashMap::from([("Level", Value::U32(7))]), vec![])
+            .await
+            .unwrap();
+        assert_eq!(levels.next().await, Some(7));
+
+        // Changes to other properties are not reported.
+        emit_changed(&service, HashMap::from([("Other", Value::U32(5))]), vec![])
+            .await
+            .unwrap();
+        // Invalidation leads to fetching the actual value.
+        emit_changed(&service, HashMap::new(), vec!["Level"])
+            .await
+            .unwrap();
+        assert_eq!(levels.next().await, Some(1));
+        assert_eq!(client.cached_other().unwrap(), Some(5));
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn property_iter() {
+        block_on(test_property_iter()).unwrap();
+    }
+
+    async fn test_property_iter() -> Result<()> {
+        const PATH: &str = "/org/freedesktop/zbus/PropertyIterTest";
+        const LEN: u32 = 100_000;
+
+        struct ServicePropertyIterTest;
+        #[crate::interface(name = "org.freedesktop.zbus.PropertyIterTest")]
+        impl ServicePropertyIterTest {
+            #[zbus(property)]
+            fn numbers(&self) -> Vec<u32> {
+                (0..LEN).collect()
+            }
+            #[zbus(property)]
+            fn names(&self) -> Vec<String> {
+                vec!["first".into(), "second".into()]
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.PropertyIterTest",
+            default_path = "/org/freedesktop/zbus/PropertyIterTest"
+        )]
+        trait PropertyIterTest {
+            #[zbus(property(iter))]
+            fn numbers(&self) -> zbus::Result<Vec<u32>>;
+            #[zbus(property(iter))]
+            fn names(&self) -> zbus::Result<Vec<String>>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(PATH, ServicePropertyIterTest)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = PropertyIterTestProxy::builder(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let mut expected = 0;
+        for number in client.numbers_iter().await.unwrap() {
+            assert_eq!(number.unwrap(), expected);
+            expected += 1;
+        }
+        assert_eq!(expected, LEN);
+
+        let names = client
+            .names_iter()
+            .await
+            .unwrap()
+            .collect::<zvariant::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(names, ["first", "second"]);
+
+        // The element type must match the signature of the property.
+        client
+            .inner()
+            .get_property_iter::<String>("Numbers")
+            .await
+            .unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn property_variant() {
+        block_on(test_property_variant()).unwrap();
+    }
+
+    async fn test_property_variant() -> Result<()> {
+        const PATH: &str = "/org/freedesktop/zbus/PropertyVariantTest";
+
+        struct ServicePropertyVariantTest;
+        #[crate::interface(name = "org.freedesktop.zbus.PropertyVariantTest")]
+        impl ServicePropertyVariantTest {
+            #[zbus(property)]
+            fn count(&self) -> u32 {
+                42
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.PropertyVariantTest",
+            default_path = "/org/freedesktop/zbus/PropertyVariantTest"
+        )]
+        trait PropertyVariantTest {
+            #[zbus(property(variant))]
+            fn count(&self) -> zbus::Result<u32>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(PATH, ServicePropertyVariantTest)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = PropertyVariantTestProxy::builder(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(client.count().await.unwrap(), 42);
+
+        // The value is still wrapped in the variant it was transported in.
+        let variant = client.count_variant().await.unwrap();
+        assert_eq!(variant.value_signature(), "v");
+        match &*variant {
+            zvariant::Value::Value(value) => {
+                assert_eq!(value.value_signature(), "u");
+                assert_eq!(**value, zvariant::Value::U32(42));
+            }
+            value => panic!("expected a variant, got {value:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn optional_property() {
+        block_on(test_optional_property()).unwrap();
+    }
+
+    async fn test_optional_property() -> Result<()> {
+        use crate::proxy::CacheProperties;
+
+        const PATH: &str = "/org/freedesktop/zbus/OptionalPropertyTest";
+
+        struct ServiceOptionalPropertyTest;
+        #[crate::interface(name = "org.freedesktop.zbus.OptionalPropertyTest")]
+        impl ServiceOptionalPropertyTest {
+            #[zbus(property)]
+            fn count(&self) -> u32 {
+                42
+            }
+        }
+
+        // A newer version of the interface, with an extra property.
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.OptionalPropertyTest",
+            default_path = "/org/freedesktop/zbus/OptionalPropertyTest",
+            gen_properties = true
+        )]
+        trait OptionalPropertyTest {
+            #[zbus(property)]
+            fn count(&self) -> zbus::Result<u32>;
+            #[zbus(property(optional))]
+            fn label(&self) -> zbus::Result<Option<String>>;
+            #[zbus(property(optional), name = "Count")]
+            fn optional_count(&self) -> zbus::Result<Option<u32>>;
+        }
+
+        // Declares the wrong type for a property.
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.OptionalPropertyTest",
+            default_path = "/org/freedesktop/zbus/OptionalPropertyTest"
+        )]
+        trait OptionalPropertyMismatch {
+            #[zbus(property(optional))]
+            fn count(&self) -> zbus::Result<Option<String>>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(PATH, ServiceOptionalPropertyTest)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        for cache in [CacheProperties::Yes, CacheProperties::No] {
+            let client = OptionalPropertyTestProxy::builder(&client_conn)
+                .destination(service.unique_name().unwrap().to_owned())
+                .unwrap()
+                .cache_properties(cache)
+                .build()
+                .await
+                .unwrap();
+
+            assert_eq!(client.label().await.unwrap(), None);
+            assert_eq!(client.optional_count().await.unwrap(), Some(42));
+
+            let properties = client.properties().await.unwrap();
+            assert_eq!(properties.count, 42);
+            assert_eq!(properties.label, None);
+            assert_eq!(properties.optional_count, Some(42));
+
+            // Other errors are not hidden.
+            let client = OptionalPropertyMismatchProxy::builder(&client_conn)
+                .destination(service.unique_name().unwrap().to_owned())
+                .unwrap()
+                .cache_properties(cache)
+                .build()
+                .await
+                .unwrap();
+            client.count().await.unwrap_err();
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn property_range() {
+        block_on(test_property_range()).unwrap();
+    }
+
+    async fn test_property_range() -> Result<()> {
+        const PATH: &str = "/org/freedesktop/zbus/PropertyRangeTest";
+
+        // Accepts any value, so only the client can reject one.
+        struct ServicePropertyRangeTest {
+            volume: u8,
+            sets: u32,
+        }
+        #[crate::interface(name = "org.freedesktop.zbus.PropertyRangeTest")]
+        impl ServicePropertyRangeTest {
+            #[zbus(property)]
+            fn volume(&self) -> u8 {
+                self.volume
+            }
+            #[zbus(property)]
+            fn set_volume(&mut self, volume: u8) {
+                self.volume = volume;
+                self.sets += 1;
+            }
+            #[zbus(property)]
+            fn sets(&self) -> u32 {
+                self.sets
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.PropertyRangeTest",
+            default_path = "/org/freedesktop/zbus/PropertyRangeTest",
+            gen_properties_patch = true
+        )]
+        trait PropertyRangeTest {
+            #[zbus(property)]
+            fn volume(&self) -> zbus::Result<u8>;
+            #[zbus(property(range = "0..=100"))]
+            fn set_volume(&self, volume: u8) -> zbus::Result<()>;
+            #[zbus(property)]
+            fn sets(&self) -> zbus::Result<u32>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(
+                PATH,
+                ServicePropertyRangeTest {
+                    volume: 50,
+                    sets: 0,
+                },
+            )
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = PropertyRangeTestProxy::builder(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .cache_properties(crate::proxy::CacheProperties::No)
+            .build()
+            .await
+            .unwrap();
+
+        client.set_volume(0).await.unwrap();
+        client.set_volume(100).await.unwrap();
+        assert_eq!(client.volume().await.unwrap(), 100);
+        assert_eq!(client.sets().await.unwrap(), 2);
+
+        let e = client.set_volume(101).await.unwrap_err();
+        assert!(
+            matches!(
+                &e,
+                crate::Error::FDO(inner) if matches!(**inner, crate::fdo::Error::InvalidArgs(_))
+            ),
+            "{e:?}"
+        );
+        let patch = PropertyRangeTestPropertiesPatch { volume: Some(255) };
+        client.set_properties(patch).await.unwrap_err();
+
+        // Nothing was sent to the service.
+        assert_eq!(client.volume().await.unwrap(), 100);
+        assert_eq!(client.sets().await.unwrap(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn property_confirmed() {
+        block_on(test_property_confirmed()).unwrap();
+    }
+
+    async fn test_property_confirmed() -> Result<()> {
+        const PATH: &str = "/org/freedesktop/zbus/PropertyConfirmedTest";
+
+        struct ServicePropertyConfirmedTest {
+            volume: f64,
+            name: String,
+        }
+        #[crate::interface(name = "org.freedesktop.zbus.PropertyConfirmedTest")]
+        impl ServicePropertyConfirmedTest {
+            #[zbus(property)]
+            fn volume(&self) -> f64 {
+                self.volume
+            }
+            // Silently clamps the value.
+            #[zbus(property)]
+            fn set_volume(&mut self, volume: f64) {
+                self.volume = volume.clamp(0.0, 1.0);
+            }
+            #[zbus(property)]
+            fn name(&self) -> &str {
+                &self.name
+            }
+            #[zbus(property)]
+            fn set_name(&mut self, name: &str) {
+                self.name = name.trim().to_string();
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.PropertyConfirmedTest",
+            default_path = "/org/freedesktop/zbus/PropertyConfirmedTest"
+        )]
+        trait PropertyConfirmedTest {
+            #[zbus(property)]
+            fn volume(&self) -> zbus::Result<f64>;
+            #[zbus(property(confirmed))]
+            fn set_volume(&self, volume: f64) -> zbus::Result<()>;
+            #[zbus(property)]
+            fn name(&self) -> zbus::Result<String>;
+            #[zbus(property(confirmed))]
+            fn set_name(&self, name: &str) -> zbus::Result<()>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(
+                PATH,
+                ServicePropertyConfirmedTest {
+                    volume: 0.5,
+                    name: String::new(),
+                },
+            )
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let destination = service.unique_name().unwrap().to_owned();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = PropertyConfirmedTestProxy::builder(&client_conn)
+            .destination(destination.clone())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(client.set_volume_confirmed(0.8).await.unwrap(), 0.8);
+        // The server clamped the requested value.
+        assert_eq!(client.set_volume_confirmed(1.5).await.unwrap(), 1.0);
+        let name: String = client.set_name_confirmed("  kitchen ").await.unwrap();
+        assert_eq!(name, "kitchen");
+
+        let client =
+            PropertyConfirmedTestProxyBlocking::builder(&blocking::Connection::from(client_conn))
+                .destination(destination)
+                .unwrap()
+                .build()
+                .unwrap();
+        assert_eq!(client.set_volume_confirmed(-3.0).unwrap(), 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn managed_objects() {
+        block_on(test_managed_objects()).unwrap();
+    }
+
+    async fn test_managed_objects() -> Result<()> {
+        use std::time::Duration;
+
+        const PATH: &str = "/org/freedesktop/zbus/ManagedObjectsTest";
+        const FIRST_PATH: &str = "/org/freedesktop/zbus/ManagedObjectsTest/first";
+        const SECOND_PATH: &str = "/org/freedesktop/zbus/ManagedObjectsTest/second";
+        const OTHER_PATH: &str = "/org/freedesktop/zbus/ManagedObjectsTest/other";
+        const SLOW_PATH: &str = "/org/freedesktop/zbus/SlowManagedObjectsTest";
+
+        struct ManagedDevice {
+            name: String,
+            power: u8,
+        }
+        #[crate::interface(name = "org.freedesktop.zbus.ManagedDevice")]
+        impl ManagedDevice {
+            #[zbus(property)]
+            fn name(&self) -> &str {
+                &self.name
+            }
+            #[zbus(property)]
+            fn power(&self) -> u8 {
+                self.power
+            }
+        }
+
+        struct ManagedOther;
+        #[crate::interface(name = "org.freedesktop.zbus.ManagedOther")]
+        impl ManagedOther {
+            #[zbus(property)]
+            fn name(&self) -> &str {
+                "other"
+            }
+        }
+
+        struct SlowObjectManager;
+        #[crate::interface(name = "org.freedesktop.DBus.ObjectManager")]
+        impl SlowObjectManager {
+            async fn get_managed_objects(&self) -> crate::fdo::ManagedObjects {
+                // Sleep, independently of the runtime in use.
+                crate::abstractions::timeout::timeout(
+                    Duration::from_millis(500),
+                    std::future::pending::<()>(),
+                )
+                .await;
+                crate::fdo::ManagedObjects::new()
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.ManagedDevice",
+            object_manager,
+            gen_properties = true
+        )]
+        trait ManagedDevice {
+            #[zbus(property)]
+            fn name(&self) -> zbus::Result<String>;
+            #[zbus(property)]
+            fn power(&self) -> zbus::Result<u8>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(
+                FIRST_PATH,
+                ManagedDevice {
+                    name: "first".into(),
+                    power: 10,
+                },
+            )
+            .unwrap()
+            .serve_at(
+                SECOND_PATH,
+                ManagedDevice {
+                    name: "second".into(),
+                    power: 20,
+                },
+            )
+            .unwrap()
+            .serve_at(OTHER_PATH, ManagedOther)
+            .unwrap()
+            .serve_at(PATH, crate::fdo::ObjectManager)
+            .unwrap()
+            .serve_at(SLOW_PATH, SlowObjectManager)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let dest = service.unique_name().unwrap().to_owned();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = ManagedDeviceProxy::builder(&client_conn)
+            .destination(dest.clone())
+            .unwrap()
+            .path(PATH)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let objects = client.get_managed_objects().await.unwrap();
+        // Only the objects implementing the interface.
+        assert_eq!(objects.len(), 2);
+        let first = &objects[&OwnedObjectPath::try_from(FIRST_PATH).unwrap()];
+        assert_eq!(first.name, "first");
+        assert_eq!(first.power, 10);
+        let second = &objects[&OwnedObjectPath::try_from(SECOND_PATH).unwrap()];
+        assert_eq!(second.name, "second");
+        assert_eq!(second.power, 20);
+
+        // The call goes through the proxy, so its method timeout applies.
+        let client = ManagedDeviceProxy::builder(&client_conn)
+            .destination(dest)
+            .unwrap()
+            .path(SLOW_PATH)
+            .unwrap()
+            .method_timeout(Duration::from_millis(50))
+            .build()
+            .await
+            .unwrap();
+        match client.get_managed_objects().await {
+            Err(crate::Error::InputOutput(e)) => {
+                assert_eq!(e.kind(), std::io::ErrorKind::TimedOut)
+            }
+            Err(e) => panic!("unexpected error: {e:?}"),
+            Ok(_) => panic!("the method timeout wasn't applied"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn receive_property_changed_any() {
+        block_on(test_receive_property_changed_any()).unwrap();
+    }
+
+    async fn test_receive_property_changed_any() -> Result<()> {
+        use futures_util::StreamExt;
+        use zvariant::Value;
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.AnyChanges",
+            default_path = "/org/freedesktop/zbus/AnyChanges",
+            gen_property_changed_any = true
+        )]
+        trait AnyChanges {
+            #[zbus(property)]
+            fn level(&self) -> zbus::Result<u32>;
+            #[zbus(property)]
+            fn label(&self) -> zbus::Result<String>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let proxy = AnyChangesProxy::builder(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .cache_properties(crate::proxy::CacheProperties::No)
+            .build()
+            .await
+            .unwrap();
+        let mut changes = proxy.receive_property_changed_any().await.unwrap();
+
+        let emit = |interface: &'static str, level: u32| {
+            let service = service.clone();
+            async move {
+                let mut changed = HashMap::new();
+                changed.insert("Level", Value::from(level));
+                service
+                    .emit_signal(
+                        None::<()>,
+                        "/org/freedesktop/zbus/AnyChanges",
+                        "org.freedesktop.DBus.Properties",
+                        "PropertiesChanged",
+                        &(interface, changed, vec!["Label"]),
+                    )
+                    .await
+                    .unwrap();
+            }
+        };
+        // Changes of other interfaces are filtered out.
+        emit("org.freedesktop.zbus.OtherChanges", 1).await;
+        emit("org.freedesktop.zbus.AnyChanges", 7).await;
+
+        let (name, value) = changes.next().await.unwrap();
+        assert_eq!(name, "Level");
+        assert_eq!(u32::try_from(value.unwrap()).unwrap(), 7);
+        // Invalidated properties come without a value.
+        assert_eq!(changes.next().await.unwrap(), ("Label".to_string(), None));
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn properties_snapshot() {
+        block_on(test_properties_snapshot()).unwrap();
+    }
+
+    async fn test_properties_snapshot() -> Result<()> {
+        struct SnapshotDevice;
+        #[crate::interface(name = "org.freedesktop.zbus.SnapshotDevice")]
+        impl SnapshotDevice {
+            #[zbus(property)]
+            fn name(&self) -> &str {
+                "snapshot"
+            }
+            #[zbus(property)]
+            fn power(&self) -> u8 {
+                10
+            }
+            #[zbus(property)]
+            fn modes(&self) -> Vec<&str> {
+                vec!["low", "high"]
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.SnapshotDevice",
+            default_path = "/org/freedesktop/zbus/SnapshotDevice",
+            properties_snapshot
+        )]
+        trait Device {
+            #[zbus(property)]
+            fn name(&self) -> zbus::Result<String>;
+            #[zbus(property)]
+            fn power(&self) -> zbus::Result<u8>;
+            #[zbus(property)]
+            fn modes(&self) -> zbus::Result<Vec<String>>;
+            #[zbus(property(optional))]
+            fn label(&self) -> zbus::Result<Option<String>>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at("/org/freedesktop/zbus/SnapshotDevice", SnapshotDevice)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = DeviceProxy::builder(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let snapshot = client.properties_snapshot().await.unwrap();
+        let name: &str = snapshot.name().unwrap();
+        assert_eq!(name, "snapshot");
+        assert_eq!(snapshot.power().unwrap(), 10);
+        let modes: &[String] = snapshot.modes().unwrap();
+        assert_eq!(modes, ["low", "high"]);
+        assert_eq!(snapshot.label(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_with_path() {
+        block_on(test_proxy_with_path()).unwrap();
+    }
+
+    async fn test_proxy_with_path() -> Result<()> {
+        use std::time::Duration;
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.WithPath",
+            default_service = "org.freedesktop.zbus.WithPathService",
+            default_path = "/org/freedesktop/zbus/WithPath",
+            gen_with_path = true
+        )]
+        trait WithPath {
+            fn ping(&self) -> zbus::Result<()>;
+        }
+
+        let conn = crate::Connection::session().await.unwrap();
+        // Properties aren't cached by default for interfaces without properties.
+        let proxy = WithPathProxy::builder(&conn)
+            .method_timeout(Duration::from_secs(3))
+            .cache_properties(crate::proxy::CacheProperties::Lazily)
+            .build()
+            .await
+            .unwrap();
+
+        let child = proxy
+            .with_path("/org/freedesktop/zbus/WithPath/child")
+            .await
+            .unwrap();
+        assert_eq!(child.inner().path(), "/org/freedesktop/zbus/WithPath/child");
+        assert_eq!(child.inner().interface(), "org.freedesktop.zbus.WithPath");
+        assert_eq!(
+            child.inner().destination(),
+            "org.freedesktop.zbus.WithPathService"
+        );
+        assert_eq!(child.inner().method_timeout(), Some(Duration::from_secs(3)));
+        assert_eq!(
+            child.inner().cache_properties(),
+            crate::proxy::CacheProperties::Lazily
+        );
+        // `proxy` itself is unchanged.
+        assert_eq!(proxy.inner().path(), "/org/freedesktop/zbus/WithPath");
+
+        assert!(matches!(
+            proxy.with_path("not/an/object path").await,
+            Err(crate::Error::Variant(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_owner() {
+        block_on(test_proxy_owner()).unwrap();
+    }
+
+    async fn test_proxy_owner() -> Result<()> {
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.ProxyOwner",
+            default_path = "/org/freedesktop/zbus/ProxyOwner",
+            gen_owner = true
+        )]
+        trait ProxyOwner {
+            fn ping(&self) -> zbus::Result<()>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .name("org.freedesktop.zbus.ProxyOwnerTest")
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+
+        // The bus resolves the well-known name to the unique name of the service connection.
+        let proxy = ProxyOwnerProxy::builder(&client_conn)
+            .destination("org.freedesktop.zbus.ProxyOwnerTest")
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let owner = proxy.owner().await.unwrap().unwrap();
+        assert_eq!(owner, *service.unique_name().unwrap());
+
+        let proxy = ProxyOwnerProxy::builder(&client_conn)
+            .destination("org.freedesktop.zbus.ProxyOwnerTest.Unowned")
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(proxy.owner().await.unwrap(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_call_method() {
+        block_on(test_proxy_call_method()).unwrap();
+    }
+
+    async fn test_proxy_call_method() -> Result<()> {
+        struct Calculator;
+        #[crate::interface(name = "org.freedesktop.zbus.DynamicCalculator")]
+        impl Calculator {
+            fn add(&self, a: u32, b: u32) -> u32 {
+                a + b
+            }
+            fn describe(&self, value: u32) -> (String, bool) {
+                (value.to_string(), value % 2 == 0)
+            }
+            fn subtract(&self, a: u32, b: u32) -> zbus::fdo::Result<u32> {
+                a.checked_sub(b)
+                    .ok_or_else(|| zbus::fdo::Error::InvalidArgs("Negative difference".into()))
+            }
+        }
+
+        // Only `Add` is declared, the other methods are only called by name.
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.DynamicCalculator",
+            default_path = "/org/freedesktop/zbus/DynamicCalculator",
+            gen_call_method = true
+        )]
+        trait DynamicCalculator {
+            fn add(&self, a: u32, b: u32) -> zbus::Result<u32>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at("/org/freedesktop/zbus/DynamicCalculator", Calculator)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let proxy = DynamicCalculatorProxy::builder(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let sum: u32 = proxy.call_method("Add", &(2u32, 3u32)).await.unwrap();
+        assert_eq!(sum, proxy.add(2, 3).await.unwrap());
+        let (described, even): (String, bool) =
+            proxy.call_method("Describe", &(42u32,)).await.unwrap();
+        assert_eq!(described, "42");
+        assert!(even);
+
+        // Errors of the call are returned as is.
+        let res = proxy
+            .call_method::<_, _, u32>("Subtract", &(2u32, 3u32))
+            .await;
+        assert!(matches!(res, Err(crate::Error::MethodError(_, _, _))));
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_pipeline() {
+        block_on(test_proxy_pipeline()).unwrap();
+    }
+
+    async fn test_proxy_pipeline() -> Result<()> {
+        struct Counter(u32);
+        #[crate::interface(name = "org.freedesktop.zbus.PipelinedCounter")]
+        impl Counter {
+            fn increment(&mut self, by: u32) -> u32 {
+                self.0 += by;
+                self.0
+            }
+            fn decrement(&mut self, _by: u32) -> crate::fdo::Result<u32> {
+                Err(crate::fdo::Error::NotSupported("Only counting up".into()))
+            }
+            fn name(&self) -> String {
+                "counter".to_string()
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.PipelinedCounter",
+            default_path = "/org/freedesktop/zbus/PipelinedCounter",
+            gen_pipeline = true
+        )]
+        trait PipelinedCounter {
+            fn increment(&self, by: u32) -> zbus::Result<u32>;
+            fn name(&self) -> zbus::Result<String>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at("/org/freedesktop/zbus/PipelinedCounter", Counter(0))
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let proxy = PipelinedCounterProxy::builder(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let pipeline = proxy.pipeline().increment(2).name().increment(3);
+        assert_eq!(pipeline.into_inner().len(), 3);
+        // Results are in the order of the calls.
+        let (first, name, second) = proxy
+            .pipeline()
+            .increment(2)
+            .name()
+            .increment(3)
+            .send()
+            .await;
+        assert_eq!(first.unwrap(), 2);
+        assert_eq!(name.unwrap(), "counter");
+        assert_eq!(second.unwrap(), 5);
+
+        // A failing call doesn't abort the others.
+        let replies = proxy
+            .inner()
+            .pipeline()
+            .call("Increment", &(1u32,))
+            .call("Decrement", &(1u32,))
+            .call("Not a member", &())
+            .call("Increment", &(1u32,))
+            .send()
+            .await;
+        assert!(matches!(
+            replies[1],
+            Err(crate::Error::MethodError(_, _, _))
+        ));
+        assert!(matches!(replies[2], Err(crate::Error::Names(_))));
+        let counts = [&replies[0], &replies[3]]
+            .into_iter()
+            .map(|reply| reply.as_ref().unwrap().body().deserialize().unwrap())
+            .collect::<Vec<u32>>();
+        assert_eq!(counts, [6, 7]);
+        assert_eq!(proxy.increment(0).await.unwrap(), 7);
+
+        // More calls than the connection queues replies for.
+        let replies = (0..30)
+            .fold(proxy.inner().pipeline(), |pipeline, _| {
+                pipeline.call("Increment", &(1u32,))
+            })
+            .send()
+            .await;
+        let counts = replies
+            .into_iter()
+            .map(|reply| reply.unwrap().body().deserialize().unwrap())
+            .collect::<Vec<u32>>();
+        assert_eq!(counts, (8..38).collect::<Vec<_>>());
+
+        // Nothing to send.
+        proxy.pipeline().send().await;
+        assert!(proxy.inner().pipeline().send().await.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_metrics() {
+        block_on(test_proxy_metrics()).unwrap();
+    }
+
+    async fn test_proxy_metrics() -> Result<()> {
+        use crate::fdo;
+
+        struct Thermostat;
+        #[crate::interface(name = "org.freedesktop.zbus.MeteredThermostat")]
+        impl Thermostat {
+            fn temperature(&self) -> f64 {
+                21.5
+            }
+            fn set_target(&self, target: f64) -> fdo::Result<()> {
+                if target > 30.0 {
+                    return Err(fdo::Error::InvalidArgs("too hot".into()));
+                }
+
+                Ok(())
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.MeteredThermostat",
+            default_path = "/org/freedesktop/zbus/MeteredThermostat",
+            metrics,
+            gen_with_timeout = true,
+            gen_with_path = true
+        )]
+        trait MeteredThermostat {
+            fn temperature(&self) -> zbus::Result<f64>;
+            fn set_target(&self, target: f64) -> zbus::Result<()>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at("/org/freedesktop/zbus/MeteredThermostat", Thermostat)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let destination = service.unique_name().unwrap().to_owned();
+
+        // Without a hook, calls work as usual.
+        let proxy = MeteredThermostatProxy::builder(&client_conn)
+            .destination(destination.clone())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        assert!(proxy.inner().metrics_hook().is_none());
+        assert_eq!(proxy.temperature().await.unwrap(), 21.5);
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+        let hook = crate::proxy::MetricsHook::new(move |method, elapsed, result| {
+            assert!(elapsed > std::time::Duration::ZERO);
+            recorded
+                .lock()
+                .unwrap()
+                .push((method.to_string(), result.is_ok()));
+        });
+        let proxy = MeteredThermostatProxy::builder(&client_conn)
+            .destination(destination)
+            .unwrap()
+            .metrics_hook(hook)
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(proxy.temperature().await.unwrap(), 21.5);
+        proxy.set_target(19.0).await.unwrap();
+        proxy.set_target(35.0).await.unwrap_err();
+        // Property accesses and calls through the underlying proxy are not reported.
+        let _: f64 = proxy.inner().call("Temperature", &()).await.unwrap();
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                ("Temperature".to_string(), true),
+                ("SetTarget".to_string(), true),
+                ("SetTarget".to_string(), false),
+            ]
+        );
+
+        // Proxies derived from this one keep reporting to the same hook.
+        let other = proxy
+            .with_path("/org/freedesktop/zbus/MeteredThermostat")
+            .await
+            .unwrap();
+        other.temperature().await.unwrap();
+        proxy
+            .with_timeout(std::time::Duration::from_secs(5))
+            .temperature()
+            .await
+            .unwrap();
+        assert_eq!(calls.lock().unwrap().len(), 5);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "proxy-trace")]
+    #[test]
+    #[timeout(15000)]
+    fn proxy_trace() {
+        use tracing_subscriber::{layer::SubscriberExt, Layer};
+
+        // Captures the `member`, `signature` and `success` fields of the method call events.
+        #[derive(Default)]
+        struct CallVisitor {
+            member: Option<String>,
+            signature: Option<String>,
+            success: Option<bool>,
+        }
+
+        impl tracing::field::Visit for CallVisitor {
+            fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+                if field.name() == "member" {
+                    self.member = Some(value.to_string());
+                }
+            }
+
+            fn record_bool(&mut self, field: &tracing::field::Field, value: bool) {
+                if field.name() == "success" {
+                    self.success = Some(value);
+                }
+            }
+
+            fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "signature" {
+                    self.signature = Some(format!("{value:?}"));
+                }
+            }
+        }
+
+        struct CallLayer(Arc<Mutex<Vec<(String, String, bool)>>>);
+
+        impl<S: tracing::Subscriber> Layer<S> for CallLayer {
+            fn on_event(
+                &self,
+                event: &tracing::Event<'_>,
+                _ctx: tracing_subscriber::layer::Context<'_, S>,
+            ) {
+                let mut visitor = CallVisitor::default();
+                event.record(&mut visitor);
+                if let CallVisitor {
+                    member: Some(member),
+                    signature: Some(signature),
+                    success: Some(success),
+                } = visitor
+                {
+                    self.0.lock().unwrap().push((member, signature, success));
+                }
+            }
+        }
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = tracing_subscriber::registry().with(CallLayer(calls.clone()));
+        tracing::subscriber::with_default(subscriber, || block_on(test_proxy_trace())).unwrap();
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                ("Temperature".to_string(), "".to_string(), true),
+                ("SetTarget".to_string(), "d".to_string(), true),
+                ("SetTarget".to_string(), "d".to_string(), false),
+                ("Rename".to_string(), "su".to_string(), true),
+            ]
+        );
+    }
+
+    #[cfg(feature = "proxy-trace")]
+    async fn test_proxy_trace() -> Result<()> {
+        use std::pin::pin;
+
+        use crate::fdo;
+
+        struct Thermostat;
+        #[crate::interface(name = "org.freedesktop.zbus.TracedThermostat")]
+        impl Thermostat {
+            fn temperature(&self) -> f64 {
+                21.5
+            }
+            fn set_target(&self, target: f64) -> fdo::Result<()> {
+                if target > 30.0 {
+                    return Err(fdo::Error::InvalidArgs("too hot".into()));
+                }
+
+                Ok(())
+            }
+            fn rename(&self, _name: &str, _id: u32) {}
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.TracedThermostat",
+            default_path = "/org/freedesktop/zbus/TracedThermostat",
+            trace
+        )]
+        trait TracedThermostat {
+            fn temperature(&self) -> zbus::Result<f64>;
+            fn set_target(&self, target: f64) -> zbus::Result<()>;
+            fn rename(&self, name: String, id: u32) -> zbus::Result<()>;
+        }
+
+        // The subscriber capturing the events is only the default on this thread, so the tasks of
+        // the connections must run on it too. Otherwise, they'd enter their spans on the thread of
+        // the internal executor, where the spans don't exist.
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at("/org/freedesktop/zbus/TracedThermostat", Thermostat)
+            .unwrap()
+            .internal_executor(false)
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::connection::Builder::session()
+            .unwrap()
+            .internal_executor(false)
+            .build()
+            .await
+            .unwrap();
+        let executors = async {
+            loop {
+                futures_util::future::select(
+                    pin!(service.executor().tick()),
+                    pin!(client_conn.executor().tick()),
+                )
+                .await;
+            }
+        };
+
+        let calls = async {
+            let proxy = TracedThermostatProxy::builder(&client_conn)
+                .destination(service.unique_name().unwrap().to_owned())
+                .unwrap()
+                .build()
+                .await
+                .unwrap();
+
+            assert_eq!(proxy.temperature().await.unwrap(), 21.5);
+            proxy.set_target(19.0).await.unwrap();
+            proxy.set_target(35.0).await.unwrap_err();
+            // Owned arguments are still usable for the signature, after the call.
+            proxy.rename("living room".to_string(), 3).await.unwrap();
+            // Calls through the underlying proxy are not traced.
+            let _: f64 = proxy.inner().call("Temperature", &()).await.unwrap();
+        };
+        futures_util::future::select(pin!(calls), pin!(executors)).await;
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn method_timeout() {
+        block_on(test_method_timeout()).unwrap();
+    }
+
+    async fn test_method_timeout() -> Result<()> {
+        use std::time::Duration;
+
+        struct ServiceTimeoutTest;
+        #[crate::interface(name = "org.freedesktop.zbus.TimeoutTest")]
+        impl ServiceTimeoutTest {
+            fn fast(&self) -> u32 {
+                42
+            }
+            async fn slow(&self) -> u32 {
+                // Sleep, independently of the runtime in use.
+                crate::abstractions::timeout::timeout(
+                    Duration::from_millis(500),
+                    std::future::pending::<()>(),
+                )
+                .await;
+                42
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.TimeoutTest",
+            default_path = "/org/freedesktop/zbus/TimeoutTest",
+            gen_with_timeout = true
+        )]
+        trait TimeoutTest {
+            fn fast(&self) -> zbus::Result<u32>;
+            fn slow(&self) -> zbus::Result<u32>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at("/org/freedesktop/zbus/TimeoutTest", ServiceTimeoutTest)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let dest = service.unique_name().unwrap().to_owned();
+
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = TimeoutTestProxy::new(&client_conn, dest.clone())
+            .await
+            .unwrap();
+        assert_eq!(client.inner().method_timeout(), None);
+
+        let timed = client.with_timeout(Duration::from_millis(50));
+        assert_eq!(
+            timed.inner().method_timeout(),
+            Some(Duration::from_millis(50))
+        );
+        // The original proxy is left unchanged.
+        assert_eq!(client.inner().method_timeout(), None);
+
+        assert_eq!(timed.fast().await.unwrap(), 42);
+        match timed.slow().await {
+            Err(crate::Error::InputOutput(e)) => {
+                assert_eq!(e.kind(), std::io::ErrorKind::TimedOut)
+            }
+            r => panic!("unexpected result: {r:?}"),
+        }
+        assert_eq!(client.slow().await.unwrap(), 42);
+
+        let client = TimeoutTestProxy::builder(&client_conn)
+            .destination(dest)
+            .unwrap()
+            .method_timeout(Duration::from_millis(50))
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(
+            client.inner().method_timeout(),
+            Some(Duration::from_millis(50))
+        );
+        client.slow().await.unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn method_with_message() {
+        block_on(test_method_with_message()).unwrap();
+    }
+
+    async fn test_method_with_message() -> Result<()> {
+        struct ServiceWithMessageTest;
+        #[crate::interface(name = "org.freedesktop.zbus.WithMessageTest")]
+        impl ServiceWithMessageTest {
+            fn double(&self, value: u32) -> u32 {
+                value * 2
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.WithMessageTest",
+            default_path = "/org/freedesktop/zbus/WithMessageTest"
+        )]
+        trait WithMessageTest {
+            #[zbus(with_message, traced)]
+            fn double(&self, value: u32) -> zbus::Result<u32>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(
+                "/org/freedesktop/zbus/WithMessageTest",
+                ServiceWithMessageTest,
+            )
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let dest = service.unique_name().unwrap().to_owned();
+
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = WithMessageTestProxy::new(&client_conn, dest.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(client.double(2).await.unwrap(), 4);
+        let (value, reply) = client.double_with_message(21).await.unwrap();
+        assert_eq!(value, 42);
+        let header = reply.header();
+        assert_eq!(header.message_type(), crate::message::Type::MethodReturn);
+        assert_eq!(*header.sender().unwrap(), *dest);
+        assert!(header.reply_serial().is_some());
+        // Unlike the call, the reply doesn't carry the member name.
+        assert_eq!(header.member(), None);
+
+        let (value, first_serial) = client.double_traced(1).await.unwrap();
+        assert_eq!(value, 2);
+        assert_ne!(first_serial, 0);
+        assert!(first_serial > header.reply_serial().unwrap().get());
+        let (value, second_serial) = client.double_traced(2).await.unwrap();
+        assert_eq!(value, 4);
+        assert!(second_serial > first_serial);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn signal_args_stream() {
+        block_on(test_signal_args_stream()).unwrap();
+    }
+
+    async fn test_signal_args_stream() -> Result<()> {
+        use futures_util::StreamExt;
+
+        const PATH: &str = "/org/freedesktop/zbus/SignalArgsTest";
+        const IFACE: &str = "org.freedesktop.zbus.SignalArgsTest";
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.SignalArgsTest",
+            default_path = "/org/freedesktop/zbus/SignalArgsTest",
+            gen_receive_args = true
+        )]
+        trait SignalArgsTest {
+            #[zbus(signal)]
+            fn moved(&self, name: String, x: i32, y: i32) -> zbus::Result<()>;
+
+            #[zbus(signal)]
+            fn renamed(&self, name: String) -> zbus::Result<()>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client =
+            SignalArgsTestProxy::new(&client_conn, service.unique_name().unwrap().to_owned())
+                .await
+                .unwrap();
+        let mut moves = client.receive_moved_args().await.unwrap();
+        let mut renames = client.receive_renamed_args().await.unwrap();
+
+        service
+            .emit_signal(None::<()>, PATH, IFACE, "Moved", &("foo", 1i32, -2i32))
+            .await
+            .unwrap();
+        service
+            .emit_signal(None::<()>, PATH, IFACE, "Renamed", &("bar",))
+            .await
+            .unwrap();
+        // Arguments not matching the declared ones.
+        service
+            .emit_signal(None::<()>, PATH, IFACE, "Moved", &("foo",))
+            .await
+            .unwrap();
+        service
+            .emit_signal(None::<()>, PATH, IFACE, "Moved", &("baz", 3i32, 4i32))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            moves.next().await.unwrap().unwrap(),
+            (String::from("foo"), 1, -2)
+        );
+        assert_eq!(renames.next().await.unwrap().unwrap(), "bar");
+        moves.next().await.unwrap().unwrap_err();
+        assert_eq!(
+            moves.next().await.unwrap().unwrap(),
+            (String::from("baz"), 3, 4)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn next_signal() {
+        block_on(test_next_signal()).unwrap();
+    }
+
+    async fn test_next_signal() -> Result<()> {
+        use std::time::Duration;
+
+        const PATH: &str = "/org/freedesktop/zbus/NextSignalTest";
+        const IFACE: &str = "org.freedesktop.zbus.NextSignalTest";
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.NextSignalTest",
+            default_path = "/org/freedesktop/zbus/NextSignalTest",
+            gen_next_signal = true
+        )]
+        trait NextSignalTest {
+            #[zbus(signal)]
+            fn moved(&self, name: String, x: i32, y: i32) -> zbus::Result<()>;
+
+            #[zbus(signal)]
+            fn renamed(&self, name: String) -> zbus::Result<()>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client =
+            NextSignalTestProxy::new(&client_conn, service.unique_name().unwrap().to_owned())
+                .await
+                .unwrap();
+
+        // The subscription is in place once the handle is returned, so a single emission suffices.
+        let moved = client.next_moved().await.unwrap();
+        service
+            .emit_signal(None::<()>, PATH, IFACE, "Moved", &("foo", 1i32, -2i32))
+            .await
+            .unwrap();
+        assert_eq!(
+            moved.wait(Duration::from_secs(5)).await.unwrap(),
+            (String::from("foo"), 1, -2)
+        );
+
+        let renamed = client.next_renamed().await.unwrap();
+        match renamed.wait(Duration::from_millis(100)).await {
+            Err(crate::Error::InputOutput(e)) => assert_eq!(e.kind(), std::io::ErrorKind::TimedOut),
+            r => panic!("unexpected result: {r:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_when_available() {
+        block_on(test_proxy_when_available()).unwrap();
+    }
+
+    async fn test_proxy_when_available() -> Result<()> {
+        use futures_util::future::join;
+        use std::time::Duration;
+
+        const NAME: &str = "org.freedesktop.zbus.WhenAvailableTest";
+
+        struct ServiceWhenAvailableTest;
+        #[crate::interface(name = "org.freedesktop.zbus.WhenAvailableTest")]
+        impl ServiceWhenAvailableTest {
+            fn ping(&self) -> u32 {
+                1
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.WhenAvailableTest",
+            default_service = "org.freedesktop.zbus.WhenAvailableTest",
+            default_path = "/org/freedesktop/zbus/WhenAvailableTest",
+            gen_when_available = true
+        )]
+        trait WhenAvailableTest {
+            fn ping(&self) -> zbus::Result<u32>;
+        }
+
+        let client_conn = crate::Connection::session().await.unwrap();
+
+        // Nobody owns the name yet.
+        let err =
+            WhenAvailableTestProxy::new_when_available(&client_conn, Duration::from_millis(100))
+                .await
+                .unwrap_err();
+        match err {
+            crate::Error::InputOutput(e) => assert_eq!(e.kind(), std::io::ErrorKind::TimedOut),
+            e => panic!("unexpected error: {e:?}"),
+        }
+
+        let service = async {
+            // Delay the service startup.
+            crate::abstractions::timeout::timeout(
+                Duration::from_millis(200),
+                std::future::pending::<()>(),
+            )
+            .await;
+
+            crate::connection::Builder::session()
+                .unwrap()
+                .name(NAME)
+                .unwrap()
+                .serve_at(
+                    "/org/freedesktop/zbus/WhenAvailableTest",
+                    ServiceWhenAvailableTest,
+                )
+                .unwrap()
+                .build()
+                .await
+                .unwrap()
+        };
+        let client =
+            WhenAvailableTestProxy::new_when_available(&client_conn, Duration::from_secs(10));
+        let (client, _service) = join(client, service).await;
+        let client = client.unwrap();
+        assert_eq!(client.ping().await.unwrap(), 1);
+
+        // Already owned, so no waiting.
+        let client =
+            WhenAvailableTestProxy::new_when_available(&client_conn, Duration::from_millis(100))
+                .await
+                .unwrap();
+        assert_eq!(client.ping().await.unwrap(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_new_for_typed() {
+        block_on(test_proxy_new_for_typed()).unwrap();
+    }
+
+    async fn test_proxy_new_for_typed() -> Result<()> {
+        use crate::names::BusName;
+        use zvariant::ObjectPath;
+
+        #[crate::proxy(
+            interface = "org.freedesktop.DBus.Peer",
+            default_service = "org.freedesktop.zbus.NewForTypedTest",
+            default_path = "/org/freedesktop/zbus/NewForTypedTest",
+            gen_new_for_typed = true
+        )]
+        trait NewForTypedTest {
+            fn ping(&self) -> zbus::Result<()>;
+        }
+
+        let conn = crate::Connection::session().await.unwrap();
+
+        // The names may borrow, e.g from a configuration.
+        let (destination, path) = (
+            String::from("org.freedesktop.DBus"),
+            String::from("/org/freedesktop/DBus"),
+        );
+        let destination = BusName::try_from(destination.as_str()).unwrap();
+        let path = ObjectPath::try_from(path.as_str()).unwrap();
+        let proxy = NewForTypedTestProxy::new_for_typed(&conn, destination, path)
+            .await
+            .unwrap();
+        assert_eq!(proxy.inner().destination(), "org.freedesktop.DBus");
+        assert_eq!(proxy.inner().path(), "/org/freedesktop/DBus");
+        proxy.ping().await.unwrap();
+
+        let proxy = NewForTypedTestProxyBlocking::new_for_typed(
+            &blocking::Connection::from(conn),
+            BusName::try_from("org.freedesktop.DBus").unwrap(),
+            ObjectPath::try_from("/org/freedesktop/DBus").unwrap(),
+        )
+        .unwrap();
+        proxy.ping().unwrap();
+
+        // Malformed names are rejected on conversion, before any proxy is created.
+        assert!(matches!(
+            BusName::try_from("org..freedesktop"),
+            Err(crate::names::Error::InvalidBusName(_, _))
+        ));
+        ObjectPath::try_from("org/freedesktop/DBus").unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_clone() {
+        block_on(test_proxy_clone()).unwrap();
+    }
+
+    async fn test_proxy_clone() -> Result<()> {
+        use std::time::Duration;
+
+        let conn = crate::Connection::session().await.unwrap();
+        let proxy = crate::fdo::PeerProxy::builder(&conn)
+            .destination("org.freedesktop.DBus")
+            .unwrap()
+            .path("/org/freedesktop/DBus")
+            .unwrap()
+            .method_timeout(Duration::from_secs(7))
+            .build()
+            .await
+            .unwrap();
+        let clone = proxy.clone();
+        // Dropping the original doesn't affect the clone.
+        drop(proxy);
+        assert_eq!(clone.inner().destination(), "org.freedesktop.DBus");
+        assert_eq!(clone.inner().path(), "/org/freedesktop/DBus");
+        assert_eq!(clone.inner().interface(), "org.freedesktop.DBus.Peer");
+        assert_eq!(clone.inner().method_timeout(), Some(Duration::from_secs(7)));
+        assert_eq!(clone.inner().connection().unique_name(), conn.unique_name());
+        clone.ping().await.unwrap();
+
+        let blocking_conn = blocking::Connection::from(conn);
+        let proxy = crate::blocking::fdo::PeerProxy::new(
+            &blocking_conn,
+            "org.freedesktop.DBus",
+            "/org/freedesktop/DBus",
+        )
+        .unwrap();
+        let clone = proxy.clone();
+        assert_eq!(clone.inner().destination(), proxy.inner().destination());
+        assert_eq!(clone.inner().path(), proxy.inner().path());
+        assert_eq!(clone.inner().interface(), proxy.inner().interface());
+        assert_eq!(
+            clone.inner().connection().unique_name(),
+            blocking_conn.unique_name()
+        );
+        clone.ping().unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_ord() {
+        block_on(test_proxy_ord()).unwrap();
+    }
+
+    async fn test_proxy_ord() -> Result<()> {
+        use std::collections::BTreeSet;
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.OrdTest",
+            default_service = "org.freedesktop.zbus.OrdTest",
+            ord
+        )]
+        trait OrdTest {
+            fn ping(&self) -> zbus::Result<()>;
+        }
+
+        let conn = crate::Connection::session().await.unwrap();
+        let mut proxies = vec![];
+        for path in [
+            "/org/freedesktop/zbus/OrdTest/sda",
+            "/org/freedesktop/zbus/OrdTest/nvme0",
+            "/org/freedesktop/zbus/OrdTest/sda1",
+            "/org/freedesktop/zbus/OrdTest/nvme0",
+        ] {
+            proxies.push(OrdTestProxy::new(&conn, path).await.unwrap());
+        }
+        proxies.sort();
+        let paths: Vec<_> = proxies.iter().map(|p| p.inner().path().as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/org/freedesktop/zbus/OrdTest/nvme0",
+                "/org/freedesktop/zbus/OrdTest/nvme0",
+                "/org/freedesktop/zbus/OrdTest/sda",
+                "/org/freedesktop/zbus/OrdTest/sda1",
+            ]
+        );
+        assert_eq!(proxies[0], proxies[1]);
+        assert_ne!(proxies[1], proxies[2]);
+
+        // Proxies for the same object are deduplicated.
+        // The interior mutability of proxies (e.g the property cache) doesn't take part in their
+        // ordering, which only uses the immutable path and destination.
+        #[allow(clippy::mutable_key_type)]
+        let set: BTreeSet<_> = proxies.iter().cloned().collect();
+        assert_eq!(set.len(), 3);
+
+        // Then ordered by destination.
+        let other = OrdTestProxy::builder(&conn)
+            .destination("org.freedesktop.zbus.OrdTest0")
+            .unwrap()
+            .path("/org/freedesktop/zbus/OrdTest/nvme0")
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        assert_ne!(other, proxies[0]);
+        assert!(proxies[0] < other && other < proxies[2]);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn method_with_flags() {
+        block_on(test_method_with_flags()).unwrap();
+    }
+
+    async fn test_method_with_flags() -> Result<()> {
+        use crate::{
+            message::{Flags, Header},
+            proxy::MethodFlags,
+        };
+        use enumflags2::BitFlags;
+
+        struct ServiceWithFlagsTest;
+        #[crate::interface(name = "org.freedesktop.zbus.WithFlagsTest")]
+        impl ServiceWithFlagsTest {
+            fn flags(&self, #[zbus(header)] header: Header<'_>) -> u8 {
+                header.primary().flags().bits()
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.WithFlagsTest",
+            default_path = "/org/freedesktop/zbus/WithFlagsTest"
+        )]
+        trait WithFlagsTest {
+            #[zbus(with_flags)]
+            fn flags(&self) -> zbus::Result<u8>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at("/org/freedesktop/zbus/WithFlagsTest", ServiceWithFlagsTest)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client =
+            WithFlagsTestProxy::new(&client_conn, service.unique_name().unwrap().to_owned())
+                .await
+                .unwrap();
+
+        assert_eq!(client.flags().await.unwrap(), 0);
+        let flags = client
+            .flags_with_flags(BitFlags::empty())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(flags, 0);
+        let flags = client
+            .flags_with_flags(MethodFlags::NoAutoStart.into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            BitFlags::<Flags>::from_bits(flags).unwrap(),
+            Flags::NoAutoStart
+        );
+        // No reply is expected, so none is returned.
+        let reply = client
+            .flags_with_flags(MethodFlags::NoReplyExpected | MethodFlags::NoAutoStart)
+            .await
+            .unwrap();
+        assert_eq!(reply, None);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn method_stream() {
+        block_on(test_method_stream()).unwrap();
+    }
+
+    async fn test_method_stream() -> Result<()> {
+        use futures_util::StreamExt;
+
+        struct ServiceStreamTest;
+        #[crate::interface(name = "org.freedesktop.zbus.StreamTest")]
+        impl ServiceStreamTest {
+            fn range(&self, len: u32) -> Vec<u32> {
+                (0..len).collect()
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.StreamTest",
+            default_path = "/org/freedesktop/zbus/StreamTest"
+        )]
+        trait StreamTest {
+            #[zbus(stream)]
+            fn range(&self, len: u32) -> zbus::Result<Vec<u32>>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at("/org/freedesktop/zbus/StreamTest", ServiceStreamTest)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let dest = service.unique_name().unwrap().to_owned();
+
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = StreamTestProxy::new(&client_conn, dest).await.unwrap();
+        let elements = client.range_stream(5).await.unwrap();
+        assert_eq!(elements.collect::<Vec<_>>().await, [0, 1, 2, 3, 4]);
+        let mut empty = client.range_stream(0).await.unwrap();
+        assert_eq!(empty.next().await, None);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn set_properties() {
+        block_on(test_set_properties()).unwrap();
+    }
+
+    async fn test_set_properties() -> Result<()> {
+        const PATH: &str = "/org/freedesktop/zbus/SetPropertiesTest";
+
+        #[derive(Default)]
+        struct ServiceSetPropertiesTest {
+            level: u32,
+            label: String,
+            enabled: bool,
+            writes: Vec<&'static str>,
+        }
+        #[crate::interface(name = "org.freedesktop.zbus.SetPropertiesTest")]
+        impl ServiceSetPropertiesTest {
+            #[zbus(property)]
+            fn level(&self) -> u32 {
+                self.level
+            }
+            #[zbus(property)]
+            fn set_level(&mut self, value: u32) {
+                self.writes.push("Level");
+                self.level = value;
+            }
+            #[zbus(property)]
+            fn label(&self) -> &str {
+                &self.label
+            }
+            #[zbus(property)]
+            fn set_label(&mut self, value: String) {
+                self.writes.push("Label");
+                self.label = value;
+            }
+            #[zbus(property)]
+            fn enabled(&self) -> bool {
+                self.enabled
+            }
+            #[zbus(property)]
+            fn set_enabled(&mut self, value: bool) {
+                self.writes.push("Enabled");
+                self.enabled = value;
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.SetPropertiesTest",
+            default_path = "/org/freedesktop/zbus/SetPropertiesTest",
+            gen_properties_patch = true
+        )]
+        trait SetPropertiesTest {
+            #[zbus(property)]
+            fn level(&self) -> zbus::Result<u32>;
+            #[zbus(property)]
+            fn set_level(&self, value: u32) -> zbus::Result<()>;
+            #[zbus(property)]
+            fn label(&self) -> zbus::Result<String>;
+            #[zbus(property)]
+            fn set_label(&self, value: &str) -> zbus::Result<()>;
+            #[zbus(property)]
+            fn enabled(&self) -> zbus::Result<bool>;
+            #[zbus(property)]
+            fn set_enabled(&self, value: bool) -> zbus::Result<()>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(PATH, ServiceSetPropertiesTest::default())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = SetPropertiesTestProxy::builder(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .cache_properties(crate::proxy::CacheProperties::No)
+            .build()
+            .await
+            .unwrap();
+
+        client
+            .set_properties(SetPropertiesTestPropertiesPatch {
+                level: Some(7),
+                label: None,
+                enabled: Some(true),
+            })
+            .await
+            .unwrap();
+        let iface_ref = service
+            .object_server()
+            .interface::<_, ServiceSetPropertiesTest>(PATH)
+            .await
+            .unwrap();
+        // Only the `Some` fields are written.
+        assert_eq!(iface_ref.get().await.writes, ["Level", "Enabled"]);
+        assert_eq!(client.level().await.unwrap(), 7);
+        assert_eq!(client.label().await.unwrap(), "");
+        assert!(client.enabled().await.unwrap());
+
+        // An empty patch writes nothing.
+        client
+            .set_properties(SetPropertiesTestPropertiesPatch::default())
+            .await
+            .unwrap();
+        assert_eq!(iface_ref.get().await.writes.len(), 2);
+
+        // Borrowed setter values are owned by the patch, so runtime values can be passed.
+        let label = format!("label #{}", 3);
+        client
+            .set_properties(SetPropertiesTestPropertiesPatch {
+                label: Some(label.clone()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(client.label().await.unwrap(), label);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn introspection_cache() {
+        block_on(test_introspection_cache()).unwrap();
+    }
+
+    async fn test_introspection_cache() -> Result<()> {
+        const PATH: &str = "/org/freedesktop/zbus/IntrospectionCacheTest";
+        const IFACE: &str = "org.freedesktop.zbus.IntrospectionCacheTest";
+
+        struct IntrospectionCacheTest;
+        #[crate::interface(name = "org.freedesktop.zbus.IntrospectionCacheTest")]
+        impl IntrospectionCacheTest {
+            fn ping(&self) {}
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at(PATH, IntrospectionCacheTest)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client_conn = crate::Connection::session().await.unwrap();
+        let proxy_builder = crate::proxy::Builder::<crate::Proxy<'_>>::new(&client_conn)
+            .destination(service.unique_name().unwrap().to_owned())
+            .unwrap()
+            .path(PATH)
+            .unwrap()
+            .interface(IFACE)
+            .unwrap()
+            .cache_properties(crate::proxy::CacheProperties::No);
+        let uncached = proxy_builder.clone().build().await.unwrap();
+        let cached = proxy_builder
+            .cache_introspection(true)
+            .build()
+            .await
+            .unwrap();
+
+        let xml = cached.introspect().await.unwrap();
+        assert!(xml.contains(IFACE));
+
+        // Remove the object, so introspecting it through the bus fails.
+        assert!(service
+            .object_server()
+            .remove::<IntrospectionCacheTest, _>(PATH)
+            .await
+            .unwrap());
+        uncached.introspect().await.unwrap_err();
+
+        // The cached copy is returned without asking the service.
+        assert_eq!(cached.introspect().await.unwrap(), xml);
+
+        // Refreshing goes to the service, which also keeps the previous copy on failure.
+        cached.refresh_introspection().await.unwrap_err();
+        assert_eq!(cached.introspect().await.unwrap(), xml);
+
+        service
+            .object_server()
+            .at(PATH, IntrospectionCacheTest)
+            .await
+            .unwrap();
+        // The interfaces of the new object may be listed in a different order.
+        let refreshed = cached.refresh_introspection().await.unwrap();
+        assert!(refreshed.contains(IFACE));
+        assert_eq!(cached.introspect().await.unwrap(), refreshed);
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn proxy_peer_methods() {
+        block_on(test_proxy_peer_methods()).unwrap();
+    }
+
+    async fn test_proxy_peer_methods() -> Result<()> {
+        struct ServicePeerTest;
+        #[crate::interface(name = "org.freedesktop.zbus.PeerTest")]
+        impl ServicePeerTest {
+            fn answer(&self) -> u32 {
+                42
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.PeerTest",
+            default_path = "/org/freedesktop/zbus/PeerTest"
+        )]
+        trait PeerTest {
+            fn answer(&self) -> zbus::Result<u32>;
+        }
+
+        // Generation can be disabled, e.g in case of name collisions.
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.PeerTest",
+            default_path = "/org/freedesktop/zbus/PeerTest",
+            gen_peer = false
+        )]
+        trait NoPeerTest {
+            fn answer(&self) -> zbus::Result<u32>;
+        }
+
+        let service = crate::connection::Builder::session()
+            .unwrap()
+            .serve_at("/org/freedesktop/zbus/PeerTest", ServicePeerTest)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let dest = service.unique_name().unwrap().to_owned();
+
+        let client_conn = crate::Connection::session().await.unwrap();
+        let client = PeerTestProxy::new(&client_conn, dest.clone())
+            .await
+            .unwrap();
+        assert_eq!(client.answer().await.unwrap(), 42);
+        // `org.freedesktop.zbus.PeerTest` has no such methods, so these can only succeed by going
+        // through the `org.freedesktop.DBus.Peer` interface.
+        client.ping().await.unwrap();
+        let peer = crate::fdo::PeerProxy::builder(&client_conn)
+            .destination(dest.clone())
+            .unwrap()
+            .path("/org/freedesktop/zbus/PeerTest")
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(
+            client.get_machine_id().await.unwrap(),
+            peer.get_machine_id().await.unwrap()
+        );
+
+        let blocking_conn = blocking::Connection::from(client_conn.clone());
+        let client = PeerTestProxyBlocking::new(&blocking_conn, dest.clone()).unwrap();
+        client.ping().unwrap();
+        assert!(!client.get_machine_id().unwrap().is_empty());
+
+        // Nothing clashes with the items of opted out proxies.
+        impl NoPeerTestProxy<'_> {
+            fn ping(&self) -> &'static str {
+                "pong"
+            }
+        }
+        let client = NoPeerTestProxy::new(&client_conn, dest).await.unwrap();
+        assert_eq!(client.answer().await.unwrap(), 42);
+        assert_eq!(client.ping(), "pong");
+
+        Ok(())
+    }
+
     #[test]
     #[timeout(15000)]
     fn issue_260() {
@@ -1113,6 +3455,79 @@ mod tests {
         })
     }
 
+    #[cfg(all(unix, feature = "p2p"))]
+    #[test]
+    #[timeout(15000)]
+    fn p2p_proxy() {
+        block_on(test_p2p_proxy()).unwrap();
+    }
+
+    #[cfg(all(unix, feature = "p2p"))]
+    async fn test_p2p_proxy() -> Result<()> {
+        use crate::{connection::Builder, message::Header};
+        use futures_util::try_join;
+        #[cfg(not(feature = "tokio"))]
+        use std::os::unix::net::UnixStream;
+        #[cfg(feature = "tokio")]
+        use tokio::net::UnixStream;
+
+        struct P2pTest;
+        #[crate::interface(name = "org.freedesktop.zbus.P2pTest")]
+        impl P2pTest {
+            fn has_destination(&self, #[zbus(header)] header: Header<'_>) -> bool {
+                header.destination().is_some()
+            }
+        }
+
+        #[crate::proxy(
+            interface = "org.freedesktop.zbus.P2pTest",
+            default_service = "org.freedesktop.zbus.P2pTest",
+            default_path = "/org/freedesktop/zbus/P2pTest",
+            gen_blocking = false,
+            p2p
+        )]
+        trait P2pTest {
+            fn has_destination(&self) -> zbus::Result<bool>;
+        }
+
+        let guid = crate::Guid::generate();
+        let (p0, p1) = UnixStream::pair().unwrap();
+        let server = Builder::unix_stream(p0)
+            .server(guid)
+            .unwrap()
+            .p2p()
+            .serve_at("/org/freedesktop/zbus/P2pTest", P2pTest)
+            .unwrap()
+            .build();
+        let client = Builder::unix_stream(p1).p2p().build();
+        let (_server, client) = try_join!(server, client)?;
+
+        let proxy = P2pTestProxy::new_p2p(&client, "/org/freedesktop/zbus/P2pTest")
+            .await
+            .unwrap();
+        assert_eq!(proxy.inner().destination_opt(), None);
+        // The default service isn't sent either.
+        assert!(!proxy.has_destination().await.unwrap());
+
+        // Forgetting the destination is still an error, unless explicitly asked for.
+        let err = crate::proxy::Builder::<crate::Proxy<'_>>::new(&client)
+            .path("/org/freedesktop/zbus/P2pTest")?
+            .interface("org.freedesktop.zbus.P2pTest")?
+            .build()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::MissingParameter("destination")));
+
+        // A destination is still needed on the bus.
+        let bus_conn = crate::Connection::session().await.unwrap();
+        let err = P2pTestProxy::new_p2p(&bus_conn, "/org/freedesktop/zbus/P2pTest")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::MissingParameter("destination")));
+
+        Ok(())
+    }
+
     #[cfg(all(unix, feature = "p2p"))]
     #[instrument]
     #[test]
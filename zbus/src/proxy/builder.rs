@@ -1,10 +1,18 @@
-use std::{collections::HashSet, marker::PhantomData, sync::Arc};
+use std::{
+    collections::HashSet,
+    marker::PhantomData,
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
 
 use static_assertions::assert_impl_all;
-use zbus_names::{BusName, InterfaceName};
+use zbus_names::{BusName, InterfaceName, UniqueName};
 use zvariant::{ObjectPath, Str};
 
-use crate::{proxy::ProxyInner, Connection, Error, Proxy, Result};
+use crate::{
+    proxy::{MetricsHook, ProxyInner, ProxyInnerStatic},
+    Connection, Error, Proxy, Result,
+};
 
 /// The properties caching mode.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -25,11 +33,16 @@ pub enum CacheProperties {
 pub struct Builder<'a, T = ()> {
     conn: Connection,
     destination: Option<BusName<'a>>,
+    no_destination: bool,
     path: Option<ObjectPath<'a>>,
     interface: Option<InterfaceName<'a>>,
     proxy_type: PhantomData<T>,
     cache: CacheProperties,
     uncached_properties: Option<HashSet<Str<'a>>>,
+    method_timeout: Option<Duration>,
+    cache_introspection: bool,
+    sender: Option<UniqueName<'a>>,
+    metrics_hook: Option<MetricsHook>,
 }
 
 impl<'a, T> Clone for Builder<'a, T> {
@@ -37,10 +50,15 @@ impl<'a, T> Clone for Builder<'a, T> {
         Self {
             conn: self.conn.clone(),
             destination: self.destination.clone(),
+            no_destination: self.no_destination,
             path: self.path.clone(),
             interface: self.interface.clone(),
             cache: self.cache,
             uncached_properties: self.uncached_properties.clone(),
+            method_timeout: self.method_timeout,
+            cache_introspection: self.cache_introspection,
+            sender: self.sender.clone(),
+            metrics_hook: self.metrics_hook.clone(),
             proxy_type: PhantomData,
         }
     }
@@ -56,9 +74,26 @@ impl<'a, T> Builder<'a, T> {
         D::Error: Into<Error>,
     {
         self.destination = Some(destination.try_into().map_err(Into::into)?);
+        self.no_destination = false;
         Ok(self)
     }
 
+    /// Don't set any destination, even if the proxy type has a default one.
+    ///
+    /// This is only possible on peer-to-peer connections, where there is no bus to route the
+    /// messages to their destination, and the method calls of the resulting proxy don't carry a
+    /// destination header field. On bus connections, building the proxy fails with
+    /// [`Error::MissingParameter`].
+    ///
+    /// [`Proxy::destination_opt`] returns `None` for the resulting proxy, while
+    /// [`Proxy::destination`] panics.
+    #[must_use]
+    pub fn no_destination(mut self) -> Self {
+        self.destination = None;
+        self.no_destination = true;
+        self
+    }
+
     /// Set the proxy path.
     pub fn path<P>(mut self, path: P) -> Result<Self>
     where
@@ -95,25 +130,91 @@ impl<'a, T> Builder<'a, T> {
         self
     }
 
+    /// Set how long method calls wait for a reply.
+    ///
+    /// If no reply is received in time, the call fails with an [`Error::InputOutput`] error of
+    /// kind [`std::io::ErrorKind::TimedOut`]. By default, method calls wait indefinitely.
+    #[must_use]
+    pub fn method_timeout(mut self, timeout: Duration) -> Self {
+        self.method_timeout = Some(timeout);
+        self
+    }
+
+    /// Set whether to cache the introspection XML description.
+    ///
+    /// If enabled, [`Proxy::introspect`] only fetches the description on its first call, and
+    /// returns the cached copy afterwards, until [`Proxy::refresh_introspection`] is called. This
+    /// is disabled by default.
+    #[must_use]
+    pub fn cache_introspection(mut self, cache: bool) -> Self {
+        self.cache_introspection = cache;
+        self
+    }
+
+    /// Only accept signals and method replies from the given unique name.
+    ///
+    /// By default, a proxy for a well-known name accepts signals from whichever connection owns
+    /// the name at the time, and method replies from whichever connection sends them. Once pinned
+    /// to a sender, signals from any other connection are ignored, and method replies from any
+    /// other connection are rejected with [`Error::InvalidReply`]. This protects against spoofed
+    /// signals and replies, when several connections could own the destination name.
+    ///
+    /// Method calls are still addressed to the destination.
+    pub fn sender<S>(mut self, sender: S) -> Result<Self>
+    where
+        S: TryInto<UniqueName<'a>>,
+        S::Error: Into<Error>,
+    {
+        self.sender = Some(sender.try_into().map_err(Into::into)?);
+        Ok(self)
+    }
+
+    /// Set the hook to report method calls to.
+    ///
+    /// Only the proxies generated by the [`proxy`] macro with the `metrics` attribute report
+    /// their method calls, see [`MetricsHook`] for details. By default, there is no hook.
+    ///
+    /// [`proxy`]: macro@crate::proxy
+    #[must_use]
+    pub fn metrics_hook(mut self, hook: MetricsHook) -> Self {
+        self.metrics_hook = Some(hook);
+        self
+    }
+
     pub(crate) fn build_internal(self) -> Result<Proxy<'a>> {
         let conn = self.conn;
-        let destination = self
-            .destination
-            .ok_or(Error::MissingParameter("destination"))?;
+        // Only peer-to-peer connections can do without a destination, and only if asked to.
+        let destination = match self.destination {
+            Some(destination) => Some(destination),
+            None if self.no_destination && !conn.is_bus() => None,
+            None => return Err(Error::MissingParameter("destination")),
+        };
         let path = self.path.ok_or(Error::MissingParameter("path"))?;
         let interface = self.interface.ok_or(Error::MissingParameter("interface"))?;
         let cache = self.cache;
-        let uncached_properties = self.uncached_properties.unwrap_or_default();
+        let property_cache = match cache {
+            CacheProperties::Yes | CacheProperties::Lazily => Some(OnceLock::new()),
+            CacheProperties::No => None,
+        };
 
         Ok(Proxy {
-            inner: Arc::new(ProxyInner::new(
-                conn,
+            inner: Arc::new(ProxyInner {
+                inner_without_borrows: ProxyInnerStatic {
+                    conn,
+                    dest_owner_change_match_rule: OnceLock::new(),
+                },
                 destination,
                 path,
                 interface,
                 cache,
-                uncached_properties,
-            )),
+                property_cache,
+                uncached_properties: self.uncached_properties.unwrap_or_default(),
+                pipelined_calls: Mutex::new(Vec::new()),
+                introspection_cache: self.cache_introspection.then(|| Mutex::new(None)),
+                sender: self.sender,
+                metrics_hook: self.metrics_hook,
+            }),
+            method_timeout: self.method_timeout,
         })
     }
 
@@ -153,11 +254,16 @@ where
             conn: conn.clone(),
             destination: T::DESTINATION
                 .map(|d| BusName::from_static_str(d).expect("invalid bus name")),
+            no_destination: false,
             path: T::PATH.map(|p| ObjectPath::from_static_str(p).expect("invalid default path")),
             interface: T::INTERFACE
                 .map(|i| InterfaceName::from_static_str(i).expect("invalid interface name")),
             cache: CacheProperties::default(),
             uncached_properties: None,
+            method_timeout: None,
+            cache_introspection: false,
+            sender: None,
+            metrics_hook: None,
             proxy_type: PhantomData,
         }
     }
@@ -218,6 +324,6 @@ mod tests {
             BusName::Unique(_),
         ));
         let proxy = builder.build().await.unwrap();
-        assert!(matches!(proxy.inner.destination, BusName::Unique(_)));
+        assert!(matches!(proxy.inner.destination, Some(BusName::Unique(_))));
     }
 }
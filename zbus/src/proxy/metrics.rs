@@ -0,0 +1,69 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+use static_assertions::assert_impl_all;
+
+use crate::Error;
+
+type HookFn = dyn FnMut(&str, Duration, Result<(), &Error>) + Send;
+
+/// A callback recording the method calls made through a proxy.
+///
+/// Proxies generated by the [`proxy`] macro with the `metrics` attribute time each of their method
+/// calls and report them to the hook set through [`Builder::metrics_hook`], if any. The hook is
+/// given the name of the called method, the time it took to get the reply and whether the call
+/// succeeded. This is meant for collecting metrics, e.g latency histograms per method.
+///
+/// The hook is called from the task making the method call, once the reply is received, so it
+/// should return quickly. It's shared by all clones of the proxy and it's never called
+/// concurrently.
+///
+/// The hook must not make method calls through the proxy (or any proxy sharing the hook), since
+/// these would be reported to the hook while it's still running, resulting in a deadlock. If the
+/// hook panics, later calls still get reported to it.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use zbus::proxy::MetricsHook;
+///
+/// let hook = MetricsHook::new(|method: &str, elapsed: Duration, result| {
+///     println!("{method} took {elapsed:?} (success: {})", result.is_ok());
+/// });
+/// hook.record("GetId", Duration::from_millis(3), Ok(()));
+/// ```
+///
+/// [`proxy`]: macro@crate::proxy
+/// [`Builder::metrics_hook`]: crate::proxy::Builder::metrics_hook
+#[derive(Clone)]
+pub struct MetricsHook(Arc<Mutex<HookFn>>);
+
+assert_impl_all!(MetricsHook: Send, Sync, Unpin);
+
+impl MetricsHook {
+    /// Create a new hook, calling `f` for each method call.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&str, Duration, Result<(), &Error>) + Send + 'static,
+    {
+        Self(Arc::new(Mutex::new(f)))
+    }
+
+    /// Report a call of the method `method_name`, that took `elapsed` to complete with `result`.
+    pub fn record(&self, method_name: &str, elapsed: Duration, result: Result<(), &Error>) {
+        // Don't turn a panic in a previous call of the hook into a panic in every later method call.
+        let mut f = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+
+        f(method_name, elapsed, result)
+    }
+}
+
+impl fmt::Debug for MetricsHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MetricsHook").finish_non_exhaustive()
+    }
+}
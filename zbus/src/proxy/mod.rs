@@ -12,15 +12,18 @@ use std::{
     future::Future,
     ops::Deref,
     pin::Pin,
-    sync::{Arc, OnceLock, RwLock, RwLockReadGuard},
+    sync::{Arc, Mutex, OnceLock, RwLock, RwLockReadGuard},
     task::{Context, Poll},
+    time::Duration,
 };
 use tracing::{debug, info_span, instrument, trace, Instrument};
 
-use zbus_names::{BusName, InterfaceName, MemberName, UniqueName};
-use zvariant::{ObjectPath, OwnedValue, Str, Value};
+use zbus_names::{BusName, InterfaceName, MemberName, OwnedUniqueName, UniqueName};
+use zvariant::{serialized::ArrayIter, ObjectPath, OwnedValue, Signature, Str, Value};
 
 use crate::{
+    abstractions::timeout::timeout,
+    connection::PendingMethodCall,
     fdo::{self, IntrospectableProxy, NameOwnerChanged, PropertiesChangedStream, PropertiesProxy},
     message::{Flags, Message, Sequence, Type},
     AsyncDrop, Connection, Error, Executor, MatchRule, MessageStream, OwnedMatchRule, Result, Task,
@@ -28,6 +31,14 @@ use crate::{
 
 mod builder;
 pub use builder::{Builder, CacheProperties, ProxyDefault};
+mod metrics;
+pub use metrics::MetricsHook;
+mod next_signal;
+pub use next_signal::NextSignal;
+mod pipeline;
+pub use pipeline::{Pipeline, PipelineReplies, PushReply, TypedPipeline};
+mod snapshot;
+pub use snapshot::SnapshotValue;
 
 /// A client-side interface proxy.
 ///
@@ -69,6 +80,8 @@ pub use builder::{Builder, CacheProperties, ProxyDefault};
 #[derive(Clone, Debug)]
 pub struct Proxy<'a> {
     pub(crate) inner: Arc<ProxyInner<'a>>,
+    /// How long to wait for method replies, if limited.
+    pub(crate) method_timeout: Option<Duration>,
 }
 
 assert_impl_all!(Proxy<'_>: Send, Sync, Unpin);
@@ -94,15 +107,26 @@ impl fmt::Debug for ProxyInnerStatic {
 #[derive(Debug)]
 pub(crate) struct ProxyInner<'a> {
     inner_without_borrows: ProxyInnerStatic,
-    pub(crate) destination: BusName<'a>,
+    /// Only `None` on peer-to-peer connections.
+    pub(crate) destination: Option<BusName<'a>>,
     pub(crate) path: ObjectPath<'a>,
     pub(crate) interface: InterfaceName<'a>,
 
+    /// The properties caching mode.
+    cache: CacheProperties,
     /// Cache of property values.
     property_cache: Option<OnceLock<(Arc<PropertiesCache>, Task<()>)>>,
     /// Set of properties which do not get cached, by name.
     /// This overrides proxy-level caching behavior.
     uncached_properties: HashSet<Str<'a>>,
+    /// Method calls queued by `call_pipelined`, waiting for the next `flush`.
+    pipelined_calls: Mutex<Vec<Message>>,
+    /// Cache of the introspection XML, if enabled.
+    introspection_cache: Option<Mutex<Option<String>>>,
+    /// The only sender signals and method replies are accepted from, if pinned.
+    pub(crate) sender: Option<UniqueName<'a>>,
+    /// The hook method calls are reported to, by proxies generated with `metrics`.
+    metrics_hook: Option<MetricsHook>,
 }
 
 impl Drop for ProxyInnerStatic {
@@ -342,7 +366,7 @@ impl PropertiesCache {
             .inner()
             .connection()
             .call_method_raw(
-                Some(proxy.inner().destination()),
+                proxy.inner().destination_opt(),
                 proxy.inner().path(),
                 Some(proxy.inner().interface()),
                 "GetAll",
@@ -360,9 +384,20 @@ impl PropertiesCache {
                     // discard updates prior to the initial population
                 }
                 Some(Either::Right(populate)) => {
-                    populate?.body().deserialize().map(|values| {
-                        self.update_cache(&uncached_properties, &values, Vec::new(), &interface);
-                    })?;
+                    proxy
+                        .inner()
+                        .inner
+                        .check_reply_sender(populate)?
+                        .body()
+                        .deserialize()
+                        .map(|values| {
+                            self.update_cache(
+                                &uncached_properties,
+                                &values,
+                                Vec::new(),
+                                &interface,
+                            );
+                        })?;
                     break;
                 }
                 None => break,
@@ -487,29 +522,20 @@ impl PropertiesCache {
 }
 
 impl<'a> ProxyInner<'a> {
-    pub(crate) fn new(
-        conn: Connection,
-        destination: BusName<'a>,
-        path: ObjectPath<'a>,
-        interface: InterfaceName<'a>,
-        cache: CacheProperties,
-        uncached_properties: HashSet<Str<'a>>,
-    ) -> Self {
-        let property_cache = match cache {
-            CacheProperties::Yes | CacheProperties::Lazily => Some(OnceLock::new()),
-            CacheProperties::No => None,
+    /// Reject `reply` if the proxy is pinned to a sender and the reply comes from another one.
+    pub(crate) fn check_reply_sender(&self, reply: Result<Message>) -> Result<Message> {
+        let Some(sender) = &self.sender else {
+            return reply;
+        };
+        let msg = match &reply {
+            Ok(msg) | Err(Error::MethodError(_, _, msg)) => msg,
+            Err(_) => return reply,
         };
-        Self {
-            inner_without_borrows: ProxyInnerStatic {
-                conn,
-                dest_owner_change_match_rule: OnceLock::new(),
-            },
-            destination,
-            path,
-            interface,
-            property_cache,
-            uncached_properties,
+        if msg.header().sender().map(BusName::as_str) != Some(sender.as_str()) {
+            return Err(Error::InvalidReply);
         }
+
+        reply
     }
 
     /// Subscribe to the "NameOwnerChanged" signal on the bus for our destination.
@@ -522,8 +548,8 @@ impl<'a> ProxyInner<'a> {
         }
 
         let well_known_name = match &self.destination {
-            BusName::WellKnown(well_known_name) => well_known_name,
-            BusName::Unique(_) => return Ok(()),
+            Some(BusName::WellKnown(well_known_name)) => well_known_name,
+            Some(BusName::Unique(_)) | None => return Ok(()),
         };
 
         if self
@@ -624,8 +650,26 @@ impl<'a> Proxy<'a> {
     }
 
     /// Get a reference to the destination service name.
+    ///
+    /// # Panics
+    ///
+    /// If the proxy has no destination, i.e it was built with [`Builder::no_destination`]. Use
+    /// [`Proxy::destination_opt`] for such proxies.
+    ///
+    /// [`Builder::no_destination`]: crate::proxy::Builder::no_destination
     pub fn destination(&self) -> &BusName<'_> {
-        &self.inner.destination
+        self.destination_opt()
+            .expect("proxy built without a destination")
+    }
+
+    /// Get a reference to the destination service name, if any.
+    ///
+    /// This is only `None` for proxies on peer-to-peer connections, that were built with
+    /// [`Builder::no_destination`].
+    ///
+    /// [`Builder::no_destination`]: crate::proxy::Builder::no_destination
+    pub fn destination_opt(&self) -> Option<&BusName<'_>> {
+        self.inner.destination.as_ref()
     }
 
     /// Get a reference to the object path.
@@ -633,30 +677,106 @@ impl<'a> Proxy<'a> {
         &self.inner.path
     }
 
+    /// Get a reference to the only sender signals and method replies are accepted from, if any.
+    ///
+    /// See [`Builder::sender`](crate::proxy::Builder::sender) for details.
+    pub fn sender(&self) -> Option<&UniqueName<'_>> {
+        self.inner.sender.as_ref()
+    }
+
     /// Get a reference to the interface.
     pub fn interface(&self) -> &InterfaceName<'_> {
         &self.inner.interface
     }
 
+    /// The properties caching mode.
+    ///
+    /// See [`Builder::cache_properties`](crate::proxy::Builder::cache_properties) for details.
+    pub fn cache_properties(&self) -> CacheProperties {
+        self.inner.cache
+    }
+
+    /// How long method calls wait for a reply, if limited.
+    ///
+    /// See [`Builder::method_timeout`](crate::proxy::Builder::method_timeout) for details.
+    pub fn method_timeout(&self) -> Option<Duration> {
+        self.method_timeout
+    }
+
+    /// The hook method calls are reported to, if any.
+    ///
+    /// See [`Builder::metrics_hook`](crate::proxy::Builder::metrics_hook) for details.
+    pub fn metrics_hook(&self) -> Option<&MetricsHook> {
+        self.inner.metrics_hook.as_ref()
+    }
+
+    /// Create a variant of this proxy, that waits for method replies for at most `timeout`.
+    ///
+    /// The returned proxy shares the connection, as well as the property cache, with `self`.
+    /// `self` itself is left unchanged.
+    pub fn with_timeout(&self, timeout: Duration) -> Proxy<'a> {
+        Proxy {
+            inner: self.inner.clone(),
+            method_timeout: Some(timeout),
+        }
+    }
+
     /// Introspect the associated object, and return the XML description.
     ///
+    /// If introspection caching was enabled through [`Builder::cache_introspection`], only the
+    /// first call fetches the XML description, and the subsequent calls return the cached copy,
+    /// until [`Proxy::refresh_introspection`] is called.
+    ///
     /// See the [xml](xml/index.html) module for parsing the
     /// result.
     pub async fn introspect(&self) -> fdo::Result<String> {
-        let proxy = IntrospectableProxy::builder(&self.inner.inner_without_borrows.conn)
-            .destination(&self.inner.destination)?
-            .path(&self.inner.path)?
-            .build()
-            .await?;
+        if let Some(xml) = self
+            .inner
+            .introspection_cache
+            .as_ref()
+            .and_then(|cache| cache.lock().expect("lock poisoned").clone())
+        {
+            return Ok(xml);
+        }
+
+        self.refresh_introspection().await
+    }
+
+    /// Introspect the associated object, bypassing the introspection cache.
+    ///
+    /// If introspection caching is enabled, the cache is updated with the fetched XML description.
+    pub async fn refresh_introspection(&self) -> fdo::Result<String> {
+        let xml = self.fetch_introspection().await?;
+        if let Some(cache) = &self.inner.introspection_cache {
+            cache.lock().expect("lock poisoned").replace(xml.clone());
+        }
+
+        Ok(xml)
+    }
+
+    async fn fetch_introspection(&self) -> fdo::Result<String> {
+        let mut builder = IntrospectableProxy::builder(&self.inner.inner_without_borrows.conn);
+        if let Some(destination) = &self.inner.destination {
+            builder = builder.destination(destination)?;
+        }
+        if let Some(sender) = &self.inner.sender {
+            builder = builder.sender(sender)?;
+        }
+        let proxy = builder.path(&self.inner.path)?.build().await?;
 
         proxy.introspect().await
     }
 
     fn properties_proxy(&self) -> PropertiesProxy<'_> {
-        PropertiesProxy::builder(&self.inner.inner_without_borrows.conn)
+        let mut builder = PropertiesProxy::builder(&self.inner.inner_without_borrows.conn);
+        if let Some(destination) = &self.inner.destination {
             // Safe because already checked earlier
-            .destination(self.inner.destination.as_ref())
-            .unwrap()
+            builder = builder.destination(destination.as_ref()).unwrap();
+        }
+        if let Some(sender) = &self.inner.sender {
+            builder = builder.sender(sender.as_ref()).unwrap();
+        }
+        builder
             // Safe because already checked earlier
             .path(self.inner.path.as_ref())
             .unwrap()
@@ -668,10 +788,15 @@ impl<'a> Proxy<'a> {
     }
 
     fn owned_properties_proxy(&self) -> PropertiesProxy<'static> {
-        PropertiesProxy::builder(&self.inner.inner_without_borrows.conn)
+        let mut builder = PropertiesProxy::builder(&self.inner.inner_without_borrows.conn);
+        if let Some(destination) = &self.inner.destination {
             // Safe because already checked earlier
-            .destination(self.inner.destination.to_owned())
-            .unwrap()
+            builder = builder.destination(destination.to_owned()).unwrap();
+        }
+        if let Some(sender) = &self.inner.sender {
+            builder = builder.sender(sender.to_owned()).unwrap();
+        }
+        builder
             // Safe because already checked earlier
             .path(self.inner.path.to_owned())
             .unwrap()
@@ -799,6 +924,65 @@ impl<'a> Proxy<'a> {
         value.try_into().map_err(Into::into)
     }
 
+    /// Get the property `property_name`, as the variant it's transported in.
+    ///
+    /// Unlike [`Proxy::get_property`], this never consults the cache and always calls the `Get`
+    /// method of the `org.freedesktop.DBus.Properties` interface. The returned value is a
+    /// [`Value::Value`], wrapping the value of the property as is. This is useful for properties
+    /// whose type differs between implementations, as the signature of the contained value can be
+    /// inspected before converting it.
+    pub async fn get_property_variant(&self, property_name: &str) -> Result<OwnedValue> {
+        let value = self.get_proxy_property(property_name).await?;
+
+        OwnedValue::try_from(Value::Value(Box::new(value.into()))).map_err(Into::into)
+    }
+
+    /// Get the array property `property_name`, as an iterator over its elements.
+    ///
+    /// Unlike [`Proxy::get_property`], this never consults the cache and always calls the `Get`
+    /// method of the `org.freedesktop.DBus.Properties` interface. The elements are deserialized
+    /// from the reply only as the returned iterator is advanced, which avoids deserializing all of
+    /// them upfront for large arrays. Keep in mind that the iterator keeps the whole reply in
+    /// memory, until it's dropped.
+    pub async fn get_property_iter<T>(
+        &self,
+        property_name: &str,
+    ) -> Result<ArrayIter<'static, 'static, T>>
+    where
+        T: serde::de::DeserializeOwned + zvariant::Type,
+    {
+        let reply = self
+            .properties_proxy()
+            .inner()
+            .call_method("Get", &(self.inner.interface.as_ref(), property_name))
+            .await?;
+        let body = reply.body();
+        let data = body.data();
+
+        // The reply is a variant so it starts with the signature of the property.
+        let (signature, parsed) = data.deserialize::<Signature<'_>>()?;
+        let expected = <Vec<T> as zvariant::Type>::signature();
+        if signature != expected {
+            return Err(zvariant::Error::SignatureMismatch(
+                signature.to_owned(),
+                format!("`{expected}`"),
+            )
+            .into());
+        }
+
+        data.slice(parsed..).array_iter().map_err(Into::into)
+    }
+
+    /// Get all the properties of the interface.
+    ///
+    /// Unlike [`Proxy::get_property`], this never consults the cache. Effectively, call the
+    /// `GetAll` method of the `org.freedesktop.DBus.Properties` interface.
+    pub async fn get_all_properties(&self) -> fdo::Result<HashMap<String, OwnedValue>> {
+        self.properties_proxy()
+            .get_all(Some(self.inner.interface.as_ref()).into())
+            .await
+    }
+
     /// Set the property `property_name`.
     ///
     /// Effectively, call the `Set` method of the `org.freedesktop.DBus.Properties` interface.
@@ -811,6 +995,25 @@ impl<'a> Proxy<'a> {
             .await
     }
 
+    /// Set the property `property_name`, then get its value back from the server.
+    ///
+    /// This is useful for properties whose value the server may adjust, e.g by clamping it into a
+    /// range, as the returned value is the one actually stored by the server. Effectively, call the
+    /// `Set` method of the `org.freedesktop.DBus.Properties` interface, then its `Get` method.
+    /// Like [`Proxy::get_property_variant`], the cache is never consulted, since it may not be
+    /// updated yet.
+    pub async fn set_property_confirmed<'t, T, R>(&self, property_name: &str, value: T) -> Result<R>
+    where
+        T: 't + Into<Value<'t>>,
+        R: TryFrom<OwnedValue>,
+        R::Error: Into<Error>,
+    {
+        self.set_property(property_name, value).await?;
+        let value = self.get_proxy_property(property_name).await?;
+
+        value.try_into().map_err(Into::into)
+    }
+
     /// Call a method and return the reply.
     ///
     /// Typically, you would want to use [`call`] method instead. Use this method if you need to
@@ -824,17 +1027,82 @@ impl<'a> Proxy<'a> {
         M::Error: Into<Error>,
         B: serde::ser::Serialize + zvariant::DynamicType,
     {
-        self.inner
+        self.call_method_raw(self.interface(), method_name, BitFlags::empty(), body)
+            .await
+            .map(|reply| reply.expect("no reply"))
+    }
+
+    /// Call a method of another interface of the object and return the reply.
+    ///
+    /// Same as [`Proxy::call_method`], but for the method `method_name` of `interface_name`, rather
+    /// than of the interface of this proxy. This is mostly useful for the standard interfaces all
+    /// objects implement, e.g `org.freedesktop.DBus.Peer`.
+    pub async fn call_interface_method<'i, 'm, I, M, B>(
+        &self,
+        interface_name: I,
+        method_name: M,
+        body: &B,
+    ) -> Result<Message>
+    where
+        I: TryInto<InterfaceName<'i>>,
+        I::Error: Into<Error>,
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        let interface_name = interface_name.try_into().map_err(Into::into)?;
+
+        self.call_method_raw(&interface_name, method_name, BitFlags::empty(), body)
+            .await
+            .map(|reply| reply.expect("no reply"))
+    }
+
+    async fn call_method_raw<'m, M, B>(
+        &self,
+        interface_name: &InterfaceName<'_>,
+        method_name: M,
+        flags: BitFlags<Flags>,
+        body: &B,
+    ) -> Result<Option<Message>>
+    where
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        let reply = match self
+            .inner
             .inner_without_borrows
             .conn
-            .call_method(
-                Some(&self.inner.destination),
-                self.inner.path.as_str(),
-                Some(&self.inner.interface),
+            .call_method_raw(
+                self.destination_opt(),
+                self.path(),
+                Some(interface_name),
                 method_name,
+                flags,
                 body,
             )
-            .await
+            .await?
+        {
+            Some(reply) => reply,
+            None => return Ok(None),
+        };
+
+        self.wait_for_reply(reply).await.map(Some)
+    }
+
+    /// Wait for the reply of a method call, within the method timeout if any.
+    async fn wait_for_reply(&self, reply: PendingMethodCall) -> Result<Message> {
+        let reply = match self.method_timeout {
+            Some(duration) => timeout(duration, reply).await.unwrap_or_else(|| {
+                Err(Error::InputOutput(
+                    std::io::Error::new(std::io::ErrorKind::TimedOut, "method call timed out")
+                        .into(),
+                ))
+            }),
+            None => reply.await,
+        };
+
+        self.inner.check_reply_sender(reply)
     }
 
     /// Call a method and return the reply body.
@@ -877,20 +1145,10 @@ impl<'a> Proxy<'a> {
     {
         let flags = flags.iter().map(Flags::from).collect::<BitFlags<_>>();
         match self
-            .inner
-            .inner_without_borrows
-            .conn
-            .call_method_raw(
-                Some(self.destination()),
-                self.path(),
-                Some(self.interface()),
-                method_name,
-                flags,
-                body,
-            )
+            .call_method_raw(self.interface(), method_name, flags, body)
             .await?
         {
-            Some(reply) => reply.await?.body().deserialize().map(Some),
+            Some(reply) => reply.body().deserialize().map(Some),
             None => Ok(None),
         }
     }
@@ -909,6 +1167,81 @@ impl<'a> Proxy<'a> {
         Ok(())
     }
 
+    /// Queue a method call, not expecting a reply, to be sent on the next [`Proxy::flush`].
+    ///
+    /// This is meant for methods that are called very frequently and whose reply is never needed.
+    /// Instead of sending each call on its own, the calls are batched and then sent back to back.
+    /// The `NoReplyExpected` flag is set on the calling message.
+    ///
+    /// # Ordering
+    ///
+    /// Pipelined calls are sent in the order they were queued, and no other message from the same
+    /// connection is sent in between. However, they are only sent on [`Proxy::flush`], so any
+    /// calls made through the other methods in the meantime, are sent before them. The queue is
+    /// shared between clones of the proxy and any calls still queued when the last clone is
+    /// dropped, are discarded.
+    pub fn call_pipelined<'m, M, B>(&self, method_name: M, body: &B) -> Result<()>
+    where
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        let msg = self.method_call_msg(method_name, Flags::NoReplyExpected.into(), body)?;
+        self.inner
+            .pipelined_calls
+            .lock()
+            .expect("lock poisoned")
+            .push(msg);
+
+        Ok(())
+    }
+
+    /// Send all the method calls queued by [`Proxy::call_pipelined`].
+    pub async fn flush(&self) -> Result<()> {
+        let msgs = std::mem::take(&mut *self.inner.pipelined_calls.lock().expect("lock poisoned"));
+        if msgs.is_empty() {
+            return Ok(());
+        }
+
+        self.inner.inner_without_borrows.conn.send_all(&msgs).await
+    }
+
+    /// Create a [`Pipeline`], to send several method calls at once and then collect their replies.
+    ///
+    /// Unlike [`Proxy::call_pipelined`], the replies of the calls are retrieved. See [`Pipeline`]
+    /// for details.
+    pub fn pipeline(&self) -> Pipeline<'a> {
+        Pipeline::new(self.clone())
+    }
+
+    /// Build a method call message to the destination, path and interface of this proxy.
+    fn method_call_msg<'m, M, B>(
+        &self,
+        method_name: M,
+        flags: BitFlags<Flags>,
+        body: &B,
+    ) -> Result<Message>
+    where
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        let conn = &self.inner.inner_without_borrows.conn;
+        let mut builder = Message::method(self.path(), method_name)?;
+        if let Some(sender) = conn.unique_name() {
+            builder = builder.sender(sender)?
+        }
+        if let Some(destination) = self.destination_opt() {
+            builder = builder.destination(destination)?;
+        }
+        builder = builder.interface(self.interface())?;
+        for flag in flags {
+            builder = builder.with_flags(flag)?;
+        }
+
+        builder.build(body)
+    }
+
     /// Create a stream for signal named `signal_name`.
     pub async fn receive_signal<'m, M>(&self, signal_name: M) -> Result<SignalStream<'m>>
     where
@@ -954,6 +1287,38 @@ impl<'a> Proxy<'a> {
         self.receive_signals(None, &[]).await
     }
 
+    /// Subscribe to the next signal named `signal_name` emitted by this service.
+    ///
+    /// The subscription is in place once this returns, so a signal emitted afterwards is not
+    /// missed, even if it's only waited for later. This makes it possible to wait for a signal
+    /// triggered by a method call, by subscribing before making the call:
+    ///
+    /// ```no_run
+    /// # zbus::block_on(async {
+    /// # let connection = zbus::Connection::session().await?;
+    /// # let proxy = zbus::Proxy::new(&connection, "org.zbus.Device", "/org/zbus/Device", "org.zbus.Device").await?;
+    /// use std::time::Duration;
+    ///
+    /// let moved = proxy.next_signal("Moved").await?.args::<(i32, i32)>();
+    /// proxy.call_method("Move", &(1i32, 2i32)).await?;
+    /// let (_x, _y) = moved.wait(Duration::from_secs(5)).await?;
+    /// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+    /// # }).unwrap();
+    /// ```
+    ///
+    /// See [`NextSignal`] for details.
+    pub async fn next_signal<'m, M>(&self, signal_name: M) -> Result<NextSignal>
+    where
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+    {
+        let signal_name = signal_name.try_into().map_err(Into::into)?.into_owned();
+
+        self.receive_signals(Some(signal_name), &[])
+            .await
+            .map(NextSignal::new)
+    }
+
     /// Get a stream to receive property changed events.
     ///
     /// Note that zbus doesn't queue the updates. If the listener is slower than the receiver, it
@@ -994,15 +1359,20 @@ impl<'a> Proxy<'a> {
     ///
     /// Note that zbus doesn't queue the updates. If the listener is slower than the receiver, it
     /// will only receive the last update.
+    ///
+    /// Fails with [`Error::MissingParameter`] if the proxy has no destination.
     pub async fn receive_owner_changed(&self) -> Result<OwnerChangedStream<'_>> {
         use futures_util::StreamExt;
+        let destination = self
+            .destination_opt()
+            .ok_or(Error::MissingParameter("destination"))?;
         let dbus_proxy = fdo::DBusProxy::builder(self.connection())
             .cache_properties(CacheProperties::No)
             .build()
             .await?;
         Ok(OwnerChangedStream {
             stream: dbus_proxy
-                .receive_name_owner_changed_with_args(&[(0, self.destination().as_str())])
+                .receive_name_owner_changed_with_args(&[(0, destination.as_str())])
                 .await?
                 .map(Box::new(move |signal| {
                     let args = signal.args().unwrap();
@@ -1010,9 +1380,92 @@ impl<'a> Proxy<'a> {
 
                     new_owner
                 })),
-            name: self.destination().clone(),
+            name: destination.clone(),
         })
     }
+
+    /// The unique name of the connection currently owning the destination, if any.
+    ///
+    /// This calls `org.freedesktop.DBus.GetNameOwner` on the bus, and returns `None` if the
+    /// destination isn't owned by any peer. Since the owner can change at any time, the returned
+    /// name is only a snapshot. Use [`Proxy::receive_owner_changed`] to keep track of it.
+    ///
+    /// Fails with [`Error::MissingParameter`] if the proxy has no destination.
+    pub async fn owner(&self) -> Result<Option<OwnedUniqueName>> {
+        let destination = self
+            .destination_opt()
+            .ok_or(Error::MissingParameter("destination"))?;
+        let dbus_proxy = fdo::DBusProxy::builder(self.connection())
+            .cache_properties(CacheProperties::No)
+            .build()
+            .await?;
+
+        match dbus_proxy.get_name_owner(destination.clone()).await {
+            Ok(owner) => Ok(Some(owner)),
+            Err(fdo::Error::NameHasNoOwner(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Wait for the destination to be owned by a peer on the bus.
+    ///
+    /// This returns immediately if the destination is already owned, and otherwise waits for it to
+    /// be acquired, e.g by a service that is still starting up. If that doesn't happen within
+    /// `timeout`, an [`std::io::ErrorKind::TimedOut`] I/O error is returned. The timeout covers the
+    /// whole operation, including the subscription to owner changes and the owner query.
+    ///
+    /// Since there is no bus to own names on peer-to-peer connections, this returns immediately on
+    /// those.
+    pub async fn wait_for_owner(&self, timeout: Duration) -> Result<()> {
+        use futures_util::StreamExt;
+
+        // Only proxies on peer-to-peer connections lack a destination.
+        let destination = match self.destination_opt() {
+            Some(destination) if self.connection().is_bus() => destination,
+            _ => return Ok(()),
+        };
+
+        let wait = async {
+            // Subscribe before querying the owner, so we don't miss the name being acquired in
+            // between.
+            let mut owner_changes = self.receive_owner_changed().await?;
+            let dbus_proxy = fdo::DBusProxy::builder(self.connection())
+                .cache_properties(CacheProperties::No)
+                .build()
+                .await?;
+            match dbus_proxy.get_name_owner(destination.clone()).await {
+                Ok(_) => return Ok(()),
+                Err(fdo::Error::NameHasNoOwner(_)) => (),
+                Err(e) => return Err(e.into()),
+            }
+
+            while let Some(owner) = owner_changes.next().await {
+                if owner.is_some() {
+                    return Ok(());
+                }
+            }
+
+            Err(Error::InputOutput(
+                std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "owner changes stream ended",
+                )
+                .into(),
+            ))
+        };
+
+        crate::abstractions::timeout::timeout(timeout, wait)
+            .await
+            .unwrap_or_else(|| {
+                Err(Error::InputOutput(
+                    std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        "timed out waiting for the destination to be owned",
+                    )
+                    .into(),
+                ))
+            })
+    }
 }
 
 #[derive(Debug, Default)]
@@ -1120,9 +1573,16 @@ impl<'a> SignalStream<'a> {
         signal_name: Option<MemberName<'a>>,
         args: &[(u8, &str)],
     ) -> Result<SignalStream<'a>> {
-        let mut rule_builder = MatchRule::builder()
-            .msg_type(Type::Signal)
-            .sender(proxy.destination())?
+        let destination = match proxy.sender() {
+            // No need to track the owner of the destination name, if pinned to a sender.
+            Some(sender) => Some(BusName::Unique(sender.to_owned())),
+            None => proxy.destination_opt().map(BusName::to_owned),
+        };
+        let mut rule_builder = MatchRule::builder().msg_type(Type::Signal);
+        if let Some(destination) = &destination {
+            rule_builder = rule_builder.sender(destination)?;
+        }
+        rule_builder = rule_builder
             .path(proxy.path())?
             .interface(proxy.interface())?;
         if let Some(name) = &signal_name {
@@ -1134,15 +1594,23 @@ impl<'a> SignalStream<'a> {
         let signal_rule: OwnedMatchRule = rule_builder.build().to_owned().into();
         let conn = proxy.connection();
 
-        let (src_unique_name, stream) = match proxy.destination().to_owned() {
-            BusName::Unique(name) => (
+        let (src_unique_name, stream) = match destination {
+            // Without a destination, there is no bus and hence, no sender on the signals.
+            None => (
+                None,
+                join_streams(
+                    MessageStream::for_match_rule(signal_rule, conn, None).await?,
+                    None,
+                ),
+            ),
+            Some(BusName::Unique(name)) => (
                 Some(name),
                 join_streams(
                     MessageStream::for_match_rule(signal_rule, conn, None).await?,
                     None,
                 ),
             ),
-            BusName::WellKnown(name) => {
+            Some(BusName::WellKnown(name)) => {
                 use ordered_stream::OrderedStreamExt;
 
                 let name_owner_changed_rule = MatchRule::builder()
@@ -1430,6 +1898,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[timeout(15000)]
+    fn pinned_sender() {
+        block_on(test_pinned_sender()).unwrap();
+    }
+
+    async fn test_pinned_sender() -> Result<()> {
+        const PATH: &str = "/org/freedesktop/zbus/PinnedSender";
+        const IFACE: &str = "org.freedesktop.zbus.PinnedSender";
+
+        let conn = Connection::session().await?;
+        let trusted = Connection::session().await?;
+        let impostor = Connection::session().await?;
+        let trusted_name = trusted.unique_name().unwrap().clone();
+
+        // The destination name is owned by another connection than the one we trust.
+        let well_known = "org.freedesktop.zbus.async.ProxyPinnedSenderTest";
+        impostor.request_name(well_known).await?;
+
+        let proxy: Proxy<'_> = Builder::new(&conn)
+            .destination(well_known)?
+            .sender(trusted_name.as_str())?
+            .path(PATH)?
+            .interface(IFACE)?
+            .cache_properties(CacheProperties::No)
+            .build()
+            .await?;
+        assert_eq!(
+            proxy.sender().map(UniqueName::as_str),
+            Some(trusted_name.as_str())
+        );
+        let mut stream = proxy.receive_signal("Ping").await?;
+
+        impostor
+            .emit_signal(None::<()>, PATH, IFACE, "Ping", &1u32)
+            .await?;
+        trusted
+            .emit_signal(None::<()>, PATH, IFACE, "Ping", &2u32)
+            .await?;
+
+        // Only the signal from the trusted connection gets through.
+        let signal = stream.next().await.unwrap();
+        assert_eq!(
+            signal.header().sender().map(BusName::as_str),
+            Some(trusted_name.as_str())
+        );
+        assert_eq!(signal.body().deserialize::<u32>()?, 2);
+
+        Ok(())
+    }
+
     #[test]
     #[timeout(15000)]
     fn signal_stream_deadlock() {
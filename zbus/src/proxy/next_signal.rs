@@ -0,0 +1,109 @@
+use std::{fmt, time::Duration};
+
+use futures_util::StreamExt;
+use static_assertions::assert_impl_all;
+
+use crate::{message::Message, proxy::SignalStream, AsyncDrop, Error, Result};
+
+/// A subscription to the next signal of a given name.
+///
+/// Created by [`Proxy::next_signal`](crate::Proxy::next_signal), once the subscription is in place.
+/// Hence any signal emitted after its creation is received, e.g one triggered by a method call
+/// made in between. Use [`NextSignal::wait`] to get the signal, which ends the subscription.
+/// Dropping the handle ends it as well.
+///
+/// The signal is yielded as a [`Message`] by default, or as its decoded arguments after a call to
+/// [`NextSignal::args`].
+///
+/// # Example
+///
+/// ```no_run
+/// # zbus::block_on(async {
+/// use std::time::Duration;
+/// use zbus::{Connection, Proxy};
+///
+/// let connection = Connection::session().await?;
+/// let proxy = Proxy::new(
+///     &connection,
+///     "org.freedesktop.DBus",
+///     "/org/freedesktop/DBus",
+///     "org.freedesktop.DBus",
+/// )
+/// .await?;
+/// let acquired = proxy.next_signal("NameAcquired").await?.args::<String>();
+/// connection.request_name("org.zbus.NextSignalExample").await?;
+/// let _name = acquired.wait(Duration::from_secs(5)).await?;
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// # }).unwrap();
+/// ```
+pub struct NextSignal<T = Message> {
+    stream: SignalStream<'static>,
+    decode: fn(Message) -> Result<T>,
+}
+
+assert_impl_all!(NextSignal: Send, Sync, Unpin);
+
+impl NextSignal {
+    pub(crate) fn new(stream: SignalStream<'static>) -> Self {
+        Self { stream, decode: Ok }
+    }
+}
+
+impl<T> NextSignal<T> {
+    /// Yield the decoded arguments of the signal, rather than the message.
+    ///
+    /// Multiple arguments are decoded as a tuple.
+    pub fn args<A>(self) -> NextSignal<A>
+    where
+        A: serde::de::DeserializeOwned + zvariant::Type,
+    {
+        NextSignal {
+            stream: self.stream,
+            decode: |msg| msg.body().deserialize::<A>(),
+        }
+    }
+
+    /// Wait for the signal.
+    ///
+    /// If no signal is received within `timeout`, an [`std::io::ErrorKind::TimedOut`] I/O error is
+    /// returned.
+    pub async fn wait(mut self, timeout: Duration) -> Result<T> {
+        let decode = self.decode;
+        let next = async {
+            self.stream.next().await.ok_or_else(|| {
+                Error::InputOutput(
+                    std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "signal stream ended")
+                        .into(),
+                )
+            })
+        };
+        let msg = crate::abstractions::timeout::timeout(timeout, next)
+            .await
+            .unwrap_or_else(|| {
+                Err(Error::InputOutput(
+                    std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        "timed out waiting for the signal",
+                    )
+                    .into(),
+                ))
+            })?;
+        // Unsubscribe right away, rather than in the background on drop.
+        self.stream.async_drop().await;
+
+        decode(msg)
+    }
+
+    /// Consumes `self`, returning the underlying [`SignalStream`].
+    pub fn into_inner(self) -> SignalStream<'static> {
+        self.stream
+    }
+}
+
+impl<T> fmt::Debug for NextSignal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NextSignal")
+            .field("stream", &self.stream)
+            .finish_non_exhaustive()
+    }
+}
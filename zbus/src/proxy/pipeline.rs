@@ -0,0 +1,328 @@
+use std::{fmt, marker::PhantomData};
+
+use enumflags2::BitFlags;
+use futures_util::future::join_all;
+use static_assertions::assert_impl_all;
+use zbus_names::MemberName;
+use zvariant::DynamicDeserialize;
+
+use crate::{message::Message, Error, Proxy, Result};
+
+/// A batch of method calls, sent all at once.
+///
+/// Created by [`Proxy::pipeline`]. Calls are queued with [`Pipeline::call`] and only sent on
+/// [`Pipeline::send`], back to back, before waiting for any of their replies. Compared to making
+/// each call in turn, this saves the round trips between the calls and the socket is only written
+/// to once for all of them, which helps when many independent calls need to be made, e.g on
+/// startup.
+///
+/// # Ordering
+///
+/// The calls are sent in the order they were queued, and no other message from the same connection
+/// is sent in between. The replies are returned in the same order as the calls, whatever the order
+/// in which they're received. Note that the peer may still handle the calls concurrently, so they
+/// should be independent of each other.
+///
+/// # Errors
+///
+/// Each call gets its own result and the failure of one doesn't abort the others, whether the
+/// failure is the call message failing to be built, an error reply or a timeout (the
+/// [method timeout] of the proxy applies to each reply). Only if the calls can't be sent at all,
+/// they all fail with the same error.
+///
+/// # Example
+///
+/// ```
+/// # zbus::block_on(async {
+/// use zbus::{Connection, Proxy};
+///
+/// let connection = Connection::session().await?;
+/// let proxy = Proxy::new(
+///     &connection,
+///     "org.freedesktop.DBus",
+///     "/org/freedesktop/DBus",
+///     "org.freedesktop.DBus",
+/// )
+/// .await?;
+/// let replies = proxy
+///     .pipeline()
+///     .call("GetId", &())
+///     .call("NameHasOwner", &("org.freedesktop.DBus",))
+///     .call("ListNames", &())
+///     .send()
+///     .await;
+/// let mut replies = replies.into_iter();
+///
+/// let _id: String = replies.next().unwrap()?.body().deserialize()?;
+/// let has_owner: bool = replies.next().unwrap()?.body().deserialize()?;
+/// assert!(has_owner);
+/// let _names: Vec<String> = replies.next().unwrap()?.body().deserialize()?;
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// # }).unwrap();
+/// ```
+///
+/// Proxies generated by the [`proxy`](macro@crate::proxy) macro provide a [`TypedPipeline`] instead,
+/// with one method per declared method of the interface and typed results.
+///
+/// [method timeout]: crate::proxy::Builder::method_timeout
+#[derive(Clone, Debug)]
+#[must_use = "the calls are only sent by `Pipeline::send`"]
+pub struct Pipeline<'a> {
+    proxy: Proxy<'a>,
+    calls: Vec<Result<Message>>,
+}
+
+assert_impl_all!(Pipeline<'_>: Send, Sync, Unpin);
+
+impl<'a> Pipeline<'a> {
+    pub(crate) fn new(proxy: Proxy<'a>) -> Self {
+        Self {
+            proxy,
+            calls: Vec::new(),
+        }
+    }
+
+    /// Queue a call to the method `method_name`, with `body` as arguments.
+    ///
+    /// If the call message can't be built, e.g because `method_name` isn't a valid member name,
+    /// the error is returned as the result of this call by [`Pipeline::send`].
+    pub fn call<'m, M, B>(mut self, method_name: M, body: &B) -> Self
+    where
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        let msg = self
+            .proxy
+            .method_call_msg(method_name, BitFlags::empty(), body);
+        self.calls.push(msg);
+
+        self
+    }
+
+    /// The number of queued calls.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Whether no call has been queued.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Send all the queued calls and wait for their replies.
+    ///
+    /// The result of each call is returned, in the order the calls were queued.
+    pub async fn send(self) -> Vec<Result<Message>> {
+        let msgs = self
+            .calls
+            .iter()
+            .filter_map(|call| call.as_ref().ok().cloned())
+            .collect::<Vec<_>>();
+        let mut pending = match self.proxy.connection().call_all(&msgs).await {
+            Ok(pending) => pending.into_iter(),
+            Err(e) => {
+                return self
+                    .calls
+                    .into_iter()
+                    .map(|call| call.and_then(|_| Err(e.clone())))
+                    .collect();
+            }
+        };
+
+        // Each pending call has its own receiver on the bounded channel of method replies, so they
+        // must all be polled at once: waiting for the replies in turn would let the receivers of the
+        // later calls fill up, blocking the reception of any message on the connection.
+        let proxy = &self.proxy;
+        let replies = self.calls.into_iter().map(|call| {
+            let reply = call.map(|_| pending.next().expect("one pending reply per sent call"));
+
+            async move {
+                match reply {
+                    Ok(reply) => proxy.wait_for_reply(reply).await,
+                    Err(e) => Err(e),
+                }
+            }
+        });
+
+        join_all(replies).await
+    }
+}
+
+/// A [`Pipeline`] keeping track of the reply types of the queued calls.
+///
+/// Proxies generated by the [`proxy`](macro@crate::proxy) macro wrap one in the pipeline they return
+/// from their `pipeline` method, which has a method to queue a call for each declared method of the
+/// interface. The type parameter is the tuple of the results of the calls queued so far, which
+/// [`TypedPipeline::send`] returns, with each reply body deserialized to the return type of its
+/// method. Up to 16 calls can be queued.
+///
+/// Everything documented for [`Pipeline`] applies here as well.
+#[must_use = "the calls are only sent by `TypedPipeline::send`"]
+pub struct TypedPipeline<'a, R = ()> {
+    pipeline: Pipeline<'a>,
+    replies: PhantomData<fn() -> R>,
+}
+
+assert_impl_all!(TypedPipeline<'_>: Send, Sync, Unpin);
+
+impl<'a> TypedPipeline<'a> {
+    /// Create a pipeline, with no queued calls, for the calls made through `proxy`.
+    pub fn new(proxy: &Proxy<'a>) -> Self {
+        Self {
+            pipeline: proxy.pipeline(),
+            replies: PhantomData,
+        }
+    }
+}
+
+impl<'a, R> TypedPipeline<'a, R> {
+    /// Queue a call to the method `method_name`, with `body` as arguments and a reply body of type
+    /// `T`.
+    pub fn call<'m, M, B, T>(self, method_name: M, body: &B) -> TypedPipeline<'a, R::Output>
+    where
+        R: PushReply<T>,
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+        T: for<'d> DynamicDeserialize<'d>,
+    {
+        TypedPipeline {
+            pipeline: self.pipeline.call(method_name, body),
+            replies: PhantomData,
+        }
+    }
+
+    /// The number of queued calls.
+    pub fn len(&self) -> usize {
+        self.pipeline.len()
+    }
+
+    /// Whether no call has been queued.
+    pub fn is_empty(&self) -> bool {
+        self.pipeline.is_empty()
+    }
+
+    /// Send all the queued calls and wait for their replies.
+    ///
+    /// The result of each call is returned, in the order the calls were queued.
+    pub async fn send(self) -> R
+    where
+        R: PipelineReplies,
+    {
+        R::from_replies(self.pipeline.send().await)
+    }
+
+    /// Consumes `self`, returning the underlying [`Pipeline`].
+    pub fn into_inner(self) -> Pipeline<'a> {
+        self.pipeline
+    }
+}
+
+impl<R> Clone for TypedPipeline<'_, R> {
+    fn clone(&self) -> Self {
+        Self {
+            pipeline: self.pipeline.clone(),
+            replies: PhantomData,
+        }
+    }
+}
+
+impl<R> fmt::Debug for TypedPipeline<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedPipeline")
+            .field("pipeline", &self.pipeline)
+            .finish()
+    }
+}
+
+/// The results of the calls queued on a [`TypedPipeline`].
+///
+/// Implemented for tuples of up to 16 [`Result`]s, one per call.
+pub trait PipelineReplies {
+    /// Build the results from the replies to the calls, in order.
+    fn from_replies(replies: Vec<Result<Message>>) -> Self;
+}
+
+/// The results of the calls queued on a [`TypedPipeline`], with room for one more call.
+///
+/// Implemented for tuples of up to 15 [`Result`]s.
+pub trait PushReply<T>: PipelineReplies {
+    /// The results once a call with a reply body of type `T` is queued.
+    type Output: PipelineReplies;
+}
+
+fn deserialize_reply<T>(reply: Option<Result<Message>>) -> Result<T>
+where
+    T: for<'d> DynamicDeserialize<'d>,
+{
+    reply
+        .expect("one reply per queued call")
+        .and_then(|msg| msg.body().deserialize())
+}
+
+macro_rules! pipeline_replies_impls {
+    ($($name:ident)*) => {
+        impl<$($name),*> PipelineReplies for ($(Result<$name>,)*)
+        where
+            $($name: for<'d> DynamicDeserialize<'d>,)*
+        {
+            fn from_replies(replies: Vec<Result<Message>>) -> Self {
+                let mut replies = replies.into_iter();
+
+                ($(deserialize_reply::<$name>(replies.next()),)*)
+            }
+        }
+    };
+}
+
+macro_rules! push_reply_impls {
+    ($($name:ident)*) => {
+        impl<$($name,)* T> PushReply<T> for ($(Result<$name>,)*)
+        where
+            $($name: for<'d> DynamicDeserialize<'d>,)*
+            T: for<'d> DynamicDeserialize<'d>,
+        {
+            type Output = ($(Result<$name>,)* Result<T>,);
+        }
+    };
+}
+
+// No call queued, so there are no replies.
+impl PipelineReplies for () {
+    fn from_replies(_replies: Vec<Result<Message>>) -> Self {}
+}
+
+pipeline_replies_impls! { T0 }
+pipeline_replies_impls! { T0 T1 }
+pipeline_replies_impls! { T0 T1 T2 }
+pipeline_replies_impls! { T0 T1 T2 T3 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 T7 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 }
+pipeline_replies_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15 }
+
+push_reply_impls! {}
+push_reply_impls! { T0 }
+push_reply_impls! { T0 T1 }
+push_reply_impls! { T0 T1 T2 }
+push_reply_impls! { T0 T1 T2 T3 }
+push_reply_impls! { T0 T1 T2 T3 T4 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 T6 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 T6 T7 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 }
+push_reply_impls! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 }
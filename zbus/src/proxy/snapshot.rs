@@ -0,0 +1,80 @@
+use std::{collections::HashMap, ops::Deref};
+
+use zvariant::{OwnedObjectPath, OwnedSignature, OwnedValue};
+
+/// A property type that can be accessed through a properties snapshot.
+///
+/// The accessors of the `<TraitName>PropertiesSnapshot` struct generated by the [`proxy`] macro
+/// with the `properties_snapshot` attribute return the value of the property as
+/// [`SnapshotValue::Ref`]. Types dereferencing to a borrowed form are returned as a reference to
+/// their [`Deref::Target`], e.g `&str` for a `String` and `&[T]` for a `Vec<T>`, so the value
+/// borrows from the snapshot and can't outlive it. Other types are returned by reference too,
+/// except for numbers and booleans, which are copied.
+///
+/// Property getters returning a type not implementing this trait make the snapshot fail to
+/// compile. It can be implemented for custom property types.
+///
+/// [`proxy`]: macro@crate::proxy
+pub trait SnapshotValue {
+    /// The type the value is accessed as, borrowing from the snapshot.
+    type Ref<'a>
+    where
+        Self: 'a;
+
+    /// Access the value.
+    fn snapshot_ref(&self) -> Self::Ref<'_>;
+}
+
+macro_rules! deref_snapshot_value {
+    ($($ty:ty),*) => {
+        $(
+            impl SnapshotValue for $ty {
+                type Ref<'a> = &'a <$ty as Deref>::Target;
+
+                fn snapshot_ref(&self) -> Self::Ref<'_> {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+deref_snapshot_value!(String, OwnedObjectPath, OwnedSignature, OwnedValue);
+
+impl<T> SnapshotValue for Vec<T> {
+    type Ref<'a>
+        = &'a <Vec<T> as Deref>::Target
+    where
+        T: 'a;
+
+    fn snapshot_ref(&self) -> Self::Ref<'_> {
+        self
+    }
+}
+
+impl<K, V, H> SnapshotValue for HashMap<K, V, H> {
+    type Ref<'a>
+        = &'a Self
+    where
+        Self: 'a;
+
+    fn snapshot_ref(&self) -> Self::Ref<'_> {
+        self
+    }
+}
+
+macro_rules! copy_snapshot_value {
+    ($($ty:ty),*) => {
+        $(
+            impl SnapshotValue for $ty {
+                type Ref<'a> = $ty;
+
+                fn snapshot_ref(&self) -> Self::Ref<'_> {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+copy_snapshot_value!(u8, bool, i16, u16, i32, u32, i64, u64, f64);
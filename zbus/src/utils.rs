@@ -28,6 +28,55 @@ impl<T, E> ResultAdapter for Result<T, E> {
     type Err = E;
 }
 
+/// Emit the trace event of a method call made through a proxy generated with the `trace`
+/// attribute.
+///
+/// This is only public for the code generated by the [`proxy`] macro to call it. It's not part of
+/// the stable API and may change or go away in any release, without notice.
+///
+/// [`proxy`]: macro@crate::proxy
+#[cfg(feature = "proxy-trace")]
+#[doc(hidden)]
+pub fn trace_method_call<B, T, E>(
+    interface: &zbus_names::InterfaceName<'_>,
+    member: &str,
+    body: &B,
+    reply: &Result<T, E>,
+) where
+    B: ?Sized + zvariant::DynamicType,
+{
+    if !tracing::enabled!(tracing::Level::TRACE) {
+        return;
+    }
+
+    let mut signature = body.dynamic_signature();
+    if signature.starts_with(zvariant::STRUCT_SIG_START_STR) {
+        // Remove leading and trailing STRUCT delimiters, as in the message.
+        signature = signature.slice(1..signature.len() - 1);
+    }
+    tracing::trace!(
+        %interface,
+        member,
+        %signature,
+        success = reply.is_ok(),
+        "method call"
+    );
+}
+
+/// A no-op without the `proxy-trace` feature. Not part of the stable API either.
+#[cfg(not(feature = "proxy-trace"))]
+#[doc(hidden)]
+#[inline(always)]
+pub fn trace_method_call<B, T, E>(
+    _interface: &zbus_names::InterfaceName<'_>,
+    _member: &str,
+    _body: &B,
+    _reply: &Result<T, E>,
+) where
+    B: ?Sized + zvariant::DynamicType,
+{
+}
+
 #[cfg(not(feature = "tokio"))]
 #[doc(hidden)]
 pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
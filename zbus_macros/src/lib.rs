@@ -20,6 +20,8 @@ mod error;
 mod iface;
 mod proxy;
 mod utils;
+#[cfg(feature = "proxy-from-xml")]
+mod xml;
 
 /// Attribute macro for defining D-Bus proxies (using [`zbus::Proxy`] and
 /// [`zbus::blocking::Proxy`]).
@@ -28,7 +30,37 @@ mod utils;
 /// Proxy implementation, named `TraitNameProxy` and a blocking one, named `TraitNameProxyBlocking`.
 /// The proxy instances can be created with the associated `new()` or `builder()` methods. The
 /// former doesn't take any argument and uses the default service name and path. The later allows
-/// you to specify non-default proxy arguments.
+/// you to specify non-default proxy arguments. The interface name, default path and default service
+/// are also available as the `INTERFACE`, `DEFAULT_PATH` and `DEFAULT_SERVICE` associated
+/// constants, respectively. Both proxy types implement `Clone`, which is cheap: the clones share
+/// the same connection and caches, and keep the destination, path, interface and method call
+/// timeout, so a configured proxy can be handed to several tasks. With the `gen_pipeline`
+/// attribute, the asynchronous proxy also has a `pipeline` method to send several method calls at
+/// once before waiting for any of the replies.
+///
+/// The pipeline is of a generated `<TraitName>Pipeline` type, with a method to queue a call for
+/// each declared method of the trait, taking the same arguments, and a `send` method to send the
+/// queued calls. `send` returns a tuple of the results of the calls, in order, with each reply
+/// body deserialized to the return type of its method, e.g
+/// `let (count, name) = proxy.pipeline().increment(2).name().send().await;`. Methods with
+/// `no_reply`, `no_autostart` or `allow_interactive_auth`, as well as methods named `send` or
+/// `into_inner`, can't be queued. See `zbus::proxy::TypedPipeline` for details.
+///
+/// For services that may not be running yet, with the `gen_when_available` attribute, the proxy can
+/// instead be created with the associated `new_when_available()` method. It takes the same
+/// arguments as `new()`, followed by a timeout, and waits for the destination name to be owned on
+/// the bus before returning the proxy. If that doesn't happen before the timeout elapses, a timed
+/// out I/O error is returned. With the `gen_new_for_typed` attribute, the associated
+/// `new_for_typed()` method creates the proxy for a given `zbus::names::BusName` destination and
+/// `zbus::zvariant::ObjectPath` path, for when these are already validated, whatever the default
+/// service and path.
+///
+/// None of these generated methods, nor the ones described in the sections below, are generated if
+/// the trait declares a method of the same name. The declared method takes precedence.
+///
+/// No `introspect` method is generated. The introspection data of the object is available through
+/// the `introspect` method of the underlying proxy, returned by `inner()`, so the trait can declare
+/// an `introspect` method of its own, e.g for an interface with an unrelated `Introspect` method.
 ///
 /// The following attributes are supported:
 ///
@@ -48,11 +80,134 @@ mod utils;
 ///
 /// * `blocking_name` - Specify the exact name of the blocking proxy type.
 ///
+/// * `async_suffix` - Also give the blocking proxy type an asynchronous variant of each method
+///   call and property method, named with this suffix appended, e.g `async_suffix = "_async"` adds
+///   an `async fn get_foo_async` next to `fn get_foo`. This allows using a single proxy type in both
+///   blocking and asynchronous code. Both proxy types must be generated. The asynchronous variants
+///   delegate to the asynchronous proxy type, on the same underlying connection. Signals, methods
+///   returning sub-proxies (`object`) and generic methods don't get a variant. It's an error if a
+///   suffixed name collides with a method declared in the trait.
+///
+/// * `gen_peer` - Whether or not to generate the `ping` and `get_machine_id` methods (default:
+///   `true`). These call the methods of the same name of the standard `org.freedesktop.DBus.Peer`
+///   interface, on the destination and path of the proxy, regardless of the proxy's own interface.
+///   Like the other method calls, they honour the method timeout and sender of the proxy.
+///   A method of the same name declared in the trait takes precedence over the generated one. Use
+///   `gen_peer = false` to opt out, e.g if the names clash with other items of the proxy type.
+///
+/// * `gen_pipeline` - Whether or not to generate the `pipeline` method of the asynchronous proxy
+///   and the `<TraitName>Pipeline` type it returns (default: `false`). See above for details.
+///
+/// * `gen_server` - Whether or not to also generate a `TraitNameServer` trait (default: `false`),
+///   declaring the same methods and properties (but not signals) as the trait, with the same
+///   signatures. It's meant to be implemented by the service side, so the client and server
+///   definitions can't get out of sync. Dispatching calls to its implementations is not provided
+///   (yet). For each signal, the trait also provides an `emit_<signal>(conn, path, args...)`
+///   associated function, that emits the signal from the object at `path` on the given
+///   `zbus::Connection`, with its interface and member name and the typed signal arguments.
+///
+/// * `gen_member_docs` - Whether or not to add the D-Bus member name to the docs of the generated
+///   method calls (default: `true`), e.g "Calls the `GetConnectionUnixUser` method.", as a paragraph
+///   of its own after the docs of the trait method. This helps correlating the Rust methods with
+///   the bus members when reading the docs.
+///
+/// * `gen_properties` - Whether or not to generate the `properties` method and the
+///   `<TraitName>Properties` struct it returns (default: `false`). See [Properties](#properties)
+///   below for details. The interface must have properties.
+///
+/// * `gen_properties_patch` - Whether or not to generate the `set_properties` method and the
+///   `<TraitName>PropertiesPatch` struct it takes (default: `false`). See
+///   [Properties](#properties) below for details. The interface must have property setters.
+///
+/// * `gen_signal_enum` - Whether or not to generate the `receive_all_signals` method and the
+///   `<TraitName>Signal` enum it yields (default: `false`). See [Signals](#signals) below for
+///   details. The interface must have signals.
+///
+/// * `gen_with_timeout` - Whether or not to generate the `with_timeout` method (default: `false`),
+///   which returns a copy of the proxy with the given method call timeout.
+///
+/// * `gen_connection` - Whether or not to generate the `connection` method (default: `false`),
+///   which returns the connection of the proxy, e.g to create other proxies on it.
+///
+/// * `gen_when_available` - Whether or not to generate the associated `new_when_available()`
+///   method (default: `false`). See above for details.
+///
+/// * `gen_new_for_typed` - Whether or not to generate the associated `new_for_typed()` method
+///   (default: `false`). See above for details.
+///
+/// * `gen_with_path` - Whether or not to generate the `with_path` method (default: `false`), which
+///   returns a proxy for another object of the same destination and interface.
+///
+/// * `gen_owner` - Whether or not to generate the `owner` method (default: `false`), which returns
+///   the unique name of the current owner of the destination, if any.
+///
+/// * `gen_call_method` - Whether or not to generate the `call_method` method of the asynchronous
+///   proxy (default: `false`), to call methods that aren't declared in the trait by name, with the
+///   reply body deserialized to the type of the caller's choice.
+///
+/// * `gen_object_at` - Whether or not to generate the `<object>_at` methods (default: `false`). See
+///   the `object` method attribute below for details.
+///
+/// * `gen_property_changed_any` - Whether or not to generate the `receive_property_changed_any`
+///   method (default: `false`). See [Properties](#properties) below for details. The interface
+///   must have properties.
+///
+/// * `gen_receive_args` - Whether or not to generate the `receive_<signal>_args` methods of the
+///   asynchronous proxy (default: `false`). See [Signals](#signals) below for details.
+///
+/// * `gen_next_signal` - Whether or not to generate the `next_<signal>` methods (default:
+///   `false`). See [Signals](#signals) below for details.
+///
+/// * `p2p` - also generate a `new_p2p` method, that creates a proxy with the given path on a
+///   peer-to-peer connection. Since there is no bus to route the messages on such connections, the
+///   proxy doesn't have any destination, not even the `default_service`, and hence its method calls
+///   don't carry a destination header field.
+///
+/// * `object_manager` - also generate a `get_managed_objects` method, for interfaces implemented by
+///   objects under an `org.freedesktop.DBus.ObjectManager`. It calls `GetManagedObjects` on the
+///   destination and path of the proxy, i.e the proxy must be created for the path of the object
+///   manager, and returns the objects implementing the interface, along with their properties in
+///   the `<TraitName>Properties` struct (see [Properties](#properties) below). It requires
+///   `gen_properties`. Like the other method calls, it honours the method timeout and sender of the
+///   proxy.
+///
+/// * `properties_snapshot` - also generate a `properties_snapshot` method, fetching all the
+///   properties through a single `GetAll` call into a `<TraitName>PropertiesSnapshot`, which
+///   converts them to their types once. Its accessors, named after the property getters, return
+///   the properties as their `zbus::proxy::SnapshotValue::Ref` type, borrowing from the snapshot so
+///   they can't outlive it, e.g `&str` for a `String` property and `&[T]` for a `Vec<T>` one. The
+///   snapshot itself is owned, rather than borrowing from the proxy, so it can be kept around or
+///   moved to another task, and a fresh one is fetched on each call. The type of each property
+///   must implement `zbus::proxy::SnapshotValue`, which rules out tuples and arrays, and the
+///   interface must have properties.
+///
+/// * `metrics` - time each method call and report it to the `zbus::proxy::MetricsHook` of the
+///   proxy, set through the `metrics_hook` method of its builder. The hook is given the D-Bus name
+///   of the method, the elapsed time until the reply was received and whether the call succeeded.
+///   Without a hook, which is the default, the calls are only timed. Property accesses and the
+///   extra methods generated through method attributes, like `_with_flags`, are not reported.
+///
+/// * `trace` - emit a `tracing` event at trace level for each method call, with the interface, the
+///   D-Bus name of the method, the signature of the arguments and whether the call succeeded. The
+///   events are only emitted if the `proxy-trace` feature of `zbus` is enabled. Otherwise, this
+///   attribute has no effect, and no cost. As with `metrics`, property accesses and the extra
+///   methods generated through method attributes are not traced.
+///
+/// * `ord` - implement `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` for the proxy types,
+///   comparing the object paths lexicographically, and then the destinations. This allows keeping
+///   proxies in a `BTreeSet` or sorting them, e.g for a deterministic order of devices. Proxies for
+///   the same object are equal, whatever their connection or method call timeout.
+///
 /// * `assume_defaults` - whether to auto-generate values for `default_path` and `default_service`
 ///   if none are specified (default: `false`). `proxy` generates a warning if neither this
 ///   attribute nor one of the default values are specified. Please make sure to explicitly set
 ///   either this attribute or the default values, according to your needs.
 ///
+/// * `extends` - the name of another proxy trait, whose methods are merged into this one. Read the
+///   [Splitting large interfaces](#splitting-large-interfaces) section below for details.
+///
+/// * `extendable` - allow other proxy traits to extend this one through `extends`.
+///
 /// Each trait method will be expanded to call to the associated D-Bus remote interface.
 ///
 /// Trait methods accept `proxy` attributes:
@@ -60,8 +215,10 @@ mod utils;
 /// * `name` - override the D-Bus name (pascal case form by default)
 ///
 /// * `property` - expose the method as a property. If the method takes an argument, it must be a
-///   setter, with a `set_` prefix. Otherwise, it's a getter. Additional sub-attributes exists to
-///   control specific property behaviors:
+///   setter, with a `set_` prefix. Otherwise, it's a getter. The generated getters and setters are
+///   `#[inline]`, unless the trait method has its own `#[inline(..)]` attribute. Other attributes of
+///   the trait methods, including `#[inline]` for method calls, are passed on to the generated
+///   methods. Additional sub-attributes exists to control specific property behaviors:
 ///   * `emits_changed_signal` - specifies how property changes are signaled. Valid values are those
 ///     documented in [DBus specifications][dbus_emits_changed_signal]:
 ///     * `"true"` - (default) change signal is always emitted with the value included. This uses
@@ -75,6 +232,33 @@ mod utils;
 ///     * `"false"` - change signal is not (guaranteed to be) emitted if the property changes. This
 ///       disables property value caching, and does not generate a listener method for the change
 ///       signal.
+///   * `changed_values` - make the generated `receive_<name>_changed` method return a stream
+///     (iterator for the blocking proxy) of the new property values, instead of the property change
+///     events. Values that can't be retrieved or decoded are skipped. The property must emit a
+///     change signal.
+///   * `iter` - also generate a `<name>_iter` method for an array property, that returns an
+///     iterator over the elements of the array. Elements are deserialized on demand as the iterator
+///     is advanced, instead of all at once, which is useful for very large arrays. This method never
+///     uses the cache and the returned iterator keeps the whole reply message in memory until it's
+///     dropped. Hence the elements must own their data, i.e `String` rather than `&str`.
+///   * `variant` - also generate a `<name>_variant` method, that returns the property's value as
+///     the [`OwnedValue`] variant it's transported in, without unwrapping it. This is useful for
+///     properties whose type varies between implementations, as the signature of the contained
+///     value can be inspected first. This method never uses the cache.
+///   * `optional` - for properties that may be missing, e.g on older versions of a service. The
+///     getter must return an `Option` of the property value, which is `None` if the server replies
+///     with an `UnknownProperty` or `InvalidArgs` error. Other errors are returned as usual. The
+///     field of an optional property in the `properties` struct is likewise `None` if the property
+///     is missing from the `GetAll` reply.
+///   * `range` - for setters of properties with a constrained value, e.g `range = "0..=100"`. The
+///     setter returns an `InvalidArgs` error for a value outside the range, without contacting the
+///     server. Any range expression works, as long as it contains values of the setter's argument
+///     type.
+///   * `confirmed` - for setters of properties whose value the server may adjust, e.g by clamping
+///     it. Also generate a `<setter>_confirmed` method, e.g `set_volume_confirmed`, that sets the
+///     property and then gets it back from the server, returning the value actually stored. The
+///     value is returned owned, e.g a `String` for a setter taking a `&str`, and the cache is never
+///     used to get it.
 ///
 /// * `signal` - declare a signal just like a D-Bus method. Read the [Signals](#signals) section
 ///   below for details.
@@ -87,8 +271,40 @@ mod utils;
 /// * `allow_interactive_auth` - declare a method call that is allowed to trigger an interactive
 ///   prompt for authorization or confirmation from the receiver.
 ///
+/// * `pipelined` - in addition to the usual method, generate a `<method_name>_pipelined` method
+///   that queues the call, without expecting a reply, instead of sending it. The queued calls are
+///   sent, in order, through the also generated `flush` method. This is useful for methods that are
+///   called very frequently and whose reply is never needed. See [`zbus::Proxy::call_pipelined`]
+///   for the ordering guarantees.
+///
+/// * `with_message` - in addition to the usual method, generate a `<method_name>_with_message`
+///   method that returns the reply [`zbus::message::Message`] along with the deserialized reply
+///   body. This is useful if you need the header fields of the reply, e.g the sender or serial.
+///   It can not be combined with `object` or any of the method call flags above.
+///
+/// * `traced` - in addition to the usual method, generate a `<method_name>_traced` method that
+///   returns the serial number of the method call message along with the deserialized reply body.
+///   This is useful for correlating the calls in your logs with bus traces. It can not be combined
+///   with `object` or any of the method call flags above.
+///
+/// * `with_flags` - in addition to the usual method, generate a `<method_name>_with_flags` method
+///   that takes a `BitFlags<`[`zbus::proxy::MethodFlags`]`>` argument, before the method arguments, to
+///   set on the method call message. This is useful when the flags are only known at runtime. The
+///   reply body is returned as an `Option`, which is `None` if, and only if,
+///   `MethodFlags::NoReplyExpected` was passed. It can not be combined with `object`.
+///
+/// * `stream` - in addition to the usual method, generate a `<method_name>_stream` method for a
+///   method returning an array, that returns a stream (iterator for the blocking proxy) of the
+///   elements of the array. This is only a convenience for uniform consumption of the elements,
+///   e.g in async pipelines: it's backed by the usual single method call, and not by any kind of
+///   server-side streaming. It can not be combined with `object` or `no_reply`.
+///
 /// * `object` - methods that returns an [`ObjectPath`] can be annotated with the `object` attribute
-///   to specify the proxy object to be constructed from the returned [`ObjectPath`].
+///   to specify the proxy object to be constructed from the returned [`ObjectPath`]. With the
+///   `gen_object_at` attribute on the trait, for each such proxy type, a `<object>_at` method is
+///   also generated (e.g `device_at` for `object = "Device"`), creating a proxy for any given path
+///   on the same connection and destination as `self`. It fails if the path is not a valid object
+///   path. A method of the same name declared in the trait takes precedence over the generated one.
 ///
 /// * `async_object` - if the assumptions made by `object` attribute about naming of the
 ///   asynchronous proxy type, don't fit your bill, you can use this to specify its exact name.
@@ -107,6 +323,93 @@ mod utils;
 /// access to the signal arguments. It also implements `Deref<Target = Message>` to allow easy
 /// access to the underlying [`zbus::message::Message`].
 ///
+/// With the `gen_receive_args` attribute, the asynchronous proxy also provides a
+/// `receive_<method_name>_args` method, that creates a stream yielding the decoded signal arguments
+/// directly, as a tuple if there are multiple of them. Since the arguments outlive the messages
+/// they're decoded from, this method is only provided for signals with arguments that don't
+/// borrow, i.e without any references or lifetimes.
+///
+/// For the same signals, with the `gen_next_signal` attribute, both proxies also provide a
+/// `next_<method_name>` method, that subscribes to the next signal before returning a handle to it.
+/// The handle's `wait` method, taking a timeout, waits for the signal and returns its decoded
+/// arguments. Since the subscription is in place once the method returns, a signal triggered by
+/// e.g a method call made in between is not missed. See [`zbus::Proxy::next_signal`].
+///
+/// To handle all the signals of the interface at once, the `gen_signal_enum` attribute provides a
+/// `receive_all_signals` method. Its stream (iterator for the blocking proxy) yields values of a
+/// `<TraitName>Signal` enum, with one variant per declared signal, holding the `<SignalName>`
+/// wrapper. The incoming signals are dispatched to the variants by their member name, and signals
+/// not declared in the trait are skipped. Since the proxy only receives the signals of its own
+/// interface, this also works for proxies built with another interface than the declared one. If
+/// the trait declares a `receive_all_signals` method itself, the enum isn't generated either.
+///
+/// # Properties
+///
+/// With the `gen_properties` attribute, a `properties` method is also provided, that fetches
+/// all the properties at once, through a single `org.freedesktop.DBus.Properties.GetAll` call,
+/// into a struct named `<TraitName>Properties`, with one field for each getter. A property missing
+/// from the reply results in a [`zbus::fdo::Error::UnknownProperty`] error. The struct can also be
+/// built from such a dictionary of properties, through its `TryFrom` implementation. If the trait
+/// declares a method named `properties` itself, neither the method nor the struct are generated.
+///
+/// Similarly, with the `gen_properties_patch` attribute, a `set_properties` method is provided,
+/// that takes a struct named `<TraitName>PropertiesPatch`, with an `Option` field for each setter.
+/// Setters taking a reference get a field of the owned form of the referenced type (e.g `String`
+/// for `&str`). Each property that is `Some` in the patch is then set, and the ones left as `None`
+/// are skipped.
+/// Since D-Bus has no standard way to set multiple properties at once, this still results in one
+/// `org.freedesktop.DBus.Properties.Set` call per property.
+///
+/// To react to changes of any of the properties, with the `gen_property_changed_any` attribute, the
+/// `receive_property_changed_any` method returns a stream (an iterator for the blocking proxy) of
+/// `(name, value)` pairs, decoded from the `org.freedesktop.DBus.Properties.PropertiesChanged`
+/// signals for the interface. Properties that are invalidated, rather than changed, come with a
+/// `None` value.
+///
+/// # Splitting large interfaces
+///
+/// The declaration of a large interface can be split into several traits, chained through the
+/// `extends` attribute. The extended trait must be declared `extendable`:
+///
+/// ```
+/// use zbus_macros::proxy;
+/// use zbus::Result;
+///
+/// #[proxy(
+///     interface = "org.test.Manager",
+///     default_path = "/org/test/Manager",
+///     extendable
+/// )]
+/// trait ManagerUnits {
+///     fn start_unit(&self, name: &str, mode: &str) -> Result<()>;
+/// }
+///
+/// #[proxy(
+///     interface = "org.test.Manager",
+///     default_path = "/org/test/Manager",
+///     extends = "ManagerUnits"
+/// )]
+/// trait Manager {
+///     fn cancel_job(&self, id: u32) -> Result<()>;
+/// }
+/// ```
+///
+/// The methods of the extended trait (including the ones it itself extends) are added to the
+/// methods declared in the extending trait, and the proxies generated for the latter get all of
+/// them, under its own interface name and other attributes. The proxies of the extended trait are
+/// still generated as usual.
+///
+/// The methods are merged as they were written, so:
+///
+/// * The extended trait must be declared before the extending one, either in the same module or in
+///   a parent module. This is because the items are passed along through a (hidden) declarative
+///   macro, which is only generated for `extendable` traits.
+/// * The names used in the extended trait's methods must also resolve where the extending trait is
+///   declared.
+/// * Both traits must use the same macro (`proxy` or the deprecated `dbus_proxy`).
+///
+/// Declaring a method with the same name in both traits results in a compile error.
+///
 /// # Example
 ///
 /// ```no_run
@@ -191,6 +494,11 @@ mod utils;
 /// [`zbus::blocking::Proxy`]: https://docs.rs/zbus/latest/zbus/blocking/proxy/struct.Proxy.html
 /// [`zbus::SignalStream`]: https://docs.rs/zbus/latest/zbus/proxy/struct.SignalStream.html
 /// [`zbus::blocking::SignalIterator`]: https://docs.rs/zbus/latest/zbus/blocking/proxy/struct.SignalIterator.html
+/// [`zbus::Proxy::call_pipelined`]: https://docs.rs/zbus/latest/zbus/proxy/struct.Proxy.html#method.call_pipelined
+/// [`zbus::Proxy::next_signal`]: https://docs.rs/zbus/latest/zbus/proxy/struct.Proxy.html#method.next_signal
+/// [`zbus::proxy::MethodFlags`]: https://docs.rs/zbus/latest/zbus/proxy/enum.MethodFlags.html
+/// [`zbus::fdo::Error::UnknownProperty`]: https://docs.rs/zbus/latest/zbus/fdo/enum.Error.html#variant.UnknownProperty
+/// [`OwnedValue`]: https://docs.rs/zvariant/latest/zvariant/struct.OwnedValue.html
 /// [`ObjectPath`]: https://docs.rs/zvariant/latest/zvariant/struct.ObjectPath.html
 /// [dbus_emits_changed_signal]: https://dbus.freedesktop.org/doc/dbus-specification.html#introspection-format
 #[proc_macro_attribute]
@@ -202,6 +510,51 @@ pub fn proxy(attr: TokenStream, item: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Macro for generating D-Bus proxies from introspection XML, at compile time.
+///
+/// Instead of writing the trait for [`proxy`](macro@proxy) by hand, it's generated from the
+/// description of the interface in the given XML file, with its methods, properties and signals.
+/// The path to the file is relative to the directory of the `Cargo.toml` of the crate being built.
+/// The `interface` attribute is required to pick the interface in the file, and the proxy types
+/// are named after its last component, e.g `BarProxy` and `BarProxyBlocking` for `org.foo.Bar`.
+/// All other attributes of [`proxy`](macro@proxy) are supported as well.
+///
+/// The generated items are named in snake case, with the original member names set through the
+/// `name` attribute. Arguments are typed after their D-Bus signatures, like in the code generated
+/// by `zbus-xmlgen`: basic types map to their Rust equivalents, arrays to `Vec` (slices for method
+/// arguments), dictionaries to `HashMap` and structures to tuples. Signatures that can't be mapped,
+/// such as GVariant's maybe type, are reported as errors. The
+/// `org.freedesktop.DBus.Property.EmitsChangedSignal` annotation is honored.
+///
+/// This macro is only available with the `proxy-from-xml` feature enabled.
+///
+/// # Example
+///
+/// ```ignore
+/// use zbus::proxy_from_xml;
+///
+/// proxy_from_xml!(
+///     "interfaces/org.freedesktop.zbus.Calculator.xml",
+///     interface = "org.freedesktop.zbus.Calculator",
+///     default_service = "org.freedesktop.zbus",
+///     default_path = "/org/freedesktop/zbus/Calculator",
+/// );
+///
+/// # async fn call(connection: &zbus::Connection) -> zbus::Result<()> {
+/// let calculator = CalculatorProxy::new(connection).await?;
+/// let sum = calculator.add(1, 2).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "proxy-from-xml")]
+#[proc_macro]
+pub fn proxy_from_xml(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as xml::ProxyFromXmlArgs);
+    xml::expand(args)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 #[deprecated = "Use `#[proxy(...)]` proc macro with `#[zbus(...)]` item attributes instead."]
 #[proc_macro_attribute]
 pub fn dbus_proxy(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -390,7 +743,13 @@ pub fn dbus_interface(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// If a special variant marked with the `zbus` attribute is present, `From<zbus::Error>` is
 /// also implemented for your type. This variant can only have a single unnamed field of type
 /// [`zbus::Error`]. This implementation makes it possible for you to declare proxy methods to
-/// directly return this type, rather than [`zbus::Error`].
+/// directly return this type, rather than [`zbus::Error`]. Method errors named after one of the
+/// other variants are converted to that variant, along with their description, and any other
+/// error falls through to the special variant.
+///
+/// The D-Bus name of each variant is the `prefix` given on the type, followed by the name of the
+/// variant, unless overridden with the `name` attribute on the variant, e.g
+/// `#[zbus(name = "NotFound")]`.
 ///
 /// Each variant (except for the special `zbus` one) can optionally have a (named or unnamed)
 /// `String` field (which is used as the human-readable error description).
@@ -1,9 +1,10 @@
 use crate::utils::{pat_ident, typed_arg, zbus_path, PropertyEmitsChangedSignal};
-use proc_macro2::{Literal, Span, TokenStream};
+use proc_macro2::{Literal, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
-    fold::Fold, parse_quote, parse_str, punctuated::Punctuated, spanned::Spanned, Error, FnArg,
-    Ident, ItemTrait, Meta, Path, ReturnType, Token, TraitItemFn,
+    fold::Fold, parse_quote, parse_str, punctuated::Punctuated, spanned::Spanned, Error, ExprRange,
+    FnArg, GenericArgument, GenericParam, Ident, ItemTrait, Meta, Path, PathArguments, ReturnType,
+    Token, TraitItemFn, Type,
 };
 use zvariant_utils::{case, def_attrs, macros::AttrParse, old_new};
 
@@ -20,15 +21,48 @@ pub mod old {
             default_service str,
             async_name str,
             blocking_name str,
+            async_suffix str,
             gen_async bool,
-            gen_blocking bool
+            gen_blocking bool,
+            gen_peer bool,
+            gen_pipeline bool,
+            gen_server bool,
+            gen_member_docs bool,
+            gen_properties bool,
+            gen_properties_patch bool,
+            gen_signal_enum bool,
+            gen_with_timeout bool,
+            gen_connection bool,
+            gen_when_available bool,
+            gen_new_for_typed bool,
+            gen_with_path bool,
+            gen_owner bool,
+            gen_call_method bool,
+            gen_object_at bool,
+            gen_property_changed_any bool,
+            gen_receive_args bool,
+            gen_next_signal bool,
+            extends str,
+            extendable none,
+            p2p none,
+            object_manager none,
+            properties_snapshot none,
+            metrics none,
+            trace none,
+            ord none
         };
 
         pub MethodAttributes("method") {
             name str,
             property {
                 pub PropertyAttributes("property") {
-                    emits_changed_signal str
+                    emits_changed_signal str,
+                    changed_values none,
+                    iter none,
+                    variant none,
+                    optional none,
+                    range str,
+                    confirmed none
                 }
             },
             signal none,
@@ -37,7 +71,12 @@ pub mod old {
             blocking_object str,
             no_reply none,
             no_autostart none,
-            allow_interactive_auth none
+            allow_interactive_auth none,
+            pipelined none,
+            with_message none,
+            stream none,
+            traced none,
+            with_flags none
         };
     }
 }
@@ -53,15 +92,48 @@ def_attrs! {
         default_service str,
         async_name str,
         blocking_name str,
+        async_suffix str,
         gen_async bool,
-        gen_blocking bool
+        gen_blocking bool,
+        gen_peer bool,
+        gen_pipeline bool,
+        gen_server bool,
+        gen_member_docs bool,
+        gen_properties bool,
+        gen_properties_patch bool,
+        gen_signal_enum bool,
+        gen_with_timeout bool,
+        gen_connection bool,
+        gen_when_available bool,
+        gen_new_for_typed bool,
+        gen_with_path bool,
+        gen_owner bool,
+        gen_call_method bool,
+        gen_object_at bool,
+        gen_property_changed_any bool,
+        gen_receive_args bool,
+        gen_next_signal bool,
+        extends str,
+        extendable none,
+        p2p none,
+        object_manager none,
+        properties_snapshot none,
+        metrics none,
+        trace none,
+        ord none
     };
 
     pub MethodAttributes("method") {
         name str,
         property {
             pub PropertyAttributes("property") {
-                emits_changed_signal str
+                emits_changed_signal str,
+                changed_values none,
+                iter none,
+                variant none,
+                optional none,
+                range str,
+                confirmed none
             }
         },
         signal none,
@@ -70,7 +142,12 @@ def_attrs! {
         blocking_object str,
         no_reply none,
         no_autostart none,
-        allow_interactive_auth none
+        allow_interactive_auth none,
+        pipelined none,
+        with_message none,
+        stream none,
+        traced none,
+        with_flags none
     };
 }
 
@@ -98,11 +175,112 @@ impl AsyncOpts {
     }
 }
 
+// The optional convenience items to generate on the proxies, on top of the ones for the declared
+// methods. Each is enabled through its own `gen_*` attribute, so that their names can't clash with
+// the user's items unless asked for.
+pub struct GenExtras {
+    properties: bool,
+    properties_patch: bool,
+    signal_enum: bool,
+    with_timeout: bool,
+    connection: bool,
+    when_available: bool,
+    new_for_typed: bool,
+    with_path: bool,
+    owner: bool,
+    call_method: bool,
+    object_at: bool,
+    property_changed_any: bool,
+    receive_args: bool,
+    next_signal: bool,
+}
+
+// The attributes of a property getter or setter.
+struct PropertyAttrs {
+    emits_changed_signal: PropertyEmitsChangedSignal,
+    changed_values: bool,
+    iter: bool,
+    variant: bool,
+    optional: bool,
+    range: Option<ExprRange>,
+    confirmed: bool,
+}
+
+impl PropertyAttrs {
+    // Parse the attributes of the property method `m`, checking they apply to it.
+    fn parse(attrs: PropertyAttributes, m: &TraitItemFn) -> Result<Self, Error> {
+        let is_setter = m.sig.inputs.len() > 1;
+        let emits_changed_signal = match &attrs.emits_changed_signal {
+            Some(s) => PropertyEmitsChangedSignal::parse(s, m.span())?,
+            None => PropertyEmitsChangedSignal::True,
+        };
+        if attrs.changed_values
+            && matches!(
+                emits_changed_signal,
+                PropertyEmitsChangedSignal::False | PropertyEmitsChangedSignal::Const
+            )
+        {
+            return Err(Error::new_spanned(
+                &m.sig,
+                "`changed_values` requires the property to emit a change signal",
+            ));
+        }
+
+        let getter_only = [
+            (attrs.iter, "iter"),
+            (attrs.variant, "variant"),
+            (attrs.optional, "optional"),
+        ];
+        if let Some((_, attr)) = getter_only.iter().find(|(set, _)| *set && is_setter) {
+            return Err(Error::new_spanned(
+                &m.sig,
+                format!("`{attr}` can only be used on property getters"),
+            ));
+        }
+        if attrs.confirmed && !is_setter {
+            return Err(Error::new_spanned(
+                &m.sig,
+                "`confirmed` can only be used on property setters",
+            ));
+        }
+        let range =
+            match &attrs.range {
+                Some(_) if !is_setter => {
+                    return Err(Error::new_spanned(
+                        &m.sig,
+                        "`range` can only be used on property setters",
+                    ))
+                }
+                Some(range) => Some(parse_str::<ExprRange>(range).map_err(|e| {
+                    Error::new(m.span(), format!("invalid `range` expression: {e}"))
+                })?),
+                None => None,
+            };
+
+        Ok(Self {
+            emits_changed_signal,
+            changed_values: attrs.changed_values,
+            iter: attrs.iter,
+            variant: attrs.variant,
+            optional: attrs.optional,
+            range,
+            confirmed: attrs.confirmed,
+        })
+    }
+}
+
 pub fn expand<I: AttrParse + Into<ImplAttrs>, M: AttrParse + Into<MethodAttrs>>(
     args: Punctuated<Meta, Token![,]>,
-    input: ItemTrait,
+    mut input: ItemTrait,
 ) -> Result<TokenStream, Error> {
+    let zbus = zbus_path();
+    let forwarded_args: Punctuated<Meta, Token![,]> = args
+        .iter()
+        .filter(|arg| !arg.path().is_ident("extends"))
+        .cloned()
+        .collect();
     let (
+        proxy_macro,
         interface,
         name,
         assume_defaults,
@@ -110,10 +288,38 @@ pub fn expand<I: AttrParse + Into<ImplAttrs>, M: AttrParse + Into<MethodAttrs>>(
         default_service,
         async_name,
         blocking_name,
+        async_suffix,
         gen_async,
         gen_blocking,
+        gen_peer,
+        gen_pipeline,
+        gen_server,
+        gen_member_docs,
+        gen_properties,
+        gen_properties_patch,
+        gen_signal_enum,
+        gen_with_timeout,
+        gen_connection,
+        gen_when_available,
+        gen_new_for_typed,
+        gen_with_path,
+        gen_owner,
+        gen_call_method,
+        gen_object_at,
+        gen_property_changed_any,
+        gen_receive_args,
+        gen_next_signal,
+        extends,
+        extendable,
+        gen_p2p,
+        gen_object_manager,
+        gen_properties_snapshot,
+        gen_metrics,
+        gen_trace,
+        gen_ord,
     ) = match I::parse_nested_metas(args)?.into() {
         ImplAttrs::Old(old) => (
+            quote! { #zbus::dbus_proxy },
             old.interface,
             old.name,
             old.assume_defaults,
@@ -121,10 +327,38 @@ pub fn expand<I: AttrParse + Into<ImplAttrs>, M: AttrParse + Into<MethodAttrs>>(
             old.default_service,
             old.async_name,
             old.blocking_name,
+            old.async_suffix,
             old.gen_async,
             old.gen_blocking,
+            old.gen_peer,
+            old.gen_pipeline,
+            old.gen_server,
+            old.gen_member_docs,
+            old.gen_properties,
+            old.gen_properties_patch,
+            old.gen_signal_enum,
+            old.gen_with_timeout,
+            old.gen_connection,
+            old.gen_when_available,
+            old.gen_new_for_typed,
+            old.gen_with_path,
+            old.gen_owner,
+            old.gen_call_method,
+            old.gen_object_at,
+            old.gen_property_changed_any,
+            old.gen_receive_args,
+            old.gen_next_signal,
+            old.extends,
+            old.extendable,
+            old.p2p,
+            old.object_manager,
+            old.properties_snapshot,
+            old.metrics,
+            old.trace,
+            old.ord,
         ),
         ImplAttrs::New(new) => (
+            quote! { #zbus::proxy },
             new.interface,
             new.name,
             new.assume_defaults,
@@ -132,11 +366,92 @@ pub fn expand<I: AttrParse + Into<ImplAttrs>, M: AttrParse + Into<MethodAttrs>>(
             new.default_service,
             new.async_name,
             new.blocking_name,
+            new.async_suffix,
             new.gen_async,
             new.gen_blocking,
+            new.gen_peer,
+            new.gen_pipeline,
+            new.gen_server,
+            new.gen_member_docs,
+            new.gen_properties,
+            new.gen_properties_patch,
+            new.gen_signal_enum,
+            new.gen_with_timeout,
+            new.gen_connection,
+            new.gen_when_available,
+            new.gen_new_for_typed,
+            new.gen_with_path,
+            new.gen_owner,
+            new.gen_call_method,
+            new.gen_object_at,
+            new.gen_property_changed_any,
+            new.gen_receive_args,
+            new.gen_next_signal,
+            new.extends,
+            new.extendable,
+            new.p2p,
+            new.object_manager,
+            new.properties_snapshot,
+            new.metrics,
+            new.trace,
+            new.ord,
         ),
     };
 
+    if let Some(extends) = extends {
+        // Hand the trait over to the extended trait's items macro, which merges in its items and
+        // applies this attribute again, now without `extends`.
+        let base = parse_str::<Ident>(&extends).map_err(|_| {
+            Error::new(
+                input.span(),
+                "`extends` must name an `extendable` proxy trait declared before this one",
+            )
+        })?;
+        let items_macro = proxy_items_macro(&base);
+        let ItemTrait {
+            attrs,
+            vis,
+            ident,
+            items,
+            ..
+        } = &input;
+
+        return Ok(quote! {
+            #items_macro! {
+                {
+                    #[#proxy_macro(#forwarded_args)]
+                    #(#attrs)*
+                    #vis trait #ident
+                }
+                {
+                    #(#items)*
+                }
+            }
+        });
+    }
+
+    let mut method_names = vec![];
+    for item in &mut input.items {
+        if let syn::TraitItem::Fn(m) = item {
+            // Items merged in through `extends` come out of a `macro_rules!` expansion, so their
+            // `self` wouldn't resolve against the `self` in the method bodies we generate.
+            if let Some(FnArg::Receiver(receiver)) = m.sig.inputs.first_mut() {
+                receiver.self_token.span = Span::call_site();
+            }
+
+            if method_names.contains(&m.sig.ident) {
+                return Err(Error::new_spanned(
+                    &m.sig.ident,
+                    format!(
+                        "method `{}` is declared more than once (possibly through `extends`)",
+                        m.sig.ident
+                    ),
+                ));
+            }
+            method_names.push(m.sig.ident.clone());
+        }
+    }
+
     let iface_name = match (interface, name) {
         (Some(name), None) | (None, Some(name)) => Ok(Some(name)),
         (None, None) => Ok(None),
@@ -147,6 +462,25 @@ pub fn expand<I: AttrParse + Into<ImplAttrs>, M: AttrParse + Into<MethodAttrs>>(
     }?;
     let gen_async = gen_async.unwrap_or(true);
     let gen_blocking = gen_blocking.unwrap_or(true);
+    let gen_peer = gen_peer.unwrap_or(true);
+    let gen_pipeline = gen_pipeline.unwrap_or(false);
+    let gen_member_docs = gen_member_docs.unwrap_or(true);
+    let extras = GenExtras {
+        properties: gen_properties.unwrap_or(false),
+        properties_patch: gen_properties_patch.unwrap_or(false),
+        signal_enum: gen_signal_enum.unwrap_or(false),
+        with_timeout: gen_with_timeout.unwrap_or(false),
+        connection: gen_connection.unwrap_or(false),
+        when_available: gen_when_available.unwrap_or(false),
+        new_for_typed: gen_new_for_typed.unwrap_or(false),
+        with_path: gen_with_path.unwrap_or(false),
+        owner: gen_owner.unwrap_or(false),
+        call_method: gen_call_method.unwrap_or(false),
+        object_at: gen_object_at.unwrap_or(false),
+        property_changed_any: gen_property_changed_any.unwrap_or(false),
+        receive_args: gen_receive_args.unwrap_or(false),
+        next_signal: gen_next_signal.unwrap_or(false),
+    };
 
     // Some sanity checks
     assert!(
@@ -162,23 +496,41 @@ pub fn expand<I: AttrParse + Into<ImplAttrs>, M: AttrParse + Into<MethodAttrs>>(
         "Can't set asynchronous proxy's name if you disabled it. 😸",
     );
 
+    if async_suffix.is_some() && !(gen_async && gen_blocking) {
+        return Err(Error::new(
+            input.span(),
+            "`async_suffix` requires both the asynchronous and blocking proxies",
+        ));
+    }
+
+    let blocking_proxy_name = blocking_name.unwrap_or_else(|| {
+        if gen_async {
+            format!("{}ProxyBlocking", input.ident)
+        } else {
+            // When only generating blocking proxy, there is no need for a suffix.
+            format!("{}Proxy", input.ident)
+        }
+    });
+    let async_proxy_name = async_name.unwrap_or_else(|| format!("{}Proxy", input.ident));
     let blocking_proxy = if gen_blocking {
-        let proxy_name = blocking_name.unwrap_or_else(|| {
-            if gen_async {
-                format!("{}ProxyBlocking", input.ident)
-            } else {
-                // When only generating blocking proxy, there is no need for a suffix.
-                format!("{}Proxy", input.ident)
-            }
-        });
         create_proxy::<M>(
             &input,
             iface_name.as_deref(),
             assume_defaults,
             default_path.as_deref(),
             default_service.as_deref(),
-            &proxy_name,
+            &blocking_proxy_name,
             true,
+            gen_peer,
+            gen_pipeline,
+            gen_p2p,
+            gen_object_manager,
+            gen_properties_snapshot,
+            gen_metrics,
+            gen_trace,
+            gen_ord,
+            gen_member_docs,
+            &extras,
             // Signal args structs are shared between the two proxies so always generate it for
             // async proxy only unless async proxy generation is disabled.
             !gen_async,
@@ -187,28 +539,274 @@ pub fn expand<I: AttrParse + Into<ImplAttrs>, M: AttrParse + Into<MethodAttrs>>(
         quote! {}
     };
     let async_proxy = if gen_async {
-        let proxy_name = async_name.unwrap_or_else(|| format!("{}Proxy", input.ident));
         create_proxy::<M>(
             &input,
             iface_name.as_deref(),
             assume_defaults,
             default_path.as_deref(),
             default_service.as_deref(),
-            &proxy_name,
+            &async_proxy_name,
             false,
+            gen_peer,
+            gen_pipeline,
+            gen_p2p,
+            gen_object_manager,
+            gen_properties_snapshot,
+            gen_metrics,
+            gen_trace,
+            gen_ord,
+            gen_member_docs,
+            &extras,
             true,
         )?
     } else {
         quote! {}
     };
 
+    let async_suffixed_methods = if let Some(suffix) = async_suffix {
+        gen_async_suffixed_methods::<M>(
+            &input,
+            &Ident::new(&blocking_proxy_name, Span::call_site()),
+            &Ident::new(&async_proxy_name, Span::call_site()),
+            &suffix,
+        )?
+    } else {
+        quote! {}
+    };
+
+    let server_trait = if gen_server.unwrap_or(false) {
+        gen_server_trait::<M>(&input, iface_name.as_deref())?
+    } else {
+        quote! {}
+    };
+
+    // Allows other proxy traits to pull in our items through `extends`.
+    let items_macro = if extendable {
+        let items_macro = proxy_items_macro(&input.ident);
+        let items = &input.items;
+
+        quote! {
+            #[allow(unused_macros)]
+            #[doc(hidden)]
+            macro_rules! #items_macro {
+                ({ $($head:tt)* } { $($item:tt)* }) => {
+                    $($head)* {
+                        #(#items)*
+                        $($item)*
+                    }
+                };
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     Ok(quote! {
         #blocking_proxy
 
         #async_proxy
+
+        #async_suffixed_methods
+
+        #server_trait
+
+        #items_macro
+    })
+}
+
+fn gen_server_trait<M: AttrParse + Into<MethodAttrs>>(
+    input: &ItemTrait,
+    iface_name: Option<&str>,
+) -> Result<TokenStream, Error> {
+    let zbus = zbus_path();
+    let server_name = format_ident!("{}Server", input.ident);
+    let iface_name = iface_name
+        .map(ToString::to_string)
+        .unwrap_or(format!("org.freedesktop.{}", input.ident));
+    let doc = format!(
+        " Server-side counterpart of the `{}` proxy trait, declaring the same methods and \
+        properties, and providing helpers to emit its signals.",
+        input.ident,
+    );
+    let mut methods = vec![];
+    let mut emitters = vec![];
+    for item in &input.items {
+        let m = match item {
+            syn::TraitItem::Fn(m) => m,
+            _ => continue,
+        };
+        let (name, signal) = match <M>::parse(&m.attrs)?.into() {
+            MethodAttrs::Old(old) => (old.name, old.signal),
+            MethodAttrs::New(new) => (new.name, new.signal),
+        };
+        // Signals are emitted by the server, not implemented by it.
+        if signal {
+            emitters.push(gen_server_signal_emitter(
+                &zbus,
+                &iface_name,
+                name.as_deref(),
+                m,
+            )?);
+            continue;
+        }
+
+        let mut m = m.clone();
+        m.attrs
+            .retain(|a| !a.path().is_ident("zbus") && !a.path().is_ident("dbus_proxy"));
+        m.default = None;
+        m.semi_token = Some(Default::default());
+        methods.push(m);
+    }
+
+    Ok(quote! {
+        #[doc = #doc]
+        pub trait #server_name {
+            #(#methods)*
+
+            #(#emitters)*
+        }
+    })
+}
+
+fn gen_server_signal_emitter(
+    zbus: &TokenStream,
+    iface_name: &str,
+    name: Option<&str>,
+    m: &TraitItemFn,
+) -> Result<TokenStream, Error> {
+    let method_name = m.sig.ident.to_string();
+    let member_name = name
+        .map(ToString::to_string)
+        .unwrap_or_else(|| case::pascal_or_camel_case(&method_name, true));
+    let emit = format_ident!("emit_{}", method_name);
+    let doc = format!(
+        " Emit the `{member_name}` signal from the object at `path`, on the `{iface_name}` \
+        interface, with the given arguments.",
+    );
+    let inputs: Vec<_> = m.sig.inputs.iter().filter_map(typed_arg).collect();
+    let args = inputs
+        .iter()
+        .map(|arg| {
+            pat_ident(arg)
+                .ok_or_else(|| Error::new_spanned(arg, "signal arguments must be identifiers"))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    // Lifetimes must come before our type parameter.
+    let (lifetimes, params): (Vec<_>, Vec<_>) = m
+        .sig
+        .generics
+        .params
+        .iter()
+        .partition(|param| matches!(param, GenericParam::Lifetime(_)));
+    let where_predicates = m
+        .sig
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|w| &w.predicates);
+
+    Ok(quote! {
+        #[doc = #doc]
+        fn #emit<'p, #(#lifetimes,)* P, #(#params),*>(
+            conn: &#zbus::Connection,
+            path: P,
+            #(#inputs),*
+        ) -> impl ::std::future::Future<Output = #zbus::Result<()>> + ::std::marker::Send
+        where
+            Self: ::std::marker::Sized,
+            P: ::std::convert::TryInto<#zbus::zvariant::ObjectPath<'p>>,
+            P::Error: ::std::convert::Into<#zbus::Error>,
+            #(#where_predicates,)*
+        {
+            let path = ::std::convert::TryInto::try_into(path).map_err(::std::convert::Into::into);
+
+            async move {
+                conn.emit_signal(
+                    ::std::option::Option::None::<()>,
+                    path?,
+                    #iface_name,
+                    #member_name,
+                    &(#(#args,)*),
+                )
+                .await
+            }
+        }
+    })
+}
+
+// Give the blocking proxy an asynchronous variant of each method call and property, named with
+// `suffix` appended, that delegates to the asynchronous proxy.
+fn gen_async_suffixed_methods<M: AttrParse + Into<MethodAttrs>>(
+    input: &ItemTrait,
+    blocking_proxy_name: &Ident,
+    async_proxy_name: &Ident,
+    suffix: &str,
+) -> Result<TokenStream, Error> {
+    let mut methods = TokenStream::new();
+    for item in &input.items {
+        let syn::TraitItem::Fn(m) = item else {
+            continue;
+        };
+        let (signal, object) = match <M>::parse(&m.attrs)?.into() {
+            MethodAttrs::Old(old) => (old.signal, old.object),
+            MethodAttrs::New(new) => (new.signal, new.object),
+        };
+        // Signals already have the same API on both proxies, and sub-proxies differ between them.
+        // Generic methods are left out as their bounds are added by the method call generation.
+        if signal || object.is_some() || m.sig.generics.type_params().next().is_some() {
+            continue;
+        }
+
+        let method = &m.sig.ident;
+        let name = format!("{method}{suffix}");
+        let suffixed = parse_str::<Ident>(&name).map_err(|_| {
+            Error::new(
+                input.span(),
+                format!("`async_suffix` makes `{name}` an invalid method name"),
+            )
+        })?;
+        if input
+            .items
+            .iter()
+            .any(|item| matches!(item, syn::TraitItem::Fn(other) if other.sig.ident == suffixed))
+        {
+            return Err(Error::new_spanned(
+                method,
+                format!("`{name}` is already declared, pick another `async_suffix`"),
+            ));
+        }
+
+        let mut signature = m.sig.clone();
+        signature.ident = suffixed;
+        signature.asyncness = Some(Default::default());
+        let args = m
+            .sig
+            .inputs
+            .iter()
+            .filter_map(typed_arg)
+            .filter_map(pat_ident);
+        let doc = format!(" Asynchronous variant of [`Self::{method}`].");
+        methods.extend(quote! {
+            #[doc = #doc]
+            pub #signature {
+                #async_proxy_name::from(::std::clone::Clone::clone(self.0.inner()))
+                    .#method(#(#args),*)
+                    .await
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl<'p> #blocking_proxy_name<'p> {
+            #methods
+        }
     })
 }
 
+fn proxy_items_macro(trait_ident: &Ident) -> Ident {
+    format_ident!("__{}_proxy_items", trait_ident)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
     input: &ItemTrait,
@@ -218,6 +816,16 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
     default_service: Option<&str>,
     proxy_name: &str,
     blocking: bool,
+    gen_peer: bool,
+    gen_pipeline: bool,
+    gen_p2p: bool,
+    gen_object_manager: bool,
+    gen_properties_snapshot: bool,
+    gen_metrics: bool,
+    gen_trace: bool,
+    gen_ord: bool,
+    gen_member_docs: bool,
+    extras: &GenExtras,
     gen_sig_args: bool,
 ) -> Result<TokenStream, Error> {
     let zbus = zbus_path();
@@ -250,23 +858,36 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
     let mut stream_types = TokenStream::new();
     let mut has_properties = false;
     let mut uncached_properties: Vec<String> = vec![];
+    let mut property_getters = vec![];
+    let mut property_setters: Vec<PropertySetter> = vec![];
+    let mut signal_names = vec![];
+    let mut has_pipelined = false;
+    let mut sub_proxies: Vec<(Ident, Path)> = vec![];
+    let pipeline_name = format_ident!("{}Pipeline", input.ident);
+    let mut pipeline_entries = TokenStream::new();
 
     let async_opts = AsyncOpts::new(blocking);
 
     for i in input.items.iter() {
         if let syn::TraitItem::Fn(m) = i {
-            let (mut name, signal, property) = match <M>::parse(&m.attrs)?.into() {
+            let (mut name, signal, property, pipelined) = match <M>::parse(&m.attrs)?.into() {
                 MethodAttrs::Old(old) => (
                     old.name,
                     old.signal,
-                    old.property.map(|property| property.emits_changed_signal),
-                ),
-                MethodAttrs::New(new) => (
-                    new.name,
-                    new.signal,
-                    new.property.map(|property| property.emits_changed_signal),
+                    old.property.map(|property| PropertyAttributes {
+                        emits_changed_signal: property.emits_changed_signal,
+                        changed_values: property.changed_values,
+                        iter: property.iter,
+                        variant: property.variant,
+                        optional: property.optional,
+                        range: property.range,
+                        confirmed: property.confirmed,
+                    }),
+                    old.pipelined,
                 ),
+                MethodAttrs::New(new) => (new.name, new.signal, new.property, new.pipelined),
             };
+            has_pipelined |= pipelined;
 
             let method_name = m.sig.ident.to_string();
 
@@ -274,10 +895,25 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
             let is_property = property.is_some();
             let has_inputs = m.sig.inputs.len() > 1;
 
+            if is_property && has_inputs {
+                if m.sig.inputs.len() > 2 {
+                    return Err(Error::new_spanned(
+                        &m.sig.inputs,
+                        "property setters must take exactly one value argument besides `&self`",
+                    ));
+                }
+                if name.is_none() && !method_name.starts_with("set_") {
+                    return Err(Error::new_spanned(
+                        &m.sig.ident,
+                        "property setters must be named `set_<property>`, or specify the \
+                        property `name`",
+                    ));
+                }
+            }
+
             let member_name = name.take().unwrap_or_else(|| {
                 case::pascal_or_camel_case(
                     if is_property && has_inputs {
-                        assert!(method_name.starts_with("set_"));
                         &method_name[4..]
                     } else {
                         &method_name
@@ -286,26 +922,58 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
                 )
             });
 
-            let m = if let Some(prop_attrs) = &property {
+            let property = property
+                .map(|property| PropertyAttrs::parse(property, m))
+                .transpose()?;
+            let m = if let Some(property) = &property {
                 has_properties = true;
 
-                let emits_changed_signal = if let Some(s) = &prop_attrs {
-                    PropertyEmitsChangedSignal::parse(s, m.span())?
-                } else {
-                    PropertyEmitsChangedSignal::True
-                };
-
-                if let PropertyEmitsChangedSignal::False = emits_changed_signal {
+                if let PropertyEmitsChangedSignal::False = property.emits_changed_signal {
                     uncached_properties.push(member_name.clone());
                 }
 
-                gen_proxy_property(
-                    &member_name,
-                    &method_name,
-                    m,
-                    &async_opts,
-                    emits_changed_signal,
-                )
+                if let (false, true, ReturnType::Type(_, ty)) =
+                    (has_inputs, m.sig.generics.params.is_empty(), &m.sig.output)
+                {
+                    // The properties struct owns its values so any borrows must be `'static`.
+                    let ty = SetLifetimeStatic.fold_type((**ty).clone());
+                    property_getters.push((
+                        member_name.clone(),
+                        m.sig.ident.clone(),
+                        ty,
+                        property.optional,
+                    ));
+                }
+                if let (true, true, Some(value)) = (
+                    has_inputs,
+                    m.sig.generics.params.is_empty(),
+                    m.sig.inputs.last().and_then(typed_arg),
+                ) {
+                    // The patch struct owns its values, so a borrowed value is stored in its owned
+                    // form, and borrowed back to be set.
+                    let (ty, borrowed) = match &*value.ty {
+                        Type::Reference(r) => {
+                            let elem = SetLifetimeStatic.fold_type((*r.elem).clone());
+                            let ty = parse_quote!(<#elem as ::std::borrow::ToOwned>::Owned);
+
+                            (ty, Some(elem))
+                        }
+                        ty => (SetLifetimeStatic.fold_type(ty.clone()), None),
+                    };
+                    let field = format_ident!(
+                        "{}",
+                        method_name.strip_prefix("set_").unwrap_or(&method_name)
+                    );
+                    property_setters.push((
+                        member_name.clone(),
+                        field,
+                        ty,
+                        borrowed,
+                        property.range.clone(),
+                    ));
+                }
+
+                gen_proxy_property(&member_name, &method_name, m, &async_opts, property)?
             } else if is_signal {
                 let (method, types) = gen_proxy_signal(
                     &proxy_name,
@@ -314,25 +982,165 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
                     &method_name,
                     m,
                     &async_opts,
+                    extras,
                     gen_sig_args,
                 );
                 stream_types.extend(types);
+                signal_names.push(member_name.clone());
 
                 method
             } else {
-                gen_proxy_method_call::<M>(
+                let (method, sub_proxy, pipeline_entry) = gen_proxy_method_call::<M>(
                     &member_name,
                     &method_name,
                     m,
                     <M>::parse(&m.attrs)?,
                     &async_opts,
-                )?
+                    gen_metrics,
+                    gen_trace,
+                    gen_member_docs,
+                    &pipeline_name,
+                )?;
+                pipeline_entries.extend(pipeline_entry);
+                if let Some(sub_proxy) = sub_proxy {
+                    if !sub_proxies.contains(&sub_proxy) {
+                        sub_proxies.push(sub_proxy);
+                    }
+                }
+
+                method
             };
             methods.extend(m);
         }
     }
 
-    let AsyncOpts { usage, wait, .. } = async_opts;
+    // A method of the same name declared in the trait takes precedence.
+    let has_method = |name: &str| {
+        input.items.iter().any(|item| match item {
+            syn::TraitItem::Fn(m) => m.sig.ident == name,
+            _ => false,
+        })
+    };
+    if extras.properties && property_getters.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "`gen_properties` requires the interface to have properties",
+        ));
+    }
+    // A `properties` method declared in the trait replaces the generated one, and its struct.
+    let gen_properties = extras.properties && !has_method("properties");
+
+    if gen_object_manager && property_getters.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "`object_manager` requires the interface to have properties",
+        ));
+    }
+    if gen_object_manager && !gen_properties {
+        return Err(Error::new(
+            input.span(),
+            "`object_manager` requires `gen_properties`, and no `properties` method declared in \
+            the trait",
+        ));
+    }
+    if gen_properties_snapshot && property_getters.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "`properties_snapshot` requires the interface to have properties",
+        ));
+    }
+    if gen_properties_snapshot {
+        // Tuples and arrays can't implement `SnapshotValue`, so better say so upfront than let the
+        // accessors fail to compile.
+        for (_, _, ty, optional) in &property_getters {
+            let mut value_type = first_type_arg(ty);
+            if *optional {
+                value_type = value_type.and_then(first_type_arg);
+            }
+            if let Some(value_type @ (Type::Tuple(_) | Type::Array(_))) = value_type {
+                return Err(Error::new_spanned(
+                    value_type,
+                    "`properties_snapshot` doesn't support tuple and array property types, as \
+                    they can't implement `zbus::proxy::SnapshotValue`",
+                ));
+            }
+        }
+    }
+    let (properties_struct_decl, properties_method) = if gen_properties {
+        gen_proxy_properties(
+            &input.ident,
+            &iface_name,
+            &property_getters,
+            &async_opts,
+            gen_object_manager,
+            gen_sig_args,
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+    let (snapshot_struct_decl, snapshot_method) = if gen_properties_snapshot {
+        gen_proxy_properties_snapshot(
+            &input.ident,
+            &iface_name,
+            &property_getters,
+            &async_opts,
+            gen_sig_args,
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+    if extras.properties_patch && property_setters.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "`gen_properties_patch` requires the interface to have property setters",
+        ));
+    }
+    let (patch_struct_decl, set_properties_method) =
+        if !extras.properties_patch || has_method("set_properties") {
+            (quote! {}, quote! {})
+        } else {
+            gen_proxy_properties_patch(
+                &input.ident,
+                &iface_name,
+                &property_setters,
+                &async_opts,
+                gen_sig_args,
+            )
+        };
+    if extras.signal_enum && signal_names.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "`gen_signal_enum` requires the interface to have signals",
+        ));
+    }
+    let (signals_enum_decl, receive_all_signals_method) =
+        if !extras.signal_enum || has_method("receive_all_signals") {
+            (quote! {}, quote! {})
+        } else {
+            gen_proxy_signals_enum(
+                &input.ident,
+                &iface_name,
+                &signal_names,
+                &async_opts,
+                gen_sig_args,
+            )
+        };
+    let AsyncOpts { usage, wait, .. } = &async_opts;
+    let flush_method = if has_pipelined {
+        quote! {
+            /// Send all the queued pipelined method calls.
+            pub #usage fn flush(&self) -> #zbus::Result<()> {
+                self.0.flush()#wait
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let peer_methods = if gen_peer {
+        gen_proxy_peer_methods(input, &async_opts)
+    } else {
+        quote! {}
+    };
     let (proxy_struct, connection, builder, proxy_trait) = if blocking {
         let connection = quote! { #zbus::blocking::Connection };
         let proxy = quote! { #zbus::blocking::Proxy };
@@ -349,88 +1157,512 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
         (proxy, connection, builder, proxy_trait)
     };
 
-    let proxy_method_new = match (&default_path, &default_service) {
-        (None, None) => {
+    let (new_doc, new_generics, new_params, new_args, new_where, new_body) = match (
+        &default_path,
+        &default_service,
+    ) {
+        (None, None) => (
+            "Creates a new proxy with the given service destination and path.",
+            quote! { <D, P> },
+            quote! { destination: D, path: P, },
+            quote! { destination, path, },
             quote! {
-                /// Creates a new proxy with the given service destination and path.
-                pub #usage fn new<D, P>(conn: &#connection, destination: D, path: P) -> #zbus::Result<#proxy_name<'p>>
                 where
                     D: ::std::convert::TryInto<#zbus::names::BusName<'static>>,
                     D::Error: ::std::convert::Into<#zbus::Error>,
                     P: ::std::convert::TryInto<#zbus::zvariant::ObjectPath<'static>>,
                     P::Error: ::std::convert::Into<#zbus::Error>,
-                {
-                    let obj_path = path.try_into().map_err(::std::convert::Into::into)?;
-                    let obj_destination = destination.try_into().map_err(::std::convert::Into::into)?;
-                    Self::builder(conn)
-                        .path(obj_path)?
-                        .destination(obj_destination)?
-                        .build()#wait
-                }
-            }
-        }
-        (Some(_), None) => {
+            },
+            quote! {
+                let obj_path = path.try_into().map_err(::std::convert::Into::into)?;
+                let obj_destination = destination.try_into().map_err(::std::convert::Into::into)?;
+                Self::builder(conn)
+                    .path(obj_path)?
+                    .destination(obj_destination)?
+                    .build()#wait
+            },
+        ),
+        (Some(_), None) => (
+            "Creates a new proxy with the given destination, and the default path.",
+            quote! { <D> },
+            quote! { destination: D, },
+            quote! { destination, },
             quote! {
-                /// Creates a new proxy with the given destination, and the default path.
-                pub #usage fn new<D>(conn: &#connection, destination: D) -> #zbus::Result<#proxy_name<'p>>
                 where
                     D: ::std::convert::TryInto<#zbus::names::BusName<'static>>,
                     D::Error: ::std::convert::Into<#zbus::Error>,
-                {
-                    let obj_dest = destination.try_into().map_err(::std::convert::Into::into)?;
-                    Self::builder(conn)
-                        .destination(obj_dest)?
-                        .build()#wait
-                }
-            }
-        }
-        (None, Some(_)) => {
+            },
+            quote! {
+                let obj_dest = destination.try_into().map_err(::std::convert::Into::into)?;
+                Self::builder(conn)
+                    .destination(obj_dest)?
+                    .build()#wait
+            },
+        ),
+        (None, Some(_)) => (
+            "Creates a new proxy with the given path, and the default destination.",
+            quote! { <P> },
+            quote! { path: P, },
+            quote! { path, },
             quote! {
-                /// Creates a new proxy with the given path, and the default destination.
-                pub #usage fn new<P>(conn: &#connection, path: P) -> #zbus::Result<#proxy_name<'p>>
                 where
                     P: ::std::convert::TryInto<#zbus::zvariant::ObjectPath<'static>>,
                     P::Error: ::std::convert::Into<#zbus::Error>,
-                {
-                    let obj_path = path.try_into().map_err(::std::convert::Into::into)?;
-                    Self::builder(conn)
-                        .path(obj_path)?
-                        .build()#wait
-                }
-            }
-        }
-        (Some(_), Some(_)) => {
+            },
             quote! {
-                /// Creates a new proxy with the default service and path.
-                pub #usage fn new(conn: &#connection) -> #zbus::Result<#proxy_name<'p>> {
-                    Self::builder(conn).build()#wait
-                }
-            }
-        }
-    };
-    let default_path = match default_path {
-        Some(p) => quote! { Some(#p) },
-        None => quote! { None },
+                let obj_path = path.try_into().map_err(::std::convert::Into::into)?;
+                Self::builder(conn)
+                    .path(obj_path)?
+                    .build()#wait
+            },
+        ),
+        (Some(_), Some(_)) => (
+            "Creates a new proxy with the default service and path.",
+            quote! {},
+            quote! {},
+            quote! {},
+            quote! {},
+            quote! {
+                Self::builder(conn).build()#wait
+            },
+        ),
     };
-    let default_service = match default_service {
-        Some(d) => quote! { Some(#d) },
-        None => quote! { None },
+    let proxy_method_new = quote! {
+        #[doc = #new_doc]
+        pub #usage fn new #new_generics(conn: &#connection, #new_params) -> #zbus::Result<#proxy_name<'p>>
+        #new_where
+        {
+            #new_body
+        }
     };
+    let new_when_available_method = if !extras.when_available || has_method("new_when_available") {
+        quote! {}
+    } else {
+        quote! {
+            /// Like `new`, but also waits for the destination to be owned by a peer on the bus,
+            /// before returning the proxy.
+            ///
+            /// This is useful to avoid racing against the startup of a service. If the destination
+            /// isn't owned within `timeout`, an [`std::io::ErrorKind::TimedOut`] I/O error is
+            /// returned. The timeout only covers the wait, and not the proxy creation itself. On
+            /// peer-to-peer connections, this doesn't wait at all.
+            pub #usage fn new_when_available #new_generics(
+                conn: &#connection,
+                #new_params
+                timeout: ::std::time::Duration,
+            ) -> #zbus::Result<#proxy_name<'p>>
+            #new_where
+            {
+                let proxy = Self::new(conn, #new_args)#wait?;
+                proxy.0.wait_for_owner(timeout)#wait?;
 
-    Ok(quote! {
-        impl<'a> #zbus::proxy::ProxyDefault for #proxy_name<'a> {
-            const INTERFACE: Option<&'static str> = Some(#iface_name);
-            const DESTINATION: Option<&'static str> = #default_service;
-            const PATH: Option<&'static str> = #default_path;
+                ::std::result::Result::Ok(proxy)
+            }
         }
-
-        #(#other_attrs)*
-        #[derive(Clone, Debug)]
+    };
+    let new_for_typed_method = if !extras.new_for_typed || has_method("new_for_typed") {
+        quote! {}
+    } else {
+        quote! {
+            /// Creates a new proxy with the given destination and path, overriding the defaults.
+            ///
+            /// Unlike `new`, this takes the already validated names, so malformed ones are caught
+            /// when converting them to these types, e.g with `TryFrom`, before creating the proxy.
+            pub #usage fn new_for_typed(
+                conn: &#connection,
+                destination: #zbus::names::BusName<'p>,
+                path: #zbus::zvariant::ObjectPath<'p>,
+            ) -> #zbus::Result<#proxy_name<'p>>
+            {
+                Self::builder(conn)
+                    .destination(destination)?
+                    .path(path)?
+                    .build()#wait
+            }
+        }
+    };
+    // Proxies are ordered by object path, and then by destination so that only proxies for the
+    // same object are equal.
+    let ord_impls = if gen_ord {
+        quote! {
+            impl<'p> ::std::cmp::PartialEq for #proxy_name<'p> {
+                fn eq(&self, other: &Self) -> bool {
+                    (self.0.path(), self.0.destination_opt()) == (other.0.path(), other.0.destination_opt())
+                }
+            }
+
+            impl<'p> ::std::cmp::Eq for #proxy_name<'p> {}
+
+            impl<'p> ::std::hash::Hash for #proxy_name<'p> {
+                fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                    ::std::hash::Hash::hash(&(self.0.path(), self.0.destination_opt()), state)
+                }
+            }
+
+            impl<'p> ::std::cmp::PartialOrd for #proxy_name<'p> {
+                fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                    ::std::option::Option::Some(::std::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            impl<'p> ::std::cmp::Ord for #proxy_name<'p> {
+                fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                    ::std::cmp::Ord::cmp(
+                        &(self.0.path(), self.0.destination_opt()),
+                        &(other.0.path(), other.0.destination_opt()),
+                    )
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let object_at_methods = sub_proxies
+        .iter()
+        .filter(|(at_method, _)| extras.object_at && !has_method(&at_method.to_string()))
+        .map(|(at_method, sub_proxy)| {
+            quote! {
+                /// Create a proxy for the object at `path`, on the same connection and destination
+                /// as `self`.
+                ///
+                /// Fails if `path` is not a valid object path.
+                pub #usage fn #at_method<'o, P>(&self, path: P) -> #zbus::Result<#sub_proxy<'o>>
+                where
+                    P: ::std::convert::TryInto<#zbus::zvariant::ObjectPath<'o>>,
+                    P::Error: ::std::convert::Into<#zbus::Error>,
+                {
+                    let builder = #sub_proxy::builder(self.0.connection());
+                    let builder = match self.0.destination_opt() {
+                        ::std::option::Option::Some(destination) => {
+                            builder.destination(#zbus::names::BusName::to_owned(destination))?
+                        }
+                        ::std::option::Option::None => builder.no_destination(),
+                    };
+                    builder.path(path)?.build()#wait
+                }
+            }
+        });
+    let connection_method = if !extras.connection || has_method("connection") {
+        quote! {}
+    } else {
+        quote! {
+            /// The reference to the connection of this proxy.
+            ///
+            /// This is useful for creating other proxies, or sending other messages, on the same
+            /// connection.
+            pub fn connection(&self) -> &#connection {
+                self.0.connection()
+            }
+        }
+    };
+    // The blocking proxy can use the one of the underlying `zbus::blocking::Proxy`, through
+    // `inner`, without any boilerplate anyway.
+    let call_method_method = if blocking || !extras.call_method || has_method("call_method") {
+        quote! {}
+    } else {
+        quote! {
+            /// Call the method `method_name` of the interface with `body` as arguments, and
+            /// return the reply body, deserialized as `R`.
+            ///
+            /// This is an escape hatch for calling methods that aren't declared in the trait, e.g
+            /// ones only known at runtime, without dropping to the underlying `zbus::Proxy`.
+            /// Unlike [`zbus::Proxy::call_method`], the reply is deserialized by this method.
+            pub async fn call_method<'m, M, B, R>(
+                &self,
+                method_name: M,
+                body: &B,
+            ) -> #zbus::Result<R>
+            where
+                M: ::std::convert::TryInto<#zbus::names::MemberName<'m>>,
+                M::Error: ::std::convert::Into<#zbus::Error>,
+                B: #zbus::export::serde::ser::Serialize + #zbus::zvariant::DynamicType,
+                R: for<'d> #zbus::zvariant::DynamicDeserialize<'d>,
+            {
+                self.0.call(method_name, body).await
+            }
+        }
+    };
+    if extras.property_changed_any && property_getters.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "`gen_property_changed_any` requires the interface to have properties",
+        ));
+    }
+    let receive_property_changed_any_method = if !extras.property_changed_any
+        || has_method("receive_property_changed_any")
+    {
+        quote! {}
+    } else {
+        let (properties_proxy, properties_changed, ret, changes) = if blocking {
+            (
+                quote! { #zbus::blocking::fdo::PropertiesProxy },
+                quote! { #zbus::blocking::fdo::PropertiesChanged },
+                quote! {
+                    impl ::std::iter::Iterator<Item = (
+                        ::std::string::String,
+                        ::std::option::Option<#zbus::zvariant::OwnedValue>,
+                    )> + 'p
+                },
+                quote! { ::std::iter::Iterator::flat_map(signals, decode) },
+            )
+        } else {
+            (
+                quote! { #zbus::fdo::PropertiesProxy },
+                quote! { #zbus::fdo::PropertiesChanged },
+                quote! {
+                    impl #zbus::export::futures_core::Stream<Item = (
+                        ::std::string::String,
+                        ::std::option::Option<#zbus::zvariant::OwnedValue>,
+                    )> + ::std::marker::Unpin + 'p
+                },
+                quote! {
+                    ::std::boxed::Box::pin(#zbus::export::futures_util::StreamExt::flat_map(
+                        signals,
+                        move |signal| #zbus::export::futures_util::stream::iter(decode(signal)),
+                    ))
+                },
+            )
+        };
+
+        quote! {
+            /// Create a stream of the changes of all the properties of the interface, as
+            /// `(name, value)` pairs.
+            ///
+            /// The changes are decoded from the `org.freedesktop.DBus.Properties.PropertiesChanged`
+            /// signals for the interface, in the order of the signals: first the changed
+            /// properties with their new value, then the invalidated ones, with a `None` value.
+            /// Unlike the per-property change streams, this doesn't rely on the property cache.
+            /// Signals that can't be decoded and values that can't be owned are skipped.
+            pub #usage fn receive_property_changed_any(&self) -> #zbus::Result<#ret> {
+                let mut builder = #properties_proxy::builder(self.0.connection())
+                    .path(self.0.path().to_owned())?
+                    .cache_properties(#zbus::proxy::CacheProperties::No);
+                builder = match self.0.destination_opt() {
+                    ::std::option::Option::Some(destination) => {
+                        builder.destination(destination.to_owned())?
+                    }
+                    ::std::option::Option::None => builder.no_destination(),
+                };
+                if let ::std::option::Option::Some(sender) = self.0.sender() {
+                    builder = builder.sender(sender.to_owned())?;
+                }
+                let proxy = builder.build()#wait?;
+                // The interface of the proxy may differ from the declared one, if overridden
+                // through the builder.
+                let interface = self.0.interface().as_str();
+                let signals = proxy
+                    .receive_properties_changed_with_args(&[(0, interface)])#wait?;
+                let decode = |signal: #properties_changed| {
+                    let args = match signal.args() {
+                        ::std::result::Result::Ok(args) => args,
+                        ::std::result::Result::Err(_) => return ::std::vec::Vec::new(),
+                    };
+                    let changed = args
+                        .changed_properties
+                        .into_iter()
+                        .filter_map(|(name, value)| {
+                            let value = value.try_to_owned().ok()?;
+
+                            ::std::option::Option::Some((
+                                ::std::string::ToString::to_string(name),
+                                ::std::option::Option::Some(value),
+                            ))
+                        });
+                    let invalidated = args.invalidated_properties.into_iter().map(|name| {
+                        (::std::string::ToString::to_string(name), ::std::option::Option::None)
+                    });
+
+                    ::std::iter::Iterator::collect(::std::iter::Iterator::chain(changed, invalidated))
+                };
+
+                ::std::result::Result::Ok(#changes)
+            }
+        }
+    };
+    // The pipeline is only generated for the asynchronous proxy.
+    let gen_pipeline = gen_pipeline && !blocking;
+    let (pipeline_struct_decl, pipeline_method) = if !gen_pipeline || has_method("pipeline") {
+        (quote! {}, quote! {})
+    } else {
+        let pipeline_doc = format!(
+            " A pipeline of calls to the methods of the `{iface_name}` interface, sent all at \
+            once.\n\n \
+            `R` is the tuple of the results of the calls queued so far. See \
+            [`zbus::proxy::TypedPipeline`] for details."
+        );
+        let pipeline_struct_decl = quote! {
+            #[doc = #pipeline_doc]
+            #[must_use = "the calls are only sent by `send`"]
+            pub struct #pipeline_name<'p, R = ()>(#zbus::proxy::TypedPipeline<'p, R>);
+
+            impl<'p, R> #pipeline_name<'p, R> {
+                #pipeline_entries
+
+                /// Send all the queued calls and wait for their replies.
+                ///
+                /// The results of the calls are returned as a tuple, in the order the calls were
+                /// queued.
+                pub async fn send(self) -> R
+                where
+                    R: #zbus::proxy::PipelineReplies,
+                {
+                    self.0.send().await
+                }
+
+                /// Consumes `self`, returning the underlying `zbus::proxy::TypedPipeline`.
+                pub fn into_inner(self) -> #zbus::proxy::TypedPipeline<'p, R> {
+                    self.0
+                }
+            }
+
+            impl<'p, R> ::std::fmt::Debug for #pipeline_name<'p, R> {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.debug_tuple(::std::stringify!(#pipeline_name))
+                        .field(&self.0)
+                        .finish()
+                }
+            }
+        };
+        let pipeline_method = quote! {
+            /// Create a pipeline, to send several method calls of the interface at once and then
+            /// collect their typed results, in order.
+            pub fn pipeline(&self) -> #pipeline_name<'p> {
+                #pipeline_name(#zbus::proxy::TypedPipeline::new(&self.0))
+            }
+        };
+
+        (pipeline_struct_decl, pipeline_method)
+    };
+    let with_timeout_method = if !extras.with_timeout || has_method("with_timeout") {
+        quote! {}
+    } else {
+        quote! {
+            /// Create a variant of this proxy, that waits for method replies for at most
+            /// `timeout`, without affecting `self`.
+            ///
+            /// The returned proxy shares the connection and the property cache with `self`.
+            pub fn with_timeout(&self, timeout: ::std::time::Duration) -> #proxy_name<'p> {
+                #proxy_name(self.0.with_timeout(timeout))
+            }
+        }
+    };
+    let with_path_method = if !extras.with_path || has_method("with_path") {
+        quote! {}
+    } else {
+        quote! {
+            /// Create a proxy for the same interface and destination as `self`, but for the object
+            /// at `path`, without affecting `self`.
+            ///
+            /// The new proxy is created through [`Self::builder`], on the same connection and with
+            /// the same method timeout, sender and properties caching mode as `self`. This fails if
+            /// `path` isn't a valid object path.
+            pub #usage fn with_path<P>(&self, path: P) -> #zbus::Result<#proxy_name<'p>>
+            where
+                P: ::std::convert::TryInto<#zbus::zvariant::ObjectPath<'p>>,
+                P::Error: ::std::convert::Into<#zbus::Error>,
+            {
+                let obj_path = path.try_into().map_err(::std::convert::Into::into)?;
+                let mut builder = Self::builder(self.0.connection())
+                    .interface(self.0.interface().to_owned())?
+                    .path(obj_path)?
+                    .cache_properties(self.0.cache_properties());
+                builder = match self.0.destination_opt() {
+                    ::std::option::Option::Some(destination) => {
+                        builder.destination(destination.to_owned())?
+                    }
+                    ::std::option::Option::None => builder.no_destination(),
+                };
+                if let ::std::option::Option::Some(sender) = self.0.sender() {
+                    builder = builder.sender(sender.to_owned())?;
+                }
+                if let ::std::option::Option::Some(timeout) = self.0.method_timeout() {
+                    builder = builder.method_timeout(timeout);
+                }
+                if let ::std::option::Option::Some(hook) = self.0.metrics_hook() {
+                    builder = builder.metrics_hook(::std::clone::Clone::clone(hook));
+                }
+
+                builder.build()#wait
+            }
+        }
+    };
+    let owner_method = if !extras.owner || has_method("owner") {
+        quote! {}
+    } else {
+        quote! {
+            /// The unique name of the connection currently owning the destination of this proxy,
+            /// or `None` if it isn't owned.
+            ///
+            /// This calls `org.freedesktop.DBus.GetNameOwner` on the bus, independently of the
+            /// interface of this proxy. See [`zbus::Proxy::owner`] for details.
+            pub #usage fn owner(
+                &self,
+            ) -> #zbus::Result<::std::option::Option<#zbus::names::OwnedUniqueName>> {
+                self.0.owner()#wait
+            }
+        }
+    };
+    let proxy_method_new_p2p = if gen_p2p {
+        quote! {
+            /// Creates a new proxy with the given path, on a peer-to-peer connection.
+            ///
+            /// Unlike the proxies created by `new`, this one has no destination, not even the
+            /// default one, so its method calls don't carry a destination header field. Since
+            /// method calls on a bus connection need a destination, this fails with
+            /// [`zbus::Error::MissingParameter`] on those.
+            pub #usage fn new_p2p<P>(conn: &#connection, path: P) -> #zbus::Result<#proxy_name<'p>>
+            where
+                P: ::std::convert::TryInto<#zbus::zvariant::ObjectPath<'static>>,
+                P::Error: ::std::convert::Into<#zbus::Error>,
+            {
+                let obj_path = path.try_into().map_err(::std::convert::Into::into)?;
+                Self::builder(conn)
+                    .no_destination()
+                    .path(obj_path)?
+                    .build()#wait
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let default_path = match default_path {
+        Some(p) => quote! { Some(#p) },
+        None => quote! { None },
+    };
+    let default_service = match default_service {
+        Some(d) => quote! { Some(#d) },
+        None => quote! { None },
+    };
+
+    Ok(quote! {
+        impl<'a> #zbus::proxy::ProxyDefault for #proxy_name<'a> {
+            const INTERFACE: Option<&'static str> = Some(Self::INTERFACE);
+            const DESTINATION: Option<&'static str> = Self::DEFAULT_SERVICE;
+            const PATH: Option<&'static str> = Self::DEFAULT_PATH;
+        }
+
+        #(#other_attrs)*
+        #[derive(Clone, Debug)]
         pub struct #proxy_name<'p>(#proxy_struct<'p>);
 
         impl<'p> #proxy_name<'p> {
+            /// The name of the D-Bus interface this proxy is for.
+            pub const INTERFACE: &'static str = #iface_name;
+
+            /// The default object path of this proxy, if any.
+            pub const DEFAULT_PATH: ::std::option::Option<&'static str> = #default_path;
+
+            /// The default destination service of this proxy, if any.
+            pub const DEFAULT_SERVICE: ::std::option::Option<&'static str> = #default_service;
+
             #proxy_method_new
 
+            #new_when_available_method
+
+            #new_for_typed_method
+
+            #proxy_method_new_p2p
+
             /// Returns a customizable builder for this proxy.
             pub fn builder(conn: &#connection) -> #builder<'p, Self> {
                 let mut builder = #builder::new(conn) ;
@@ -443,6 +1675,10 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
                 }
             }
 
+            #with_timeout_method
+
+            #with_path_method
+
             /// Consumes `self`, returning the underlying `zbus::Proxy`.
             pub fn into_inner(self) -> #proxy_struct<'p> {
                 self.0
@@ -458,7 +1694,31 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
                 &mut self.0
             }
 
+            #connection_method
+
+            #(#object_at_methods)*
+
+            #owner_method
+
+            #receive_property_changed_any_method
+
+            #call_method_method
+
+            #pipeline_method
+
             #methods
+
+            #properties_method
+
+            #snapshot_method
+
+            #set_properties_method
+
+            #receive_all_signals_method
+
+            #flush_method
+
+            #peer_methods
         }
 
         impl<'p> #proxy_trait<'p> for #proxy_name<'p> {
@@ -493,6 +1753,8 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
             }
         }
 
+        #ord_impls
+
         impl<'p> #zbus::zvariant::Type for #proxy_name<'p> {
             fn signature() -> #zbus::zvariant::Signature<'static> {
                 #zbus::zvariant::OwnedObjectPath::signature()
@@ -512,35 +1774,130 @@ pub fn create_proxy<M: AttrParse + Into<MethodAttrs>>(
         }
 
         #stream_types
+
+        #properties_struct_decl
+
+        #snapshot_struct_decl
+
+        #patch_struct_decl
+
+        #signals_enum_decl
+
+        #pipeline_struct_decl
     })
 }
 
+fn gen_proxy_peer_methods(input: &ItemTrait, async_opts: &AsyncOpts) -> TokenStream {
+    let AsyncOpts { usage, wait, .. } = async_opts;
+    let zbus = zbus_path();
+    let declared = |name: &str| {
+        input.items.iter().any(|item| match item {
+            syn::TraitItem::Fn(m) => m.sig.ident == name,
+            _ => false,
+        })
+    };
+    let call = |member: &str| {
+        quote! {
+            self.0
+                .call_interface_method("org.freedesktop.DBus.Peer", #member, &())
+                #wait
+        }
+    };
+
+    // Methods declared in the trait take precedence.
+    let ping = if declared("ping") {
+        quote! {}
+    } else {
+        let call = call("Ping");
+        quote! {
+            /// Check that the peer is alive, through the `org.freedesktop.DBus.Peer.Ping` method
+            /// on the destination and path of this proxy.
+            pub #usage fn ping(&self) -> #zbus::Result<()> {
+                #call?;
+
+                ::std::result::Result::Ok(())
+            }
+        }
+    };
+    let get_machine_id = if declared("get_machine_id") {
+        quote! {}
+    } else {
+        let call = call("GetMachineId");
+        quote! {
+            /// Get the machine ID of the peer, through the
+            /// `org.freedesktop.DBus.Peer.GetMachineId` method on the destination and path of this
+            /// proxy.
+            pub #usage fn get_machine_id(&self) -> #zbus::Result<::std::string::String> {
+                let reply = #call?;
+
+                reply.body().deserialize()
+            }
+        }
+    };
+
+    quote! {
+        #ping
+
+        #get_machine_id
+    }
+}
+
+// The generated method call(s), the sub-proxy returned by the method if it returns one, and the
+// entry of the method on the pipeline type.
+type MethodCall = (TokenStream, Option<(Ident, Path)>, TokenStream);
+
+#[allow(clippy::too_many_arguments)]
 fn gen_proxy_method_call<M: AttrParse + Into<MethodAttrs>>(
     method_name: &str,
     snake_case_name: &str,
     m: &TraitItemFn,
     method_attrs: M,
     async_opts: &AsyncOpts,
-) -> Result<TokenStream, Error> {
-    let (object, blocking_object, async_object, no_reply, no_autostart, allow_interactive_auth) =
-        match method_attrs.into() {
-            MethodAttrs::Old(old) => (
-                old.object,
-                old.blocking_object,
-                old.async_object,
-                old.no_reply,
-                old.no_autostart,
-                old.allow_interactive_auth,
-            ),
-            MethodAttrs::New(new) => (
-                new.object,
-                new.blocking_object,
-                new.async_object,
-                new.no_reply,
-                new.no_autostart,
-                new.allow_interactive_auth,
-            ),
-        };
+    gen_metrics: bool,
+    gen_trace: bool,
+    gen_member_docs: bool,
+    pipeline_name: &Ident,
+) -> Result<MethodCall, Error> {
+    let (
+        object,
+        blocking_object,
+        async_object,
+        no_reply,
+        no_autostart,
+        allow_interactive_auth,
+        pipelined,
+        with_message,
+        stream,
+        traced,
+        with_flags,
+    ) = match method_attrs.into() {
+        MethodAttrs::Old(old) => (
+            old.object,
+            old.blocking_object,
+            old.async_object,
+            old.no_reply,
+            old.no_autostart,
+            old.allow_interactive_auth,
+            old.pipelined,
+            old.with_message,
+            old.stream,
+            old.traced,
+            old.with_flags,
+        ),
+        MethodAttrs::New(new) => (
+            new.object,
+            new.blocking_object,
+            new.async_object,
+            new.no_reply,
+            new.no_autostart,
+            new.allow_interactive_auth,
+            new.pipelined,
+            new.with_message,
+            new.stream,
+            new.traced,
+            new.with_flags,
+        ),
+    };
     let AsyncOpts {
         usage,
         wait,
@@ -552,6 +1909,19 @@ fn gen_proxy_method_call<M: AttrParse + Into<MethodAttrs>>(
         .iter()
         .filter(|a| !a.path().is_ident("zbus") && !a.path().is_ident("dbus_proxy"))
         .collect();
+    let member_doc = gen_member_docs.then(|| {
+        let doc = format!(" Calls the `{method_name}` method.");
+        // Keep it a paragraph of its own, after any docs of the trait method.
+        let separator = other_attrs
+            .iter()
+            .any(|a| a.path().is_ident("doc"))
+            .then(|| quote! { #[doc = ""] });
+
+        quote! {
+            #separator
+            #[doc = #doc]
+        }
+    });
     let args: Vec<_> = m
         .sig
         .inputs
@@ -578,30 +1948,30 @@ fn gen_proxy_method_call<M: AttrParse + Into<MethodAttrs>>(
 
     let method_flags = match (no_reply, no_autostart, allow_interactive_auth) {
         (true, false, false) => Some(quote!(::std::convert::Into::into(
-            zbus::proxy::MethodFlags::NoReplyExpected
+            #zbus::proxy::MethodFlags::NoReplyExpected
         ))),
         (false, true, false) => Some(quote!(::std::convert::Into::into(
-            zbus::proxy::MethodFlags::NoAutoStart
+            #zbus::proxy::MethodFlags::NoAutoStart
         ))),
         (false, false, true) => Some(quote!(::std::convert::Into::into(
-            zbus::proxy::MethodFlags::AllowInteractiveAuth
+            #zbus::proxy::MethodFlags::AllowInteractiveAuth
         ))),
 
         (true, true, false) => Some(quote!(
-            zbus::proxy::MethodFlags::NoReplyExpected | zbus::proxy::MethodFlags::NoAutoStart
+            #zbus::proxy::MethodFlags::NoReplyExpected | #zbus::proxy::MethodFlags::NoAutoStart
         )),
         (true, false, true) => Some(quote!(
-            zbus::proxy::MethodFlags::NoReplyExpected
-                | zbus::proxy::MethodFlags::AllowInteractiveAuth
+            #zbus::proxy::MethodFlags::NoReplyExpected
+                | #zbus::proxy::MethodFlags::AllowInteractiveAuth
         )),
         (false, true, true) => Some(quote!(
-            zbus::proxy::MethodFlags::NoAutoStart | zbus::proxy::MethodFlags::AllowInteractiveAuth
+            #zbus::proxy::MethodFlags::NoAutoStart | #zbus::proxy::MethodFlags::AllowInteractiveAuth
         )),
 
         (true, true, true) => Some(quote!(
-            zbus::proxy::MethodFlags::NoReplyExpected
-                | zbus::proxy::MethodFlags::NoAutoStart
-                | zbus::proxy::MethodFlags::AllowInteractiveAuth
+            #zbus::proxy::MethodFlags::NoReplyExpected
+                | #zbus::proxy::MethodFlags::NoAutoStart
+                | #zbus::proxy::MethodFlags::AllowInteractiveAuth
         )),
         _ => None,
     };
@@ -646,14 +2016,49 @@ fn gen_proxy_method_call<M: AttrParse + Into<MethodAttrs>>(
     let (_, ty_generics, where_clause) = generics.split_for_impl();
 
     if let Some(proxy_path) = proxy_object {
+        if pipelined {
+            return Err(Error::new_spanned(
+                &m.sig,
+                "`pipelined` can't be used with `object`",
+            ));
+        }
+        if with_message {
+            return Err(Error::new_spanned(
+                &m.sig,
+                "`with_message` can't be used with `object`",
+            ));
+        }
+        if stream {
+            return Err(Error::new_spanned(
+                &m.sig,
+                "`stream` can't be used with `object`",
+            ));
+        }
+        if traced {
+            return Err(Error::new_spanned(
+                &m.sig,
+                "`traced` can't be used with `object`",
+            ));
+        }
+        if with_flags {
+            return Err(Error::new_spanned(
+                &m.sig,
+                "`with_flags` can't be used with `object`",
+            ));
+        }
         let proxy_path = parse_str::<Path>(&proxy_path)?;
+        // Named after the object type, e.g `device_at` for `object = "Device"`.
+        let object_name = object.as_deref().unwrap_or_default();
+        let object_name = object_name.rsplit("::").next().unwrap_or(object_name);
+        let at_method = format_ident!("{}_at", case::snake_case(object_name));
         let signature = quote! {
             fn #method #ty_generics(#inputs) -> #zbus::Result<#proxy_path<'p>>
             #where_clause
         };
 
-        Ok(quote! {
+        let method = quote! {
             #(#other_attrs)*
+            #member_doc
             pub #usage #signature {
                 let object_path: #zbus::zvariant::OwnedObjectPath =
                     self.0.call(
@@ -666,7 +2071,9 @@ fn gen_proxy_method_call<M: AttrParse + Into<MethodAttrs>>(
                     .build()
                     #wait
             }
-        })
+        };
+
+        Ok((method, Some((at_method, proxy_path)), quote! {}))
     } else {
         let body = if args.len() == 1 {
             // Wrap single arg in a tuple so if it's a struct/tuple itself, zbus will only remove
@@ -687,156 +2094,1224 @@ fn gen_proxy_method_call<M: AttrParse + Into<MethodAttrs>>(
             #where_clause
         };
 
-        if let Some(method_flags) = method_flags {
-            if no_reply {
-                Ok(quote! {
-                    #(#other_attrs)*
-                    pub #usage #signature {
-                        self.0.call_with_flags::<_, _, ()>(#method_name, #method_flags, #body)#wait?;
-                        ::std::result::Result::Ok(())
-                    }
-                })
-            } else {
-                Ok(quote! {
-                    #(#other_attrs)*
-                    pub #usage #signature {
-                        let reply = self.0.call_with_flags(#method_name, #method_flags, #body)#wait?;
+        let pipelined_method = if pipelined {
+            let pipelined_name = format_ident!("{}_pipelined", snake_case_name);
+            let doc = format!(
+                " Queue a call to `{method_name}`, to be sent on the next `flush` call. \
+                See `call_pipelined` of the underlying proxy for details."
+            );
+            quote! {
+                #[doc = #doc]
+                pub fn #pipelined_name #ty_generics(#inputs) -> #zbus::Result<()>
+                #where_clause
+                {
+                    self.0.call_pipelined(#method_name, #body)
+                }
+            }
+        } else {
+            quote! {}
+        };
 
-                        // SAFETY: This unwrap() cannot fail due to the guarantees in
-                        // call_with_flags, which can only return Ok(None) if the
-                        // NoReplyExpected is set. By not passing NoReplyExpected,
-                        // we are guaranteed to get either an Err variant (handled
-                        // in the previous statement) or Ok(Some(T)) which is safe to
-                        // unwrap
-                        ::std::result::Result::Ok(reply.unwrap())
+        let with_message_method = if with_message {
+            if method_flags.is_some() {
+                return Err(Error::new_spanned(
+                    &m.sig,
+                    "`with_message` can't be used with `no_reply`, `no_autostart` or \
+                    `allow_interactive_auth`",
+                ));
+            }
+            let ret_type = match output {
+                ReturnType::Type(_, ty) => ty,
+                ReturnType::Default => {
+                    return Err(Error::new_spanned(
+                        &m.sig,
+                        "`with_message` requires the method to return a `Result`",
+                    ))
+                }
+            };
+            let with_message_name = format_ident!("{}_with_message", snake_case_name);
+            let doc = format!(
+                " Call `{method_name}` and return the reply message along with the deserialized \
+                reply body, for when the header fields of the reply are needed."
+            );
+            quote! {
+                #[doc = #doc]
+                pub #usage fn #with_message_name #ty_generics(#inputs) -> ::std::result::Result<
+                    (<#ret_type as #zbus::ResultAdapter>::Ok, #zbus::message::Message),
+                    <#ret_type as #zbus::ResultAdapter>::Err,
+                >
+                #where_clause
+                {
+                    let reply = self.0.call_method(#method_name, #body)#wait?;
+                    let reply_body: <#ret_type as #zbus::ResultAdapter>::Ok =
+                        reply.body().deserialize()?;
+                    ::std::result::Result::Ok((reply_body, reply))
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let traced_method = if traced {
+            if method_flags.is_some() {
+                return Err(Error::new_spanned(
+                    &m.sig,
+                    "`traced` can't be used with `no_reply`, `no_autostart` or \
+                    `allow_interactive_auth`",
+                ));
+            }
+            let ret_type = match output {
+                ReturnType::Type(_, ty) => ty,
+                ReturnType::Default => {
+                    return Err(Error::new_spanned(
+                        &m.sig,
+                        "`traced` requires the method to return a `Result`",
+                    ))
+                }
+            };
+            let traced_name = format_ident!("{}_traced", snake_case_name);
+            let doc = format!(
+                " Call `{method_name}` and return the serial number of the method call message \
+                along with the reply, to correlate the call with bus traces or logs."
+            );
+            quote! {
+                #[doc = #doc]
+                pub #usage fn #traced_name #ty_generics(#inputs) -> ::std::result::Result<
+                    (<#ret_type as #zbus::ResultAdapter>::Ok, u32),
+                    <#ret_type as #zbus::ResultAdapter>::Err,
+                >
+                #where_clause
+                {
+                    let reply = self.0.call_method(#method_name, #body)#wait?;
+                    // The reply refers to the serial of the call.
+                    let serial = reply
+                        .header()
+                        .reply_serial()
+                        .ok_or(#zbus::Error::InvalidReply)?
+                        .get();
+                    let reply_body: <#ret_type as #zbus::ResultAdapter>::Ok =
+                        reply.body().deserialize()?;
+                    ::std::result::Result::Ok((reply_body, serial))
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let with_flags_method = if with_flags {
+            let ret_type = match output {
+                ReturnType::Type(_, ty) => ty,
+                ReturnType::Default => {
+                    return Err(Error::new_spanned(
+                        &m.sig,
+                        "`with_flags` requires the method to return a `Result`",
+                    ))
+                }
+            };
+            let with_flags_name = format_ident!("{}_with_flags", snake_case_name);
+            let doc = format!(
+                " Call `{method_name}` with the given flags set on the method call message.\n\n \
+                This returns `None` if, and only if, `MethodFlags::NoReplyExpected` is set. See \
+                `call_with_flags` of the underlying proxy for details."
+            );
+            let mut with_flags_inputs = inputs.clone();
+            // After `&self`.
+            with_flags_inputs.insert(
+                1,
+                parse_quote!(
+                    call_flags: #zbus::export::enumflags2::BitFlags<#zbus::proxy::MethodFlags>
+                ),
+            );
+            quote! {
+                #[doc = #doc]
+                pub #usage fn #with_flags_name #ty_generics(#with_flags_inputs) -> ::std::result::Result<
+                    ::std::option::Option<<#ret_type as #zbus::ResultAdapter>::Ok>,
+                    <#ret_type as #zbus::ResultAdapter>::Err,
+                >
+                #where_clause
+                {
+                    self.0
+                        .call_with_flags(#method_name, call_flags, #body)#wait
+                        .map_err(::std::convert::Into::into)
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let stream_method = if stream {
+            if no_reply {
+                return Err(Error::new_spanned(
+                    &m.sig,
+                    "`stream` can't be used with `no_reply`",
+                ));
+            }
+            let ret_type = match output {
+                ReturnType::Type(_, ty) => ty,
+                ReturnType::Default => {
+                    return Err(Error::new_spanned(
+                        &m.sig,
+                        "`stream` requires the method to return a `Result`",
+                    ))
+                }
+            };
+            let stream_name = format_ident!("{}_stream", snake_case_name);
+            let item = quote! {
+                <<#ret_type as #zbus::ResultAdapter>::Ok as ::std::iter::IntoIterator>::Item
+            };
+            let (doc, ret, elements) = if *blocking {
+                (
+                    format!(
+                        " Call `{method_name}` and return an iterator over the elements of the \
+                        returned array.\n\n This is a convenience wrapper, backed by a single \
+                        method call. The whole reply is received before the iterator is returned."
+                    ),
+                    quote! { impl ::std::iter::Iterator<Item = #item> },
+                    quote! { ::std::iter::IntoIterator::into_iter(reply) },
+                )
+            } else {
+                (
+                    format!(
+                        " Call `{method_name}` and return a stream of the elements of the \
+                        returned array.\n\n This is a convenience wrapper for uniform \
+                        consumption in async pipelines, backed by a single method call. It's not \
+                        server-side streaming: the whole reply is received before the stream is \
+                        returned."
+                    ),
+                    quote! {
+                        impl #zbus::export::futures_core::Stream<Item = #item> + ::std::marker::Unpin
+                    },
+                    quote! { #zbus::export::futures_util::stream::iter(reply) },
+                )
+            };
+            quote! {
+                #[doc = #doc]
+                pub #usage fn #stream_name #ty_generics(#inputs) -> ::std::result::Result<
+                    #ret,
+                    <#ret_type as #zbus::ResultAdapter>::Err,
+                >
+                #where_clause
+                {
+                    let reply: <#ret_type as #zbus::ResultAdapter>::Ok =
+                        self.#method(#(#args),*)#wait?;
+                    ::std::result::Result::Ok(#elements)
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        // With `trace`, the body is also needed after the call, for its signature, so it's bound
+        // beforehand. Same as below, don't let the arguments see it.
+        let body_arg = Ident::new("body", Span::mixed_site());
+        let call_body = if gen_trace {
+            quote! { #body_arg }
+        } else {
+            body.clone()
+        };
+        // With `metrics`, each call is timed and reported to the metrics hook of the proxy, if any.
+        // The arguments are evaluated after the start of the call, so don't let them see it.
+        let start = Ident::new("start", Span::mixed_site());
+        let timed = |call: TokenStream| {
+            let call = if gen_metrics {
+                quote! {{
+                    let #start = ::std::time::Instant::now();
+                    let reply = #call;
+                    if let ::std::option::Option::Some(hook) = self.0.metrics_hook() {
+                        hook.record(#method_name, #start.elapsed(), reply.as_ref().map(|_| ()));
+                    }
+                    reply
+                }}
+            } else {
+                call
+            };
+            if gen_trace {
+                quote! {{
+                    let #body_arg = #body;
+                    let reply = #call;
+                    #zbus::trace_method_call(self.0.interface(), #method_name, #body_arg, &reply);
+                    reply
+                }}
+            } else {
+                call
+            }
+        };
+        let method_call = if let Some(method_flags) = &method_flags {
+            if no_reply {
+                let call = timed(quote! {
+                    self.0.call_with_flags::<_, _, ()>(#method_name, #method_flags, #call_body)#wait
+                });
+                quote! {
+                    #(#other_attrs)*
+                    #member_doc
+                    pub #usage #signature {
+                        let _ = #call?;
+                        ::std::result::Result::Ok(())
+                    }
+                }
+            } else {
+                let call = timed(quote! {
+                    self.0.call_with_flags(#method_name, #method_flags, #call_body)#wait
+                });
+                quote! {
+                    #(#other_attrs)*
+                    #member_doc
+                    pub #usage #signature {
+                        let reply = #call?;
+
+                        // SAFETY: This unwrap() cannot fail due to the guarantees in
+                        // call_with_flags, which can only return Ok(None) if the
+                        // NoReplyExpected is set. By not passing NoReplyExpected,
+                        // we are guaranteed to get either an Err variant (handled
+                        // in the previous statement) or Ok(Some(T)) which is safe to
+                        // unwrap
+                        ::std::result::Result::Ok(reply.unwrap())
+                    }
+                }
+            }
+        } else {
+            let call = timed(quote! { self.0.call(#method_name, #call_body)#wait });
+            quote! {
+                #(#other_attrs)*
+                #member_doc
+                pub #usage #signature {
+                    let reply = #call?;
+                    ::std::result::Result::Ok(reply)
+                }
+            }
+        };
+
+        // Calls without a reply can't be pipelined, and the pipeline doesn't set flags.
+        let pipeline_entry = match output {
+            ReturnType::Type(_, ret_type)
+                if !*blocking
+                    && method_flags.is_none()
+                    && !matches!(snake_case_name, "send" | "into_inner") =>
+            {
+                let reply_type = quote! { <#ret_type as #zbus::ResultAdapter>::Ok };
+                let mut entry_generics = generics.clone();
+                entry_generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote!(R: #zbus::proxy::PushReply<#reply_type>));
+                let (_, entry_ty_generics, entry_where_clause) = entry_generics.split_for_impl();
+                let mut entry_inputs = inputs.clone();
+                // The pipeline is taken by value, for the calls to be chained.
+                entry_inputs[0] = parse_quote!(self);
+                let doc = format!(
+                    " Queue a call to `{method_name}`. Its result is appended to the ones \
+                    returned by `send`."
+                );
+                // The entry must only exist if the method itself does.
+                let cfg_attrs = other_attrs.iter().filter(|a| a.path().is_ident("cfg"));
+                quote! {
+                    #(#cfg_attrs)*
+                    #[doc = #doc]
+                    pub fn #method #entry_ty_generics(#entry_inputs) -> #pipeline_name<
+                        'p,
+                        <R as #zbus::proxy::PushReply<#reply_type>>::Output,
+                    >
+                    #entry_where_clause
+                    {
+                        #pipeline_name(self.0.call::<_, _, #reply_type>(#method_name, #body))
+                    }
+                }
+            }
+            _ => quote! {},
+        };
+
+        let method = quote! {
+            #method_call
+
+            #pipelined_method
+
+            #with_message_method
+
+            #traced_method
+
+            #with_flags_method
+
+            #stream_method
+        };
+
+        Ok((method, None, pipeline_entry))
+    }
+}
+
+fn gen_proxy_property(
+    property_name: &str,
+    method_name: &str,
+    m: &TraitItemFn,
+    async_opts: &AsyncOpts,
+    property: &PropertyAttrs,
+) -> Result<TokenStream, Error> {
+    let AsyncOpts {
+        usage,
+        wait,
+        blocking,
+    } = async_opts;
+    let &PropertyAttrs {
+        ref emits_changed_signal,
+        changed_values,
+        iter,
+        variant,
+        optional,
+        ref range,
+        confirmed,
+    } = property;
+    let zbus = zbus_path();
+    let other_attrs: Vec<_> = m
+        .attrs
+        .iter()
+        .filter(|a| !a.path().is_ident("zbus") && !a.path().is_ident("dbus_proxy"))
+        .collect();
+    // Getters and setters are thin forwarders, so let them be inlined across crates, unless the
+    // trait method already has its own inlining attribute.
+    let inline = if other_attrs.iter().any(|a| a.path().is_ident("inline")) {
+        quote! {}
+    } else {
+        quote! { #[inline] }
+    };
+    let signature = &m.sig;
+    if signature.inputs.len() > 1 {
+        let value = pat_ident(typed_arg(signature.inputs.last().unwrap()).unwrap()).unwrap();
+        let range_check = range
+            .as_ref()
+            .map(|range| gen_range_check(property_name, range, quote!(&#value)));
+        let confirmed_method = if confirmed {
+            gen_confirmed_setter(
+                property_name,
+                method_name,
+                m,
+                async_opts,
+                range_check.as_ref(),
+            )?
+        } else {
+            quote! {}
+        };
+        Ok(quote! {
+            #(#other_attrs)*
+            #inline
+            #[allow(clippy::needless_question_mark)]
+            pub #usage #signature {
+                #range_check
+                ::std::result::Result::Ok(self.0.set_property(#property_name, #value)#wait?)
+            }
+
+            #confirmed_method
+        })
+    } else {
+        // This should fail to compile only if the return type is wrong,
+        // so use that as the span. Resolve it at the call site though, for the `self` in the body
+        // to match the receiver of methods merged in through `extends`.
+        let body_span = if let ReturnType::Type(_, ty) = &signature.output {
+            Span::call_site().located_at(ty.span())
+        } else {
+            Span::call_site().located_at(signature.span())
+        };
+        let body = if optional {
+            quote_spanned! {body_span =>
+                match self.0.get_property(#property_name)#wait {
+                    ::std::result::Result::Ok(value) => {
+                        ::std::result::Result::Ok(::std::option::Option::Some(value))
+                    }
+                    // Servers that don't have the property (yet) reply with one of these.
+                    ::std::result::Result::Err(#zbus::Error::FDO(e))
+                        if ::std::matches!(
+                            *e,
+                            #zbus::fdo::Error::UnknownProperty(_)
+                                | #zbus::fdo::Error::InvalidArgs(_)
+                        ) =>
+                    {
+                        ::std::result::Result::Ok(::std::option::Option::None)
+                    }
+                    ::std::result::Result::Err(e) => {
+                        ::std::result::Result::Err(::std::convert::Into::into(e))
+                    }
+                }
+            }
+        } else {
+            quote_spanned! {body_span =>
+                ::std::result::Result::Ok(self.0.get_property(#property_name)#wait?)
+            }
+        };
+        let ret_type = if let ReturnType::Type(_, ty) = &signature.output {
+            Some(ty)
+        } else {
+            None
+        };
+        // The type of the property values, which optional property getters wrap in an `Option`.
+        let value_type = if optional {
+            quote! { <<#ret_type as #zbus::ResultAdapter>::Ok as ::std::iter::IntoIterator>::Item }
+        } else {
+            quote! { <#ret_type as #zbus::ResultAdapter>::Ok }
+        };
+
+        let (proxy_name, prop_stream) = if *blocking {
+            (
+                "zbus::blocking::Proxy",
+                quote! { #zbus::blocking::proxy::PropertyIterator },
+            )
+        } else {
+            ("zbus::Proxy", quote! { #zbus::proxy::PropertyStream })
+        };
+
+        let receive_method = match emits_changed_signal {
+            PropertyEmitsChangedSignal::True | PropertyEmitsChangedSignal::Invalidates => {
+                let (_, ty_generics, where_clause) = m.sig.generics.split_for_impl();
+                let receive = format_ident!("receive_{}_changed", method_name);
+                if changed_values {
+                    let gen_doc = format!(
+                        "Create a stream of the new values of the `{property_name}` property, \
+                    each time it changes. Values that can't be retrieved or decoded are skipped. \
+                    This is built on [`{proxy_name}::receive_property_changed`]."
+                    );
+                    let ret = if *blocking {
+                        quote! {
+                            impl ::std::iter::Iterator<
+                                Item = #value_type
+                            > + 'p
+                        }
+                    } else {
+                        quote! {
+                            impl #zbus::export::futures_core::Stream<
+                                Item = #value_type
+                            > + ::std::marker::Unpin + 'p
+                        }
+                    };
+                    let body = if *blocking {
+                        quote! {
+                            ::std::iter::Iterator::filter_map(
+                                self.0.receive_property_changed(#property_name),
+                                |changed| changed.get().ok(),
+                            )
+                        }
+                    } else {
+                        quote! {
+                            ::std::boxed::Box::pin(#zbus::export::futures_util::StreamExt::filter_map(
+                                self.0.receive_property_changed(#property_name).await,
+                                |changed| async move { changed.get().await.ok() },
+                            ))
+                        }
+                    };
+
+                    quote! {
+                        #[doc = #gen_doc]
+                        pub #usage fn #receive #ty_generics(&self) -> #ret
+                        #where_clause
+                        {
+                            #body
+                        }
+                    }
+                } else {
+                    let gen_doc = format!(
+                        "Create a stream for the `{property_name}` property changes. \
+                This is a convenient wrapper around [`{proxy_name}::receive_property_changed`]."
+                    );
+                    quote! {
+                        #[doc = #gen_doc]
+                        pub #usage fn #receive #ty_generics(
+                            &self
+                        ) -> #prop_stream<'p, #value_type>
+                        #where_clause
+                        {
+                            self.0.receive_property_changed(#property_name)#wait
+                        }
+                    }
+                }
+            }
+            PropertyEmitsChangedSignal::False | PropertyEmitsChangedSignal::Const => {
+                quote! {}
+            }
+        };
+
+        let cached_getter_method = match emits_changed_signal {
+            PropertyEmitsChangedSignal::True
+            | PropertyEmitsChangedSignal::Invalidates
+            | PropertyEmitsChangedSignal::Const => {
+                let cached_getter = format_ident!("cached_{}", method_name);
+                let cached_doc = format!(
+                    " Get the cached value of the `{property_name}` property, or `None` if the property is not cached.",
+                );
+                quote! {
+                    #[doc = #cached_doc]
+                    pub fn #cached_getter(&self) -> ::std::result::Result<
+                        ::std::option::Option<#value_type>,
+                        <#ret_type as #zbus::ResultAdapter>::Err>
+                    {
+                        self.0.cached_property(#property_name).map_err(::std::convert::Into::into)
+                    }
+                }
+            }
+            PropertyEmitsChangedSignal::False => quote! {},
+        };
+
+        let iter_method = if iter {
+            let (_, ty_generics, where_clause) = m.sig.generics.split_for_impl();
+            let iter_getter = format_ident!("{}_iter", method_name);
+            let iter_doc = format!(
+                "Get the elements of the `{property_name}` array property, deserializing them \
+                on demand. This never uses the cache and is built on \
+                [`{proxy_name}::get_property_iter`]."
+            );
+            quote! {
+                #[doc = #iter_doc]
+                pub #usage fn #iter_getter #ty_generics(&self) -> ::std::result::Result<
+                    #zbus::zvariant::serialized::ArrayIter<
+                        'static,
+                        'static,
+                        <#value_type as ::std::iter::IntoIterator>::Item,
+                    >,
+                    <#ret_type as #zbus::ResultAdapter>::Err>
+                #where_clause
+                {
+                    self.0
+                        .get_property_iter(#property_name)#wait
+                        .map_err(::std::convert::Into::into)
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let variant_method = if variant {
+            let (_, ty_generics, where_clause) = m.sig.generics.split_for_impl();
+            let variant_getter = format_ident!("{}_variant", method_name);
+            let variant_doc = format!(
+                "Get the `{property_name}` property as the variant it's transported in, without \
+                unwrapping it. This never uses the cache and is built on \
+                [`{proxy_name}::get_property_variant`]."
+            );
+            quote! {
+                #[doc = #variant_doc]
+                pub #usage fn #variant_getter #ty_generics(&self) -> ::std::result::Result<
+                    #zbus::zvariant::OwnedValue,
+                    <#ret_type as #zbus::ResultAdapter>::Err>
+                #where_clause
+                {
+                    self.0
+                        .get_property_variant(#property_name)#wait
+                        .map_err(::std::convert::Into::into)
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        Ok(quote! {
+            #(#other_attrs)*
+            #inline
+            #[allow(clippy::needless_question_mark)]
+            pub #usage #signature {
+                #body
+            }
+
+            #cached_getter_method
+
+            #receive_method
+
+            #iter_method
+
+            #variant_method
+        })
+    }
+}
+
+// The `<setter>_confirmed` method of a property, that also gets the value back from the server.
+fn gen_confirmed_setter(
+    property_name: &str,
+    method_name: &str,
+    m: &TraitItemFn,
+    async_opts: &AsyncOpts,
+    range_check: Option<&TokenStream>,
+) -> Result<TokenStream, Error> {
+    let AsyncOpts { usage, wait, .. } = async_opts;
+    let zbus = zbus_path();
+    let signature = &m.sig;
+    let ret_type = match &signature.output {
+        ReturnType::Type(_, ty) if signature.generics.params.is_empty() => ty,
+        _ => {
+            return Err(Error::new_spanned(
+                signature,
+                "`confirmed` requires a non-generic setter returning a `Result`",
+            ))
+        }
+    };
+    let arg = typed_arg(signature.inputs.last().unwrap()).unwrap();
+    let value = pat_ident(arg).unwrap();
+    // The value is read back as an owned value, whatever the setter takes.
+    let value_type = match &*arg.ty {
+        Type::Reference(r) => {
+            let elem = SetLifetimeStatic.fold_type((*r.elem).clone());
+            quote! { <#elem as ::std::borrow::ToOwned>::Owned }
+        }
+        Type::ImplTrait(_) => {
+            return Err(Error::new_spanned(
+                &arg.ty,
+                "`confirmed` can't be used on setters taking an `impl Trait` value",
+            ))
+        }
+        ty => {
+            let ty = SetLifetimeStatic.fold_type(ty.clone());
+            quote! { #ty }
+        }
+    };
+    let arg_type = &arg.ty;
+    let confirmed_name = format_ident!("{}_confirmed", method_name);
+    let doc = format!(
+        " Set the `{property_name}` property, then get it back from the server and return the \
+        value actually stored, which may differ from `{value}` if the server adjusted it. See \
+        `set_property_confirmed` of the underlying proxy for details."
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        pub #usage fn #confirmed_name(
+            &self,
+            #value: #arg_type,
+        ) -> ::std::result::Result<#value_type, <#ret_type as #zbus::ResultAdapter>::Err> {
+            #range_check
+            self.0
+                .set_property_confirmed(#property_name, #value)#wait
+                .map_err(::std::convert::Into::into)
+        }
+    })
+}
+
+fn gen_proxy_properties(
+    trait_name: &Ident,
+    iface_name: &str,
+    getters: &[(String, Ident, Type, bool)],
+    async_opts: &AsyncOpts,
+    gen_object_manager: bool,
+    gen_struct: bool,
+) -> (TokenStream, TokenStream) {
+    let AsyncOpts { usage, wait, .. } = async_opts;
+    let zbus = zbus_path();
+    let struct_name = format_ident!("{}Properties", trait_name);
+    let fields: Vec<_> = getters.iter().map(|(_, field, _, _)| field).collect();
+    let types: Vec<_> = getters.iter().map(|(_, _, ty, _)| ty).collect();
+    let values = getters.iter().map(|(member_name, _, ty, optional)| {
+        // Several getters may be declared for the same property, e.g with different types.
+        let shared = getters
+            .iter()
+            .filter(|(name, ..)| name == member_name)
+            .count()
+            > 1;
+        let take = if shared {
+            quote! {
+                properties
+                    .get(#member_name)
+                    .map(#zbus::zvariant::OwnedValue::try_clone)
+                    .transpose()?
+            }
+        } else {
+            quote! { properties.remove(#member_name) }
+        };
+        if *optional {
+            // Missing optional properties are `None`.
+            return quote! {
+                #take
+                    .map(|value| {
+                        <<<#ty as #zbus::ResultAdapter>::Ok as ::std::iter::IntoIterator>::Item
+                            as ::std::convert::TryFrom<_>>::try_from(value)
+                            .map_err(::std::convert::Into::<#zbus::Error>::into)
+                    })
+                    .transpose()?
+            };
+        }
+
+        let missing = format!("Property `{member_name}` missing");
+        quote! {
+            {
+                let value = #take.ok_or_else(|| {
+                    #zbus::Error::from(#zbus::fdo::Error::UnknownProperty(
+                        ::std::string::ToString::to_string(#missing),
+                    ))
+                })?;
+                <<#ty as #zbus::ResultAdapter>::Ok as ::std::convert::TryFrom<_>>::try_from(value)
+                    .map_err(::std::convert::Into::<#zbus::Error>::into)?
+            }
+        }
+    });
+
+    let struct_decl = if gen_struct {
+        let struct_doc = format!(" All the properties of the `{iface_name}` interface.");
+        let field_docs = getters
+            .iter()
+            .map(|(member_name, _, _, _)| format!(" The `{member_name}` property."));
+        quote! {
+            #[doc = #struct_doc]
+            pub struct #struct_name {
+                #(
+                    #[doc = #field_docs]
+                    pub #fields: <#types as #zbus::ResultAdapter>::Ok,
+                )*
+            }
+
+            impl ::std::convert::TryFrom<
+                ::std::collections::HashMap<::std::string::String, #zbus::zvariant::OwnedValue>,
+            > for #struct_name {
+                type Error = #zbus::Error;
+
+                /// Extract the properties from a dictionary of property names and values, as
+                /// returned by `org.freedesktop.DBus.Properties.GetAll`.
+                #[allow(unused_mut)]
+                fn try_from(
+                    mut properties: ::std::collections::HashMap<
+                        ::std::string::String,
+                        #zbus::zvariant::OwnedValue,
+                    >,
+                ) -> #zbus::Result<Self> {
+                    ::std::result::Result::Ok(#struct_name {
+                        #(#fields: #values,)*
+                    })
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let method_doc = format!(
+        " Get all the properties of the `{iface_name}` interface at once.\n\n \
+        Unlike the individual property getters, this never uses the cache but fetches all \
+        properties through a single `org.freedesktop.DBus.Properties.GetAll` call.",
+    );
+    let method = quote! {
+        #[doc = #method_doc]
+        pub #usage fn properties(&self) -> #zbus::Result<#struct_name> {
+            let properties = self.0.get_all_properties()#wait?;
+
+            ::std::convert::TryFrom::try_from(properties)
+        }
+    };
+    let managed_objects_method = if gen_object_manager {
+        let doc = format!(
+            " Get the objects implementing the `{iface_name}` interface, with their properties, \
+            from the `org.freedesktop.DBus.ObjectManager` at the destination and path of this \
+            proxy.\n\n Objects that don't implement the interface are skipped."
+        );
+        quote! {
+            #[doc = #doc]
+            pub #usage fn get_managed_objects(
+                &self,
+            ) -> #zbus::Result<
+                ::std::collections::HashMap<#zbus::zvariant::OwnedObjectPath, #struct_name>,
+            > {
+                let reply = self
+                    .0
+                    .call_interface_method(
+                        "org.freedesktop.DBus.ObjectManager",
+                        "GetManagedObjects",
+                        &(),
+                    )#wait?;
+                let objects: #zbus::fdo::ManagedObjects = reply.body().deserialize()?;
+
+                objects
+                    .into_iter()
+                    .filter_map(|(path, interfaces)| {
+                        let (_, properties) = interfaces
+                            .into_iter()
+                            .find(|(name, _)| name.as_str() == #iface_name)?;
+
+                        ::std::option::Option::Some(
+                            ::std::convert::TryFrom::try_from(properties)
+                                .map(|properties| (path, properties)),
+                        )
+                    })
+                    .collect()
+            }
+        }
+    } else {
+        quote! {}
+    };
+    (
+        struct_decl,
+        quote! {
+            #method
+
+            #managed_objects_method
+        },
+    )
+}
+
+fn gen_proxy_properties_snapshot(
+    trait_name: &Ident,
+    iface_name: &str,
+    getters: &[(String, Ident, Type, bool)],
+    async_opts: &AsyncOpts,
+    gen_struct: bool,
+) -> (TokenStream, TokenStream) {
+    let AsyncOpts { usage, wait, .. } = async_opts;
+    let zbus = zbus_path();
+    let struct_name = format_ident!("{}PropertiesSnapshot", trait_name);
+
+    let struct_decl = if gen_struct {
+        let value_types: Vec<_> = getters
+            .iter()
+            .map(|(_, _, ty, optional)| {
+                if *optional {
+                    quote! {
+                        <<#ty as #zbus::ResultAdapter>::Ok as ::std::iter::IntoIterator>::Item
+                    }
+                } else {
+                    quote! { <#ty as #zbus::ResultAdapter>::Ok }
+                }
+            })
+            .collect();
+        let fields = getters
+            .iter()
+            .zip(&value_types)
+            .map(|((_, accessor, _, _), value_type)| {
+                quote! { #accessor: ::std::option::Option<#value_type> }
+            });
+        let field_inits =
+            getters
+                .iter()
+                .zip(&value_types)
+                .map(|((member_name, accessor, _, _), value_type)| {
+                    quote! {
+                        #accessor: properties
+                            .remove(#member_name)
+                            .map(<#value_type as ::std::convert::TryFrom<_>>::try_from)
+                            .transpose()
+                            .map_err(::std::convert::Into::<#zbus::Error>::into)?
+                    }
+                });
+        let accessors = getters.iter().zip(&value_types).map(
+            |((member_name, accessor, ty, optional), value_type)| {
+                let doc = format!(" The `{member_name}` property.");
+                // Point at the property type if it doesn't implement `SnapshotValue`.
+                let ret_type = quote_spanned! {ty.span()=>
+                    <#value_type as #zbus::proxy::SnapshotValue>::Ref<'_>
+                };
+
+                if *optional {
+                    quote! {
+                        #[doc = #doc]
+                        pub fn #accessor(&self) -> ::std::option::Option<#ret_type> {
+                            self.#accessor
+                                .as_ref()
+                                .map(#zbus::proxy::SnapshotValue::snapshot_ref)
+                        }
+                    }
+                } else {
+                    let missing = format!("Property `{member_name}` missing");
+
+                    quote! {
+                        #[doc = #doc]
+                        pub fn #accessor(&self) -> #zbus::Result<#ret_type> {
+                            self.#accessor
+                                .as_ref()
+                                .map(#zbus::proxy::SnapshotValue::snapshot_ref)
+                                .ok_or_else(|| {
+                                    #zbus::Error::from(#zbus::fdo::Error::UnknownProperty(
+                                        ::std::string::ToString::to_string(#missing),
+                                    ))
+                                })
+                        }
                     }
-                })
+                }
+            },
+        );
+        let struct_doc = format!(
+            " A snapshot of all the properties of the `{iface_name}` interface.\n\n \
+            The properties are converted once, when the snapshot is created. The accessors return \
+            them as their `zbus::proxy::SnapshotValue::Ref` type, borrowing from the snapshot, \
+            e.g `&str` for a `String` property and `&[T]` for a `Vec<T>` one, so they can't \
+            outlive it. Numbers and booleans are copied.",
+        );
+
+        quote! {
+            #[doc = #struct_doc]
+            #[derive(Debug)]
+            pub struct #struct_name {
+                #(#fields,)*
             }
-        } else {
-            Ok(quote! {
-                #(#other_attrs)*
-                pub #usage #signature {
-                    let reply = self.0.call(#method_name, #body)#wait?;
-                    ::std::result::Result::Ok(reply)
+
+            impl #struct_name {
+                #(#accessors)*
+            }
+
+            impl ::std::convert::TryFrom<
+                ::std::collections::HashMap<::std::string::String, #zbus::zvariant::OwnedValue>,
+            > for #struct_name {
+                type Error = #zbus::Error;
+
+                /// Create a snapshot from a dictionary of property names and values, as returned by
+                /// `org.freedesktop.DBus.Properties.GetAll`.
+                ///
+                /// Fails if any of the values can't be converted to the type of its property.
+                fn try_from(
+                    mut properties: ::std::collections::HashMap<
+                        ::std::string::String,
+                        #zbus::zvariant::OwnedValue,
+                    >,
+                ) -> #zbus::Result<Self> {
+                    ::std::result::Result::Ok(#struct_name {
+                        #(#field_inits,)*
+                    })
                 }
-            })
+            }
         }
-    }
+    } else {
+        quote! {}
+    };
+
+    let method_doc = format!(
+        " Get a snapshot of all the properties of the `{iface_name}` interface.\n\n \
+        The properties are fetched through a single `org.freedesktop.DBus.Properties.GetAll` call \
+        and can then be accessed without any further calls, mostly by reference. Fails if any \
+        property can't be converted to its type.",
+    );
+    let method = quote! {
+        #[doc = #method_doc]
+        pub #usage fn properties_snapshot(&self) -> #zbus::Result<#struct_name> {
+            let properties = self.0.get_all_properties()#wait?;
+
+            ::std::convert::TryFrom::try_from(properties)
+        }
+    };
+
+    (struct_decl, method)
 }
 
-fn gen_proxy_property(
-    property_name: &str,
-    method_name: &str,
-    m: &TraitItemFn,
+fn gen_proxy_signals_enum(
+    trait_name: &Ident,
+    iface_name: &str,
+    signal_names: &[String],
     async_opts: &AsyncOpts,
-    emits_changed_signal: PropertyEmitsChangedSignal,
-) -> TokenStream {
+    gen_enum: bool,
+) -> (TokenStream, TokenStream) {
     let AsyncOpts {
-        usage,
-        wait,
-        blocking,
+        usage, blocking, ..
     } = async_opts;
     let zbus = zbus_path();
-    let other_attrs: Vec<_> = m
-        .attrs
+    let enum_name = format_ident!("{}Signal", trait_name);
+    let variants: Vec<_> = signal_names
         .iter()
-        .filter(|a| !a.path().is_ident("zbus") && !a.path().is_ident("dbus_proxy"))
+        .map(|name| format_ident!("{}", name))
         .collect();
-    let signature = &m.sig;
-    if signature.inputs.len() > 1 {
-        let value = pat_ident(typed_arg(signature.inputs.last().unwrap()).unwrap()).unwrap();
+
+    let enum_decl = if gen_enum {
+        let enum_doc = format!(" A signal of the `{iface_name}` interface.");
+        let variant_docs = signal_names
+            .iter()
+            .map(|name| format!(" A `{name}` signal."));
         quote! {
-            #(#other_attrs)*
-            #[allow(clippy::needless_question_mark)]
-            pub #usage #signature {
-                ::std::result::Result::Ok(self.0.set_property(#property_name, #value)#wait?)
+            #[doc = #enum_doc]
+            #[derive(Debug, Clone)]
+            pub enum #enum_name {
+                #(
+                    #[doc = #variant_docs]
+                    #variants(#variants),
+                )*
             }
-        }
-    } else {
-        // This should fail to compile only if the return type is wrong,
-        // so use that as the span.
-        let body_span = if let ReturnType::Type(_, ty) = &signature.output {
-            ty.span()
-        } else {
-            signature.span()
-        };
-        let body = quote_spanned! {body_span =>
-            ::std::result::Result::Ok(self.0.get_property(#property_name)#wait?)
-        };
-        let ret_type = if let ReturnType::Type(_, ty) = &signature.output {
-            Some(ty)
-        } else {
-            None
-        };
 
-        let (proxy_name, prop_stream) = if *blocking {
-            (
-                "zbus::blocking::Proxy",
-                quote! { #zbus::blocking::proxy::PropertyIterator },
-            )
-        } else {
-            ("zbus::Proxy", quote! { #zbus::proxy::PropertyStream })
-        };
+            impl #enum_name {
+                /// Try to construct a signal of the interface from a [`zbus::Message`], based on
+                /// its member name.
+                ///
+                /// Only the signals of the interface declared on the proxy trait are recognized.
+                pub fn from_message<M>(msg: M) -> ::std::option::Option<Self>
+                where
+                    M: ::std::convert::Into<#zbus::message::Message>,
+                {
+                    let msg = msg.into();
+                    let member = msg.header().member().map(|m| m.to_string());
 
-        let receive_method = match emits_changed_signal {
-            PropertyEmitsChangedSignal::True | PropertyEmitsChangedSignal::Invalidates => {
-                let (_, ty_generics, where_clause) = m.sig.generics.split_for_impl();
-                let receive = format_ident!("receive_{}_changed", method_name);
-                let gen_doc = format!(
-                    "Create a stream for the `{property_name}` property changes. \
-                This is a convenient wrapper around [`{proxy_name}::receive_property_changed`]."
-                );
-                quote! {
-                    #[doc = #gen_doc]
-                    pub #usage fn #receive #ty_generics(
-                        &self
-                    ) -> #prop_stream<'p, <#ret_type as #zbus::ResultAdapter>::Ok>
-                    #where_clause
-                    {
-                        self.0.receive_property_changed(#property_name)#wait
+                    match member.as_deref() {
+                        #(
+                            ::std::option::Option::Some(#signal_names) => {
+                                #variants::from_message(msg).map(Self::#variants)
+                            }
+                        )*
+                        _ => ::std::option::Option::None,
                     }
                 }
-            }
-            PropertyEmitsChangedSignal::False | PropertyEmitsChangedSignal::Const => {
-                quote! {}
-            }
-        };
 
-        let cached_getter_method = match emits_changed_signal {
-            PropertyEmitsChangedSignal::True
-            | PropertyEmitsChangedSignal::Invalidates
-            | PropertyEmitsChangedSignal::Const => {
-                let cached_getter = format_ident!("cached_{}", method_name);
-                let cached_doc = format!(
-                    " Get the cached value of the `{property_name}` property, or `None` if the property is not cached.",
-                );
-                quote! {
-                    #[doc = #cached_doc]
-                    pub fn #cached_getter(&self) -> ::std::result::Result<
-                        ::std::option::Option<<#ret_type as #zbus::ResultAdapter>::Ok>,
-                        <#ret_type as #zbus::ResultAdapter>::Err>
-                    {
-                        self.0.cached_property(#property_name).map_err(::std::convert::Into::into)
+                #[doc = "The reference to the underlying [`zbus::Message`]."]
+                pub fn message(&self) -> &#zbus::message::Message {
+                    match self {
+                        #(Self::#variants(signal) => signal.message(),)*
                     }
                 }
             }
-            PropertyEmitsChangedSignal::False => quote! {},
+        }
+    } else {
+        quote! {}
+    };
+
+    let method_doc = format!(
+        " Create a stream that receives all the signals of the `{iface_name}` interface, as \
+        [`{enum_name}`] values. Signals that are not declared on the proxy are skipped.",
+    );
+    // The proxy only receives the signals of its own interface, which differs from the declared
+    // one if overridden through the builder. Unlike `from_message`, this hence only dispatches on
+    // the member name.
+    let dispatch = quote! {
+        |msg: #zbus::message::Message| {
+            let member = msg.header().member().map(|m| m.to_string());
+
+            match member.as_deref() {
+                #(
+                    ::std::option::Option::Some(#signal_names) => {
+                        ::std::option::Option::Some(#enum_name::#variants(#variants(msg.body())))
+                    }
+                )*
+                _ => ::std::option::Option::None,
+            }
+        }
+    };
+    let (ret, body) = if *blocking {
+        (
+            quote! { impl ::std::iter::Iterator<Item = #enum_name> + 'static },
+            quote! {
+                ::std::iter::Iterator::filter_map(self.0.receive_all_signals()?, #dispatch)
+            },
+        )
+    } else {
+        (
+            quote! {
+                impl #zbus::export::futures_core::stream::Stream<Item = #enum_name>
+                    + ::std::marker::Unpin
+                    + 'static
+            },
+            quote! {
+                {
+                    let dispatch = #dispatch;
+
+                    #zbus::export::futures_util::StreamExt::filter_map(
+                        self.0.receive_all_signals().await?,
+                        move |msg| ::std::future::ready(dispatch(msg)),
+                    )
+                }
+            },
+        )
+    };
+    let method = quote! {
+        #[doc = #method_doc]
+        pub #usage fn receive_all_signals(&self) -> #zbus::Result<#ret> {
+            ::std::result::Result::Ok(#body)
+        }
+    };
+
+    (enum_decl, method)
+}
+
+// A writable property: its name, the field for it in the patch struct, the type of the field, the
+// type the setter borrows the value as (if it takes a reference) and the allowed range of values.
+type PropertySetter = (String, Ident, Type, Option<Type>, Option<ExprRange>);
+
+fn gen_proxy_properties_patch(
+    trait_name: &Ident,
+    iface_name: &str,
+    setters: &[PropertySetter],
+    async_opts: &AsyncOpts,
+    gen_struct: bool,
+) -> (TokenStream, TokenStream) {
+    let AsyncOpts { usage, wait, .. } = async_opts;
+    let zbus = zbus_path();
+    let struct_name = format_ident!("{}PropertiesPatch", trait_name);
+    let fields: Vec<_> = setters.iter().map(|(_, field, _, _, _)| field).collect();
+    let types: Vec<_> = setters.iter().map(|(_, _, ty, _, _)| ty).collect();
+    let set_values = setters.iter().map(|(member_name, field, _, borrowed, _)| {
+        let value = match borrowed {
+            Some(elem) => quote! { ::std::borrow::Borrow::<#elem>::borrow(&value) },
+            None => quote! { value },
         };
 
         quote! {
-            #(#other_attrs)*
-            #[allow(clippy::needless_question_mark)]
-            pub #usage #signature {
-                #body
+            if let ::std::option::Option::Some(value) = patch.#field {
+                self.0.set_property(#member_name, #value)#wait?;
             }
+        }
+    });
+    let range_checks = setters
+        .iter()
+        .filter_map(|(member_name, field, _, _, range)| {
+            let check = gen_range_check(member_name, range.as_ref()?, quote!(value));
 
-            #cached_getter_method
+            Some(quote! {
+                if let ::std::option::Option::Some(value) = &patch.#field {
+                    #check
+                }
+            })
+        });
 
-            #receive_method
+    let struct_decl = if gen_struct {
+        let struct_doc = format!(
+            " Values for the writable properties of the `{iface_name}` interface, to be set at \
+            once. Properties left as `None` are not set."
+        );
+        let field_docs = setters.iter().map(|(member_name, _, _, _, _)| {
+            format!(" The new value of the `{member_name}` property.")
+        });
+        quote! {
+            #[doc = #struct_doc]
+            #[derive(Default)]
+            pub struct #struct_name {
+                #(
+                    #[doc = #field_docs]
+                    pub #fields: ::std::option::Option<#types>,
+                )*
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let method_doc = format!(
+        " Set the properties of the `{iface_name}` interface that are `Some` in `patch`.\n\n \
+        Each property is set through its own `org.freedesktop.DBus.Properties.Set` call, in the \
+        order of declaration. The first failure is returned and the remaining properties are not \
+        set. Values outside the `range` of their property are rejected before anything is set.",
+    );
+    let method = quote! {
+        #[doc = #method_doc]
+        pub #usage fn set_properties(&self, patch: #struct_name) -> #zbus::Result<()> {
+            #(#range_checks)*
+            #(#set_values)*
+
+            ::std::result::Result::Ok(())
+        }
+    };
+
+    (struct_decl, method)
+}
+
+// Reject a property value outside of `range`, without a round trip to the server.
+fn gen_range_check(property_name: &str, range: &ExprRange, value: TokenStream) -> TokenStream {
+    let zbus = zbus_path();
+    let msg = format!(
+        "value out of range `{}` for property `{property_name}`",
+        range.to_token_stream()
+    );
+
+    quote! {
+        if !::std::ops::RangeBounds::contains(&(#range), #value) {
+            return ::std::result::Result::Err(::std::convert::Into::into(#zbus::Error::FDO(
+                ::std::boxed::Box::new(#zbus::fdo::Error::InvalidArgs(::std::string::String::from(#msg))),
+            )));
         }
     }
 }
 
+struct SetLifetimeStatic;
+
+impl Fold for SetLifetimeStatic {
+    fn fold_type_reference(&mut self, node: syn::TypeReference) -> syn::TypeReference {
+        let mut t = syn::fold::fold_type_reference(self, node);
+        t.lifetime = Some(syn::Lifetime::new("'static", Span::call_site()));
+        t
+    }
+
+    fn fold_lifetime(&mut self, _node: syn::Lifetime) -> syn::Lifetime {
+        syn::Lifetime::new("'static", Span::call_site())
+    }
+}
+
 struct SetLifetimeS;
 
 impl Fold for SetLifetimeS {
@@ -851,6 +3326,35 @@ impl Fold for SetLifetimeS {
     }
 }
 
+/// The first generic type argument of `ty`, e.g `T` for `zbus::Result<T>` or `Option<T>`.
+fn first_type_arg(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let PathArguments::AngleBracketed(args) = &path.path.segments.last()?.arguments else {
+        return None;
+    };
+
+    args.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+/// Whether `ty` is free of references and lifetimes, i.e it can be deserialized as an owned value.
+fn is_owned_type(ty: &syn::Type) -> bool {
+    fn is_owned(tokens: TokenStream) -> bool {
+        tokens.into_iter().all(|token| match token {
+            TokenTree::Punct(p) => p.as_char() != '&' && p.as_char() != '\'',
+            TokenTree::Group(g) => is_owned(g.stream()),
+            _ => true,
+        })
+    }
+
+    is_owned(ty.to_token_stream())
+}
+
+#[allow(clippy::too_many_arguments)]
 fn gen_proxy_signal(
     proxy_name: &Ident,
     iface_name: &str,
@@ -858,6 +3362,7 @@ fn gen_proxy_signal(
     snake_case_name: &str,
     method: &TraitItemFn,
     async_opts: &AsyncOpts,
+    extras: &GenExtras,
     gen_sig_args: bool,
 ) -> (TokenStream, TokenStream) {
     let AsyncOpts {
@@ -920,6 +3425,7 @@ fn gen_proxy_signal(
         proxy_path,
         receive_signal_link,
         receive_signal_with_args_link,
+        next_signal_link,
         trait_name,
         trait_link,
         signal_type,
@@ -928,6 +3434,7 @@ fn gen_proxy_signal(
             "zbus::blocking::Proxy",
             "https://docs.rs/zbus/latest/zbus/blocking/proxy/struct.Proxy.html#method.receive_signal",
             "https://docs.rs/zbus/latest/zbus/blocking/proxy/struct.Proxy.html#method.receive_signal_with_args",
+            "https://docs.rs/zbus/latest/zbus/blocking/proxy/struct.Proxy.html#method.next_signal",
             "Iterator",
             "https://doc.rust-lang.org/std/iter/trait.Iterator.html",
             quote! { blocking::proxy::SignalIterator },
@@ -937,6 +3444,7 @@ fn gen_proxy_signal(
             "zbus::Proxy",
             "https://docs.rs/zbus/latest/zbus/proxy/struct.Proxy.html#method.receive_signal",
             "https://docs.rs/zbus/latest/zbus/proxy/struct.Proxy.html#method.receive_signal_with_args",
+            "https://docs.rs/zbus/latest/zbus/proxy/struct.Proxy.html#method.next_signal",
             "Stream",
             "https://docs.rs/futures/0.3.15/futures/stream/trait.Stream.html",
             quote! { proxy::SignalStream },
@@ -970,6 +3478,80 @@ fn gen_proxy_signal(
             }
         }
     };
+    // The decoded arguments outlive the signal messages, so they can only be yielded if they don't
+    // borrow from them.
+    let receive_signal_args = if !extras.receive_args
+        || *blocking
+        || args.is_empty()
+        || !method.sig.generics.params.is_empty()
+        || !input_types.iter().all(|ty| is_owned_type(ty))
+    {
+        quote!()
+    } else {
+        let receiver_args_name = format_ident!("receive_{snake_case_name}_args");
+        let receive_args_gen_doc = format!(
+            "Create a stream that yields the decoded arguments of `{signal_name}` signals.\n\
+                \n\
+                Multiple arguments are yielded as a tuple, in the declared order. Signals whose \
+                arguments fail to decode are yielded as errors, rather than ending the stream.",
+        );
+        quote! {
+            #[doc = #receive_args_gen_doc]
+            #(#other_attrs)*
+            pub async fn #receiver_args_name(
+                &self,
+            ) -> #zbus::Result<
+                impl #zbus::export::futures_core::stream::Stream<
+                    Item = #zbus::Result<(#(#input_types),*)>,
+                > + ::std::marker::Unpin + 'static,
+            >
+            {
+                let stream = self.0.receive_signal(#signal_name).await?;
+
+                ::std::result::Result::Ok(#zbus::export::futures_util::StreamExt::map(
+                    stream,
+                    |msg| msg.body().deserialize::<(#(#input_types),*)>(),
+                ))
+            }
+        }
+    };
+    // The decoded arguments outlive the signal message here too.
+    let next_signal = if !extras.next_signal
+        || args.is_empty()
+        || !method.sig.generics.params.is_empty()
+        || !input_types.iter().all(|ty| is_owned_type(ty))
+    {
+        quote!()
+    } else {
+        let next_name = format_ident!("next_{snake_case_name}");
+        let next_gen_doc = format!(
+            "Subscribe to the next `{signal_name}` signal.\n\
+                \n\
+                The subscription is made before this method returns, so signals emitted after that \
+                are not missed. The `wait` method of the returned handle waits for the signal, \
+                up to the given timeout, and returns its decoded arguments. Multiple arguments \
+                are returned as a tuple, in the declared order.\n\
+                \n\
+                This a convenient wrapper around [`{proxy_path}::next_signal`]({next_signal_link}).",
+        );
+        let next_signal_type = if *blocking {
+            quote! { blocking::proxy::NextSignal }
+        } else {
+            quote! { proxy::NextSignal }
+        };
+        quote! {
+            #[doc = #next_gen_doc]
+            #(#other_attrs)*
+            pub #usage fn #next_name(
+                &self,
+            ) -> #zbus::Result<#zbus::#next_signal_type<(#(#input_types),*)>>
+            {
+                let next = self.0.next_signal(#signal_name)#wait?;
+
+                ::std::result::Result::Ok(next.args())
+            }
+        }
+    };
     let receive_signal = quote! {
         #[doc = #receive_gen_doc]
         #(#other_attrs)*
@@ -979,6 +3561,10 @@ fn gen_proxy_signal(
         }
 
         #receive_signal_with_args
+
+        #receive_signal_args
+
+        #next_signal
     };
 
     let stream_gen_doc = format!(
@@ -1192,3 +3778,110 @@ fn gen_proxy_signal(
 
     (receive_signal, stream_types)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_proxy(args: Punctuated<Meta, Token![,]>, input: ItemTrait) -> TokenStream {
+        expand::<ImplAttributes, MethodAttributes>(args, input).unwrap()
+    }
+
+    // The paths of the attributes of the method `method` of the `proxy` type.
+    fn method_attrs(expanded: &TokenStream, proxy: &str, method: &str) -> Vec<String> {
+        let file: syn::File = syn::parse2(expanded.clone()).unwrap();
+        file.items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Impl(i) => Some(i),
+                _ => None,
+            })
+            .filter(|i| match &*i.self_ty {
+                Type::Path(p) => p.path.segments.last().unwrap().ident == proxy,
+                _ => false,
+            })
+            .flat_map(|i| &i.items)
+            .find_map(|item| match item {
+                syn::ImplItem::Fn(f) if f.sig.ident == method => Some(
+                    f.attrs
+                        .iter()
+                        .map(|a| a.path().to_token_stream().to_string())
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("no `{proxy}::{method}` method"))
+    }
+
+    #[test]
+    fn member_docs() {
+        let input: ItemTrait = parse_quote! {
+            trait DBus {
+                /// Get the Unix user of a connection.
+                fn get_connection_unix_user(&self, bus_name: &str) -> zbus::Result<u32>;
+            }
+        };
+        let expanded = expand_proxy(
+            parse_quote!(
+                interface = "org.freedesktop.DBus",
+                default_path = "/org/freedesktop/DBus"
+            ),
+            input.clone(),
+        )
+        .to_string();
+        // Once for the async proxy and once for the blocking one.
+        let doc = "\" Calls the `GetConnectionUnixUser` method.\"";
+        assert_eq!(expanded.matches(doc).count(), 2);
+        assert!(expanded.contains("\" Get the Unix user of a connection.\""));
+
+        let expanded = expand_proxy(
+            parse_quote!(
+                interface = "org.freedesktop.DBus",
+                default_path = "/org/freedesktop/DBus",
+                gen_member_docs = false
+            ),
+            input,
+        )
+        .to_string();
+        assert!(!expanded.contains("Calls the `GetConnectionUnixUser` method."));
+        assert!(expanded.contains("\" Get the Unix user of a connection.\""));
+    }
+
+    #[test]
+    fn inline_forwarders() {
+        let expanded = expand_proxy(
+            parse_quote!(
+                interface = "org.freedesktop.zbus.Inline",
+                default_path = "/org/freedesktop/zbus/Inline"
+            ),
+            parse_quote! {
+                trait Inline {
+                    #[zbus(property)]
+                    fn volume(&self) -> zbus::Result<u8>;
+                    #[zbus(property)]
+                    fn set_volume(&self, volume: u8) -> zbus::Result<()>;
+                    #[zbus(property)]
+                    #[inline(always)]
+                    fn mode(&self) -> zbus::Result<String>;
+                    fn ping(&self) -> zbus::Result<()>;
+                    #[inline]
+                    fn pong(&self) -> zbus::Result<()>;
+                }
+            },
+        );
+
+        for proxy in ["InlineProxy", "InlineProxyBlocking"] {
+            // Getters and setters are inlined by default.
+            for method in ["volume", "set_volume"] {
+                let attrs = method_attrs(&expanded, proxy, method);
+                assert_eq!(attrs.iter().filter(|a| *a == "inline").count(), 1);
+            }
+            // Without overriding the inlining attribute of the trait method.
+            let attrs = method_attrs(&expanded, proxy, "mode");
+            assert_eq!(attrs.iter().filter(|a| *a == "inline").count(), 1);
+            // Method calls are only inlined on request.
+            assert!(!method_attrs(&expanded, proxy, "ping").contains(&"inline".to_string()));
+            assert!(method_attrs(&expanded, proxy, "pong").contains(&"inline".to_string()));
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::{env, fs::File, iter::Peekable, path::PathBuf, slice::Iter};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_str,
+    punctuated::Punctuated,
+    Error, Expr, ExprLit, Ident, ItemTrait, Lit, LitStr, Meta, Token,
+};
+use zbus_xml::{Annotation, ArgDirection, Interface, Node};
+use zvariant_utils::case;
+
+use crate::{proxy, utils::zbus_path};
+
+const EMITS_CHANGED_SIGNAL_ANNOTATION: &str = "org.freedesktop.DBus.Property.EmitsChangedSignal";
+
+pub struct ProxyFromXmlArgs {
+    path: LitStr,
+    args: Punctuated<Meta, Token![,]>,
+}
+
+impl Parse for ProxyFromXmlArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let args = if input.is_empty() {
+            Punctuated::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::parse_terminated(input)?
+        };
+
+        Ok(Self { path, args })
+    }
+}
+
+pub fn expand(ProxyFromXmlArgs { path, args }: ProxyFromXmlArgs) -> Result<TokenStream, Error> {
+    let iface_name = args
+        .iter()
+        .find_map(|meta| match meta {
+            Meta::NameValue(nv) if nv.path.is_ident("interface") || nv.path.is_ident("name") => {
+                match &nv.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(s), ..
+                    }) => Some(s.value()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .ok_or_else(|| {
+            Error::new(
+                path.span(),
+                "`interface` must be specified, to pick the interface from the XML",
+            )
+        })?;
+
+    // Paths are relative to the crate being built, as for all its other files.
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").map_err(|e| Error::new(path.span(), e))?;
+    let file_path = PathBuf::from(manifest_dir).join(path.value());
+    let file = File::open(&file_path).map_err(|e| {
+        Error::new(
+            path.span(),
+            format!("failed to open `{}`: {e}", file_path.display()),
+        )
+    })?;
+    let node = Node::from_reader(file).map_err(|e| {
+        Error::new(
+            path.span(),
+            format!("failed to parse `{}`: {e}", file_path.display()),
+        )
+    })?;
+    let iface = find_interface(&node, &iface_name).ok_or_else(|| {
+        Error::new(
+            path.span(),
+            format!(
+                "interface `{iface_name}` not found in `{}`",
+                file_path.display()
+            ),
+        )
+    })?;
+
+    let input = gen_trait(iface).map_err(|e| Error::new(path.span(), e))?;
+    let proxy = proxy::expand::<proxy::ImplAttributes, proxy::MethodAttributes>(args, input)?;
+    let file_path = file_path.to_string_lossy().to_string();
+
+    Ok(quote! {
+        // Rebuild when the XML changes.
+        const _: &[u8] = ::std::include_bytes!(#file_path);
+
+        #proxy
+    })
+}
+
+fn find_interface<'n, 'a>(node: &'n Node<'a>, name: &str) -> Option<&'n Interface<'a>> {
+    node.interfaces()
+        .iter()
+        .find(|iface| iface.name().as_str() == name)
+        .or_else(|| {
+            node.nodes()
+                .iter()
+                .find_map(|node| find_interface(node, name))
+        })
+}
+
+// The proxy trait for `iface`, as it would be written by hand.
+fn gen_trait(iface: &Interface<'_>) -> Result<ItemTrait, String> {
+    let zbus = zbus_path();
+    let iface_name = iface.name();
+    let trait_name = to_ident(iface_name.rsplit('.').next().unwrap_or(&iface_name))?;
+    let mut items = vec![];
+
+    for method in iface.methods() {
+        let member_name = method.name().to_string();
+        let ident = to_ident(&case::snake_case(&member_name))?;
+        let mut inputs = vec![];
+        let mut outputs = vec![];
+        for (i, arg) in method.args().iter().enumerate() {
+            let signature = arg.ty().signature().as_str();
+            match arg.direction() {
+                None | Some(ArgDirection::In) => {
+                    let name = arg_ident(arg.name(), i)?;
+                    let ty = to_rust_type(signature, true, true)?;
+                    inputs.push(quote! { #name: #ty });
+                }
+                Some(ArgDirection::Out) => outputs.push(to_rust_type(signature, false, false)?),
+            }
+        }
+        let output = match outputs.as_slice() {
+            [output] => output.clone(),
+            outputs => quote! { (#(#outputs),*) },
+        };
+        let doc = format!(" `{member_name}` method");
+        let lints = (inputs.len() >= 7).then(|| quote! { #[allow(clippy::too_many_arguments)] });
+
+        items.push(quote! {
+            #[doc = #doc]
+            #lints
+            #[zbus(name = #member_name)]
+            fn #ident(&self, #(#inputs),*) -> #zbus::Result<#output>;
+        });
+    }
+
+    for signal in iface.signals() {
+        let member_name = signal.name().to_string();
+        let ident = to_ident(&case::snake_case(&member_name))?;
+        let args = signal
+            .args()
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| {
+                let name = arg_ident(arg.name(), i)?;
+                let ty = to_rust_type(arg.ty().signature().as_str(), true, false)?;
+
+                Ok(quote! { #name: #ty })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let doc = format!(" `{member_name}` signal");
+
+        items.push(quote! {
+            #[doc = #doc]
+            #[zbus(signal, name = #member_name)]
+            fn #ident(&self, #(#args),*) -> #zbus::Result<()>;
+        });
+    }
+
+    for property in iface.properties() {
+        let member_name = property.name().to_string();
+        let snake_case_name = case::snake_case(&member_name);
+        let signature = property.ty().signature().as_str();
+        let attrs = match annotation(property.annotations(), EMITS_CHANGED_SIGNAL_ANNOTATION) {
+            Some(emits_changed_signal) => quote! {
+                #[zbus(property(emits_changed_signal = #emits_changed_signal), name = #member_name)]
+            },
+            None => quote! { #[zbus(property, name = #member_name)] },
+        };
+        let doc = format!(" `{member_name}` property");
+
+        if property.access().read() {
+            let ident = to_ident(&snake_case_name)?;
+            let ty = to_rust_type(signature, false, false)?;
+            items.push(quote! {
+                #[doc = #doc]
+                #attrs
+                fn #ident(&self) -> #zbus::Result<#ty>;
+            });
+        }
+        if property.access().write() {
+            let ident = to_ident(&format!("set_{snake_case_name}"))?;
+            let ty = to_rust_type(signature, true, true)?;
+            items.push(quote! {
+                #[doc = #doc]
+                #attrs
+                fn #ident(&self, value: #ty) -> #zbus::Result<()>;
+            });
+        }
+    }
+
+    syn::parse2(quote! {
+        trait #trait_name {
+            #(#items)*
+        }
+    })
+    .map_err(|e| e.to_string())
+}
+
+fn annotation<'a>(annotations: &'a [Annotation], name: &str) -> Option<&'a str> {
+    annotations
+        .iter()
+        .find(|annotation| annotation.name() == name)
+        .map(Annotation::value)
+}
+
+// Like `zbus-xmlgen`, a `_` is appended to names that are keywords in Rust.
+fn to_ident(name: &str) -> Result<Ident, String> {
+    let name = name.replace('-', "_");
+    parse_str::<Ident>(&name)
+        .or_else(|_| parse_str::<Ident>(&format!("{name}_")))
+        .map_err(|_| format!("`{name}` can't be used as a Rust identifier"))
+}
+
+fn arg_ident(name: Option<&str>, index: usize) -> Result<Ident, String> {
+    match name {
+        Some(name) => to_ident(&case::snake_case(name)),
+        None => Ok(format_ident!("arg_{}", index + 1)),
+    }
+}
+
+// Map a D-Bus signature to the Rust type used for it in proxy traits, borrowing for inputs.
+fn to_rust_type(signature: &str, input: bool, as_ref: bool) -> Result<TokenStream, String> {
+    fn iter_to_rust_type(
+        it: &mut Peekable<Iter<'_, u8>>,
+        input: bool,
+        as_ref: bool,
+    ) -> Option<TokenStream> {
+        let zbus = zbus_path();
+        let ty = match *it.next()? as char {
+            'y' => quote! { u8 },
+            'b' => quote! { bool },
+            'n' => quote! { i16 },
+            'q' => quote! { u16 },
+            'i' => quote! { i32 },
+            'u' => quote! { u32 },
+            'x' => quote! { i64 },
+            't' => quote! { u64 },
+            'd' => quote! { f64 },
+            'h' if input => quote! { #zbus::zvariant::Fd<'_> },
+            'h' => quote! { #zbus::zvariant::OwnedFd },
+            's' if input || as_ref => quote! { &str },
+            's' => quote! { ::std::string::String },
+            'o' if input && as_ref => quote! { &#zbus::zvariant::ObjectPath<'_> },
+            'o' if input => quote! { #zbus::zvariant::ObjectPath<'_> },
+            'o' => quote! { #zbus::zvariant::OwnedObjectPath },
+            'g' if input && as_ref => quote! { &#zbus::zvariant::Signature<'_> },
+            'g' if input => quote! { #zbus::zvariant::Signature<'_> },
+            'g' => quote! { #zbus::zvariant::OwnedSignature },
+            'v' if input && as_ref => quote! { &#zbus::zvariant::Value<'_> },
+            'v' if input => quote! { #zbus::zvariant::Value<'_> },
+            'v' => quote! { #zbus::zvariant::OwnedValue },
+            'a' if **it.peek()? == b'{' => {
+                it.next();
+                let key = iter_to_rust_type(it, input, as_ref)?;
+                let value = iter_to_rust_type(it, input, as_ref)?;
+                if it.next()? != &b'}' {
+                    return None;
+                }
+
+                quote! { ::std::collections::HashMap<#key, #value> }
+            }
+            'a' => {
+                let ty = iter_to_rust_type(it, input, as_ref)?;
+                if input && as_ref {
+                    quote! { &[#ty] }
+                } else {
+                    quote! { ::std::vec::Vec<#ty> }
+                }
+            }
+            '(' => {
+                let mut fields = vec![];
+                while **it.peek()? != b')' {
+                    fields.push(iter_to_rust_type(it, input, as_ref)?);
+                }
+                it.next();
+                let reference = as_ref.then(|| quote! { & });
+
+                quote! { #reference(#(#fields,)*) }
+            }
+            // GVariant-only or otherwise unsupported.
+            _ => return None,
+        };
+
+        Some(ty)
+    }
+
+    let mut it = signature.as_bytes().iter().peekable();
+    match iter_to_rust_type(&mut it, input, as_ref) {
+        Some(ty) if it.next().is_none() => Ok(ty),
+        _ => Err(format!("unsupported signature `{signature}`")),
+    }
+}
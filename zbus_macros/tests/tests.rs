@@ -10,7 +10,9 @@ mod param {
     #[zbus_macros::proxy(
         interface = "org.freedesktop.zbus_macros.ProxyParam",
         default_service = "org.freedesktop.zbus_macros",
-        default_path = "/org/freedesktop/zbus_macros/test"
+        default_path = "/org/freedesktop/zbus_macros/test",
+        gen_connection = true,
+        gen_object_at = true
     )]
     trait ProxyParam {
         #[zbus(object = "super::test::Test")]
@@ -18,6 +20,128 @@ mod param {
     }
 }
 
+mod no_debug {
+    use serde::{Deserialize, Serialize};
+    use zbus::zvariant::{OwnedValue, Type, Value};
+
+    // Property types don't have to implement `Debug`.
+    #[derive(Serialize, Deserialize, Type, Value, OwnedValue)]
+    pub struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    #[zbus_macros::proxy(
+        interface = "org.freedesktop.zbus_macros.NoDebug",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/NoDebug",
+        gen_properties = true,
+        gen_properties_patch = true
+    )]
+    trait NoDebug {
+        #[zbus(property)]
+        fn position(&self) -> zbus::Result<Position>;
+        #[zbus(property)]
+        fn set_position(&self, position: Position) -> zbus::Result<()>;
+    }
+}
+
+// Without the `gen_*` attributes, and with the peer methods opted out of, the proxies don't get any
+// item whose name could clash with the ones declared next to them.
+#[allow(dead_code)]
+mod no_extras {
+    #[zbus_macros::proxy(
+        interface = "org.freedesktop.zbus_macros.NoExtras",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/NoExtras",
+        gen_peer = false
+    )]
+    trait NoExtras {
+        #[zbus(property)]
+        fn name(&self) -> zbus::Result<String>;
+        #[zbus(property)]
+        fn set_name(&self, name: &str) -> zbus::Result<()>;
+        #[zbus(signal)]
+        fn renamed(&self, name: String) -> zbus::Result<()>;
+        #[zbus(object = "NoExtras")]
+        fn child(&self);
+    }
+
+    pub struct NoExtrasProperties;
+
+    pub struct NoExtrasPropertiesPatch;
+
+    pub enum NoExtrasSignal {}
+
+    impl NoExtrasProxy<'_> {
+        pub fn properties(&self) -> NoExtrasProperties {
+            NoExtrasProperties
+        }
+
+        pub fn set_properties(&self, _patch: NoExtrasPropertiesPatch) {}
+
+        pub fn receive_all_signals(&self) -> Option<NoExtrasSignal> {
+            None
+        }
+
+        pub fn receive_property_changed_any(&self) {}
+
+        pub fn receive_renamed_args(&self) {}
+
+        pub fn next_renamed(&self) {}
+
+        pub fn ping(&self) {}
+
+        pub fn with_timeout(&self) {}
+
+        pub fn with_path(&self) {}
+
+        pub fn no_extras_at(&self) {}
+
+        pub fn owner(&self) {}
+
+        pub fn call_method(&self) {}
+
+        pub fn connection(&self) {}
+
+        pub fn new_when_available() {}
+
+        pub fn new_for_typed() {}
+    }
+
+    impl NoExtrasProxyBlocking<'_> {
+        pub fn properties(&self) -> NoExtrasProperties {
+            NoExtrasProperties
+        }
+
+        pub fn set_properties(&self, _patch: NoExtrasPropertiesPatch) {}
+
+        pub fn receive_all_signals(&self) -> Option<NoExtrasSignal> {
+            None
+        }
+
+        pub fn receive_property_changed_any(&self) {}
+
+        pub fn next_renamed(&self) {}
+
+        pub fn ping(&self) {}
+
+        pub fn with_timeout(&self) {}
+
+        pub fn with_path(&self) {}
+
+        pub fn no_extras_at(&self) {}
+
+        pub fn owner(&self) {}
+
+        pub fn connection(&self) {}
+
+        pub fn new_when_available() {}
+
+        pub fn new_for_typed() {}
+    }
+}
+
 mod test {
     use zbus::{
         fdo,
@@ -27,7 +151,8 @@ mod test {
     #[zbus_macros::proxy(
         assume_defaults = false,
         interface = "org.freedesktop.zbus_macros.Test",
-        default_service = "org.freedesktop.zbus_macros"
+        default_service = "org.freedesktop.zbus_macros",
+        gen_connection = true
     )]
     trait Test {
         /// comment for a_test()
@@ -65,6 +190,139 @@ mod test {
     }
 }
 
+#[allow(dead_code)]
+mod nested {
+    use ::zbus::zvariant::Str;
+
+    // A local module shadowing the `zbus` crate. The generated code must still resolve the crate
+    // through an absolute path.
+    mod zbus {}
+
+    #[zbus_macros::proxy(
+        interface = "org.freedesktop.zbus_macros.Nested",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/nested"
+    )]
+    trait Nested {
+        #[zbus(no_reply, no_autostart)]
+        fn a_method(&self, val: &str) -> ::zbus::Result<()>;
+
+        #[zbus(property)]
+        fn a_property(&self) -> ::zbus::fdo::Result<u32>;
+
+        #[zbus(signal)]
+        fn a_signal(&self, arg: u8) -> ::zbus::fdo::Result<()>;
+    }
+
+    struct Nested;
+
+    #[zbus_macros::interface(name = "org.freedesktop.zbus_macros.Nested")]
+    impl Nested {
+        fn a_method(&self, _val: &str) {}
+
+        #[zbus(property)]
+        fn a_property(&self) -> u32 {
+            0
+        }
+
+        #[zbus(property)]
+        fn set_a_property(&mut self, _val: Str<'_>) {}
+    }
+}
+
+#[allow(dead_code)]
+mod extended {
+    use zbus::fdo;
+
+    #[zbus_macros::proxy(
+        interface = "org.freedesktop.zbus_macros.Manager",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/manager",
+        extendable
+    )]
+    trait ManagerUnits {
+        fn start_unit(&self, name: &str, mode: &str) -> zbus::Result<()>;
+
+        #[zbus(property)]
+        fn n_units(&self) -> fdo::Result<u32>;
+    }
+
+    #[zbus_macros::proxy(
+        interface = "org.freedesktop.zbus_macros.Manager",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/manager",
+        extends = "ManagerUnits",
+        extendable
+    )]
+    trait ManagerJobs {
+        fn cancel_job(&self, id: u32) -> zbus::Result<()>;
+    }
+
+    #[zbus_macros::proxy(
+        interface = "org.freedesktop.zbus_macros.Manager",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/manager",
+        extends = "ManagerJobs",
+        gen_properties = true
+    )]
+    trait Manager {
+        #[zbus(signal)]
+        fn reloading(&self, active: bool) -> fdo::Result<()>;
+    }
+}
+
+#[allow(dead_code)]
+mod server {
+    use zbus::fdo;
+
+    #[zbus_macros::proxy(
+        interface = "org.freedesktop.zbus_macros.Server",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/server",
+        gen_server = true,
+        gen_signal_enum = true
+    )]
+    trait Calculator {
+        /// Add `a` and `b`.
+        fn add(&self, a: u32, b: u32) -> zbus::Result<u32>;
+
+        #[zbus(property)]
+        fn precision(&self) -> fdo::Result<u8>;
+
+        #[zbus(property)]
+        fn set_precision(&self, precision: u8) -> fdo::Result<()>;
+
+        #[zbus(signal)]
+        fn overflowed(&self) -> fdo::Result<()>;
+
+        #[zbus(signal)]
+        fn rounded(&self, value: f64, digits: u8) -> fdo::Result<()>;
+    }
+
+    pub struct Calculator;
+
+    impl CalculatorServer for Calculator {
+        fn add(&self, a: u32, b: u32) -> zbus::Result<u32> {
+            a.checked_add(b)
+                .ok_or_else(|| zbus::Error::Failure("overflow".into()))
+        }
+
+        fn precision(&self) -> fdo::Result<u8> {
+            Ok(2)
+        }
+
+        fn set_precision(&self, _precision: u8) -> fdo::Result<()> {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/**/*.rs");
+}
+
 #[test]
 fn test_proxy() {
     block_on(async move {
@@ -107,6 +365,345 @@ fn test_proxy() {
     });
 }
 
+#[test]
+fn test_proxy_consts() {
+    use zbus::proxy::ProxyDefault;
+
+    assert_eq!(
+        test::TestProxy::INTERFACE,
+        "org.freedesktop.zbus_macros.Test"
+    );
+    assert_eq!(
+        test::TestProxy::DEFAULT_SERVICE,
+        Some("org.freedesktop.zbus_macros")
+    );
+    assert_eq!(test::TestProxy::DEFAULT_PATH, None);
+    assert_eq!(
+        test::TestProxyBlocking::INTERFACE,
+        test::TestProxy::INTERFACE
+    );
+
+    assert_eq!(
+        <nested::NestedProxy<'_> as ProxyDefault>::INTERFACE,
+        Some(nested::NestedProxy::INTERFACE)
+    );
+    assert_eq!(
+        <nested::NestedProxy<'_> as ProxyDefault>::PATH,
+        nested::NestedProxy::DEFAULT_PATH
+    );
+    assert_eq!(
+        nested::NestedProxy::DEFAULT_PATH,
+        Some("/org/freedesktop/zbus_macros/nested")
+    );
+
+    // Usable in const contexts.
+    const RULE_INTERFACE: &str = test::TestProxy::INTERFACE;
+    assert!(RULE_INTERFACE.ends_with(".Test"));
+}
+
+#[test]
+fn test_proxy_extends() {
+    use extended::{ManagerProxy, ManagerProxyBlocking};
+
+    // Just testing the build mostly: the merged proxy has the items of the whole chain.
+    #[allow(dead_code)]
+    async fn merged(proxy: &ManagerProxy<'_>) -> zbus::Result<()> {
+        proxy.start_unit("foo.service", "replace").await?;
+        proxy.cancel_job(1).await?;
+        let _ = proxy.n_units().await?;
+        let _ = proxy.receive_reloading().await?;
+        let properties = proxy.properties().await?;
+        let _: u32 = properties.n_units;
+
+        Ok(())
+    }
+    #[allow(dead_code)]
+    fn merged_blocking(proxy: &ManagerProxyBlocking<'_>) -> zbus::Result<()> {
+        proxy.start_unit("foo.service", "replace")?;
+        proxy.cancel_job(1)?;
+        let _ = proxy.n_units()?;
+
+        Ok(())
+    }
+
+    assert_eq!(
+        ManagerProxy::INTERFACE,
+        extended::ManagerUnitsProxy::INTERFACE
+    );
+    assert_eq!(
+        ManagerProxy::DEFAULT_PATH,
+        Some("/org/freedesktop/zbus_macros/manager")
+    );
+}
+
+#[test]
+fn test_proxy_gen_server() {
+    use server::{Calculator, CalculatorServer};
+
+    assert_eq!(Calculator.add(40, 2).unwrap(), 42);
+    Calculator.add(u32::MAX, 1).unwrap_err();
+    assert_eq!(Calculator.precision().unwrap(), 2);
+    Calculator.set_precision(3).unwrap();
+}
+
+#[test]
+fn test_proxy_gen_server_emit() {
+    use server::{Calculator, CalculatorProxy, CalculatorServer};
+
+    block_on(async move {
+        let connection = zbus::Connection::session().await.unwrap();
+        let proxy = CalculatorProxy::builder(&connection)
+            .destination(connection.unique_name().unwrap().to_owned())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let mut rounded = proxy.receive_rounded().await.unwrap();
+
+        Calculator::emit_rounded(&connection, CalculatorProxy::DEFAULT_PATH.unwrap(), 1.5, 2)
+            .await
+            .unwrap();
+
+        let signal = rounded.next().await.unwrap();
+        let msg = signal.message();
+        let header = msg.header();
+        assert_eq!(header.member().unwrap(), "Rounded");
+        assert_eq!(
+            header.interface().unwrap(),
+            "org.freedesktop.zbus_macros.Server"
+        );
+        assert_eq!(msg.body().signature().unwrap(), "dy");
+        let args = signal.args().unwrap();
+        assert_eq!((*args.value(), *args.digits()), (1.5, 2));
+
+        // Invalid paths are reported when awaiting.
+        Calculator::emit_overflowed(&connection, "not/a/path")
+            .await
+            .unwrap_err();
+    });
+}
+
+#[test]
+fn test_proxy_receive_all_signals() {
+    use server::{Calculator, CalculatorProxy, CalculatorServer, CalculatorSignal};
+
+    block_on(async move {
+        let connection = zbus::Connection::session().await.unwrap();
+        let proxy = CalculatorProxy::builder(&connection)
+            .destination(connection.unique_name().unwrap().to_owned())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let mut signals = proxy.receive_all_signals().await.unwrap();
+
+        let path = CalculatorProxy::DEFAULT_PATH.unwrap();
+        Calculator::emit_rounded(&connection, path, 0.5, 1)
+            .await
+            .unwrap();
+        Calculator::emit_overflowed(&connection, path)
+            .await
+            .unwrap();
+
+        match signals.next().await.unwrap() {
+            CalculatorSignal::Rounded(signal) => {
+                let args = signal.args().unwrap();
+                assert_eq!((*args.value(), *args.digits()), (0.5, 1));
+            }
+            signal => panic!("expected a `Rounded` signal, got {signal:?}"),
+        }
+        let signal = signals.next().await.unwrap();
+        assert!(matches!(signal, CalculatorSignal::Overflowed(_)));
+        assert_eq!(signal.message().header().member().unwrap(), "Overflowed");
+
+        // The signals are those of the interface of the proxy, if overridden through the builder.
+        const OTHER_INTERFACE: &str = "org.freedesktop.zbus_macros.OtherServer";
+        let proxy = CalculatorProxy::builder(&connection)
+            .destination(connection.unique_name().unwrap().to_owned())
+            .unwrap()
+            .interface(OTHER_INTERFACE)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let mut signals = proxy.receive_all_signals().await.unwrap();
+
+        // Not received, as it's for the declared interface.
+        Calculator::emit_rounded(&connection, path, 0.5, 1)
+            .await
+            .unwrap();
+        connection
+            .emit_signal(None::<()>, path, OTHER_INTERFACE, "Overflowed", &())
+            .await
+            .unwrap();
+
+        let signal = signals.next().await.unwrap();
+        assert!(matches!(signal, CalculatorSignal::Overflowed(_)));
+        assert_eq!(
+            signal.message().header().interface().unwrap(),
+            OTHER_INTERFACE
+        );
+        // `from_message` only recognizes the declared interface.
+        assert!(CalculatorSignal::from_message(signal.message().clone()).is_none());
+    });
+}
+
+#[test]
+fn test_proxy_async_suffix() {
+    use combined::CombinedProxyBlocking;
+
+    // Just testing the build mostly: both forms are available on the blocking proxy.
+    #[allow(dead_code)]
+    fn blocking(proxy: &CombinedProxyBlocking<'_>) -> zbus::Result<()> {
+        let _: u32 = proxy.add(1, 2)?;
+        let _: u32 = proxy.add_numbers(&[1, 2])?;
+        let _: u8 = proxy.precision()?;
+        proxy.set_precision(2)?;
+
+        Ok(())
+    }
+    #[allow(dead_code)]
+    async fn asynchronous(proxy: &CombinedProxyBlocking<'_>) -> zbus::Result<()> {
+        let _: u32 = proxy.add_async(1, 2).await?;
+        let _: u32 = proxy.add_numbers_async(&[1, 2]).await?;
+        let _: u8 = proxy.precision_async().await?;
+        proxy.set_precision_async(2).await?;
+
+        Ok(())
+    }
+
+    // The async variants are `Send`, so they can be spawned.
+    fn assert_send<T: Send>(_: T) {}
+    #[allow(dead_code)]
+    fn send(proxy: &'static CombinedProxyBlocking<'static>) {
+        assert_send(proxy.add_async(1, 2));
+    }
+
+    assert_eq!(
+        CombinedProxyBlocking::INTERFACE,
+        combined::CombinedProxy::INTERFACE
+    );
+}
+
+#[test]
+fn test_proxy_own_introspect() {
+    // The generated proxies have no `introspect` method of their own (it's on the inner
+    // `zbus::Proxy`), so an interface can have an `Introspect` method with its own semantics.
+    let _: fn(&own_introspect::OwnIntrospectProxyBlocking<'static>, u32) -> zbus::Result<String> =
+        own_introspect::OwnIntrospectProxyBlocking::introspect;
+    let _ = own_introspect::OwnIntrospectProxy::introspect;
+}
+
+#[test]
+#[cfg(feature = "proxy-from-xml")]
+fn test_proxy_from_xml() {
+    use from_xml::{SampleProxy, SampleProxyBlocking};
+    use std::collections::HashMap;
+    use zbus::zvariant::{OwnedObjectPath, Value};
+
+    // Just testing the build mostly: the items are typed after the signatures in the XML.
+    #[allow(dead_code)]
+    async fn calls(proxy: &SampleProxy<'_>) -> zbus::Result<()> {
+        let _: u32 = proxy.add(1, 2).await?;
+        let verbose = Value::from(true);
+        let options = HashMap::from([("verbose", &verbose)]);
+        let _: ((u64, String), OwnedObjectPath) = proxy.describe(&["a", "b"], options).await?;
+        proxy.reset().await?;
+        // `type` is a keyword.
+        let _: String = proxy.type_().await?;
+
+        let _: u32 = proxy.count().await?;
+        let _: String = proxy.label().await?;
+        proxy.set_label("label").await?;
+        proxy.set_tags(HashMap::from([("key", "value")])).await?;
+
+        let mut changed = proxy.receive_changed().await?;
+        if let Some(signal) = changed.next().await {
+            let args = signal.args()?;
+            let _: &&str = args.name();
+            let _: &Value<'_> = args.value();
+        }
+
+        Ok(())
+    }
+    #[allow(dead_code)]
+    fn blocking(proxy: &SampleProxyBlocking<'_>) -> zbus::Result<()> {
+        let _: u32 = proxy.add(1, 2)?;
+        let _: String = proxy.label()?;
+
+        Ok(())
+    }
+
+    assert_eq!(SampleProxy::INTERFACE, "org.freedesktop.zbus_macros.Sample");
+    assert_eq!(
+        SampleProxy::DEFAULT_PATH,
+        Some("/org/freedesktop/zbus_macros/sample")
+    );
+}
+
+#[test]
+fn test_sub_proxy() {
+    use zbus::zvariant::ObjectPath;
+
+    block_on(async move {
+        let connection = zbus::Connection::session().await.unwrap();
+        let manager = param::ProxyParamProxy::builder(&connection)
+            .destination("org.freedesktop.zbus_macros.Manager")
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let path = ObjectPath::try_from("/org/freedesktop/zbus_macros/device/1").unwrap();
+        let device = manager.test_at(path.clone()).await.unwrap();
+        assert_eq!(device.inner().path(), &path);
+        assert_eq!(device.inner().destination(), manager.inner().destination());
+        assert_eq!(
+            device.inner().connection().unique_name(),
+            connection.unique_name()
+        );
+
+        // From a borrowed string too.
+        let path_str = String::from(path.as_str());
+        let device = manager.test_at(path_str.as_str()).await.unwrap();
+        assert_eq!(device.inner().path(), &path);
+        manager.test_at("not/a/path").await.unwrap_err();
+
+        let connection = zbus::blocking::Connection::from(connection);
+        let manager = param::ProxyParamProxyBlocking::builder(&connection)
+            .destination("org.freedesktop.zbus_macros.Manager")
+            .unwrap()
+            .build()
+            .unwrap();
+        let device = manager.test_at(path.clone()).unwrap();
+        assert_eq!(device.inner().path(), &path);
+        assert_eq!(device.inner().destination(), manager.inner().destination());
+    });
+}
+
+#[test]
+fn test_proxy_connection() {
+    block_on(async move {
+        let connection = zbus::Connection::session().await.unwrap();
+        let param = param::ProxyParamProxy::new(&connection).await.unwrap();
+        assert_eq!(param.connection().unique_name(), connection.unique_name());
+
+        // Another proxy on the same connection.
+        let test = test::TestProxy::new(param.connection(), "/org/freedesktop/zbus_macros/test")
+            .await
+            .unwrap();
+        assert_eq!(test.connection().unique_name(), connection.unique_name());
+        assert_eq!(test.inner().destination(), param.inner().destination());
+
+        let connection = zbus::blocking::Connection::from(connection);
+        let param = param::ProxyParamProxyBlocking::new(&connection).unwrap();
+        let test =
+            test::TestProxyBlocking::new(param.connection(), "/org/freedesktop/zbus_macros/test")
+                .unwrap();
+        assert_eq!(test.connection().unique_name(), connection.unique_name());
+    });
+}
+
 #[test]
 fn test_derive_error() {
     #[derive(Debug, DBusError)]
@@ -121,6 +718,47 @@ fn test_derive_error() {
             desc: String,
         },
     }
+
+    fn method_error(name: &str, desc: &str) -> zbus::Error {
+        use zbus::message::Message;
+
+        let call = Message::method("/", "Apologize")
+            .unwrap()
+            .destination(":1.2")
+            .unwrap()
+            .build(&())
+            .unwrap();
+        Message::method_error(&call, name)
+            .unwrap()
+            .build(&(desc,))
+            .unwrap()
+            .into()
+    }
+
+    // Known error names map to their variants.
+    let e = Test::from(method_error(
+        "org.freedesktop.zbus.I.Am.Sorry.Dave",
+        "I can't do that",
+    ));
+    assert!(matches!(&e, Test::IAmSorryDave(desc) if desc == "I can't do that"));
+    assert_eq!(
+        zbus::DBusError::name(&e),
+        "org.freedesktop.zbus.I.Am.Sorry.Dave"
+    );
+    let e = Test::from(method_error("org.freedesktop.zbus.SomeExcuse", ""));
+    assert!(matches!(e, Test::SomeExcuse));
+    let e = Test::from(method_error("org.freedesktop.zbus.LetItBe", "whisper"));
+    assert!(matches!(&e, Test::LetItBe { desc } if desc == "whisper"));
+
+    // Anything else falls through to the `zbus::Error` variant.
+    let e = Test::from(method_error("org.example.Unknown", "who knows"));
+    assert!(matches!(
+        &e,
+        Test::ZBus(zbus::Error::MethodError(name, _, _)) if name.as_str() == "org.example.Unknown"
+    ));
+    assert_eq!(zbus::DBusError::description(&e), Some("who knows"));
+    let e = Test::from(zbus::Error::InvalidReply);
+    assert!(matches!(e, Test::ZBus(zbus::Error::InvalidReply)));
 }
 
 #[test]
@@ -289,6 +927,51 @@ fn test_interface() {
     }
 }
 
+mod combined {
+    #[zbus_macros::proxy(
+        interface = "org.freedesktop.zbus_macros.Combined",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/combined",
+        async_suffix = "_async"
+    )]
+    trait Combined {
+        fn add(&self, a: u32, b: u32) -> zbus::Result<u32>;
+
+        fn add_numbers(&self, numbers: &[u32]) -> zbus::Result<u32>;
+
+        #[zbus(property)]
+        fn precision(&self) -> zbus::Result<u8>;
+
+        #[zbus(property)]
+        fn set_precision(&self, precision: u8) -> zbus::Result<()>;
+
+        #[zbus(signal)]
+        fn overflowed(&self) -> zbus::Result<()>;
+    }
+}
+
+mod own_introspect {
+    #[zbus_macros::proxy(
+        interface = "org.freedesktop.zbus_macros.OwnIntrospect",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/own_introspect"
+    )]
+    trait OwnIntrospect {
+        /// Unrelated to `org.freedesktop.DBus.Introspectable.Introspect`.
+        fn introspect(&self, level: u32) -> zbus::Result<String>;
+    }
+}
+
+#[cfg(feature = "proxy-from-xml")]
+mod from_xml {
+    zbus_macros::proxy_from_xml!(
+        "tests/data/org.freedesktop.zbus_macros.Sample.xml",
+        interface = "org.freedesktop.zbus_macros.Sample",
+        default_service = "org.freedesktop.zbus_macros",
+        default_path = "/org/freedesktop/zbus_macros/sample",
+    );
+}
+
 mod signal_from_message {
     use super::*;
     use zbus::message::Message;
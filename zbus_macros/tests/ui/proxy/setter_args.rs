@@ -0,0 +1,11 @@
+#[zbus_macros::proxy(
+    interface = "org.freedesktop.zbus_macros.BadSetter",
+    default_service = "org.freedesktop.zbus_macros",
+    default_path = "/org/freedesktop/zbus_macros/BadSetter"
+)]
+trait BadSetter {
+    #[zbus(property)]
+    fn set_value(&self, first: u32, second: u32) -> zbus::Result<()>;
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[zbus_macros::proxy(
+    interface = "org.freedesktop.zbus_macros.Snapshot",
+    default_service = "org.freedesktop.zbus_macros",
+    default_path = "/org/freedesktop/zbus_macros/Snapshot",
+    properties_snapshot
+)]
+trait Snapshot {
+    #[zbus(property)]
+    fn position(&self) -> zbus::Result<(u32, u32)>;
+}
+
+fn main() {}
@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "serde_bytes")]
 use serde_bytes::ByteBuf;
-use std::{collections::HashMap, vec};
+use std::{borrow::Cow, collections::HashMap, net::Ipv4Addr, time::Duration, vec};
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use zvariant::{serialized::Context, to_bytes_for_signature, Type, Value, LE};
+use zvariant::{
+    serialized::Context, to_bytes_for_signature, DurationMicros, Ipv4AsU32, Type, Value, LE,
+};
 
 #[cfg(feature = "serde_bytes")]
 fn byte_array(c: &mut Criterion) {
@@ -46,6 +48,127 @@ fn fixed_size_array(c: &mut Criterion) {
     });
 }
 
+// Same as `fixed_size_array`, through a borrowed `Cow`. Like `Vec<u8>`, it's serialized element by
+// element, as the bytes are only written all at once with `#[serde(with = "serde_bytes")]`.
+fn cow_byte_array(c: &mut Criterion) {
+    let data = vec![77u8; 100_000];
+    let ay: Cow<'_, [u8]> = Cow::Borrowed(&data);
+    let ctxt = Context::new_dbus(LE, 0);
+    let signature = <Cow<'_, [u8]>>::signature();
+    c.bench_function("cow_byte_array_ser", |b| {
+        b.iter(|| {
+            to_bytes_for_signature(black_box(ctxt), black_box(&signature), black_box(&ay)).unwrap()
+        })
+    });
+    let enc = to_bytes_for_signature(ctxt, &signature, &ay).unwrap();
+    c.bench_function("cow_byte_array_de", |b| {
+        b.iter(|| {
+            let _: (Cow<'_, [u8]>, _) = enc
+                .deserialize_for_signature(black_box(&signature))
+                .unwrap();
+        })
+    });
+}
+
+// `a(tu)` arrays of structures, against `at` arrays of integers with `DurationMicros`.
+fn duration_array(c: &mut Criterion) {
+    let durations: Vec<_> = (0..10_000u64).map(Duration::from_micros).collect();
+    let ctxt = Context::new_dbus(LE, 0);
+    let signature = Vec::<Duration>::signature();
+    c.bench_function("duration_array_ser", |b| {
+        b.iter(|| {
+            to_bytes_for_signature(
+                black_box(ctxt),
+                black_box(&signature),
+                black_box(&durations),
+            )
+            .unwrap()
+        })
+    });
+    let enc = to_bytes_for_signature(ctxt, &signature, &durations).unwrap();
+    c.bench_function("duration_array_de", |b| {
+        b.iter(|| {
+            let _: (Vec<Duration>, _) = enc
+                .deserialize_for_signature(black_box(&signature))
+                .unwrap();
+        })
+    });
+
+    let durations: Vec<_> = durations.into_iter().map(DurationMicros::from).collect();
+    let signature = Vec::<DurationMicros>::signature();
+    c.bench_function("duration_micros_array_ser", |b| {
+        b.iter(|| {
+            to_bytes_for_signature(
+                black_box(ctxt),
+                black_box(&signature),
+                black_box(&durations),
+            )
+            .unwrap()
+        })
+    });
+    let enc = to_bytes_for_signature(ctxt, &signature, &durations).unwrap();
+    c.bench_function("duration_micros_array_de", |b| {
+        b.iter(|| {
+            let _: (Vec<DurationMicros>, _) = enc
+                .deserialize_for_signature(black_box(&signature))
+                .unwrap();
+        })
+    });
+}
+
+// A routing table, with IPv4 addresses encoded as `u32`: an `a(uuuu)` array of fixed-size
+// structures.
+fn ipv4_route_table(c: &mut Criterion) {
+    let routes: Vec<_> = (0..10_000u32)
+        .map(|i| {
+            (
+                Ipv4AsU32(Ipv4Addr::from(0x0a00_0000 + (i << 8))),
+                24u32,
+                Ipv4AsU32(Ipv4Addr::new(192, 168, 1, 1)),
+                i % 100,
+            )
+        })
+        .collect();
+    let ctxt = Context::new_dbus(LE, 0);
+    let signature = <Vec<(Ipv4AsU32, u32, Ipv4AsU32, u32)>>::signature();
+    c.bench_function("ipv4_route_table_ser", |b| {
+        b.iter(|| {
+            to_bytes_for_signature(black_box(ctxt), black_box(&signature), black_box(&routes))
+                .unwrap()
+        })
+    });
+    let enc = to_bytes_for_signature(ctxt, &signature, &routes).unwrap();
+    c.bench_function("ipv4_route_table_de", |b| {
+        b.iter(|| {
+            let _: (Vec<(Ipv4AsU32, u32, Ipv4AsU32, u32)>, _) = enc
+                .deserialize_for_signature(black_box(&signature))
+                .unwrap();
+        })
+    });
+}
+
+fn string_dict(c: &mut Criterion) {
+    let dict: HashMap<String, String> = (0..1000)
+        .map(|i| (format!("KEY_{i}"), format!("value number {i}")))
+        .collect();
+    let ctxt = Context::new_dbus(LE, 0);
+    let signature = HashMap::<String, String>::signature();
+    c.bench_function("string_dict_ser", |b| {
+        b.iter(|| {
+            to_bytes_for_signature(black_box(ctxt), black_box(&signature), black_box(&dict))
+                .unwrap()
+        })
+    });
+    let enc = to_bytes_for_signature(ctxt, &signature, &dict).unwrap();
+    c.bench_function("string_dict_de", |b| {
+        b.iter(|| {
+            let _: (HashMap<String, String>, _) = enc
+                .deserialize_for_signature(black_box(&signature))
+                .unwrap();
+        })
+    });
+}
+
 fn big_array_ser_and_de(c: &mut Criterion) {
     #[derive(Deserialize, Serialize, Type, PartialEq, Debug, Clone)]
     struct ZVField<'f> {
@@ -142,7 +265,24 @@ fn big_array_ser_and_de(c: &mut Criterion) {
 }
 
 #[cfg(feature = "serde_bytes")]
-criterion_group!(benches, big_array_ser_and_de, byte_array, fixed_size_array);
+criterion_group!(
+    benches,
+    big_array_ser_and_de,
+    byte_array,
+    fixed_size_array,
+    cow_byte_array,
+    duration_array,
+    ipv4_route_table,
+    string_dict
+);
 #[cfg(not(feature = "serde_bytes"))]
-criterion_group!(benches, big_array_ser_and_de, fixed_size_array);
+criterion_group!(
+    benches,
+    big_array_ser_and_de,
+    fixed_size_array,
+    cow_byte_array,
+    duration_array,
+    ipv4_route_table,
+    string_dict
+);
 criterion_main!(benches);
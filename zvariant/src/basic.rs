@@ -11,6 +11,21 @@ pub trait Basic: Type {
     const SIGNATURE_CHAR: char;
     /// The type signature, as a string.
     const SIGNATURE_STR: &'static str;
+    /// The type signature.
+    ///
+    /// Unlike [`Type::signature`], this can be used in const contexts, e.g to build lookup tables
+    /// of signatures without any lazy initialization.
+    ///
+    /// ```
+    /// use zvariant::{Basic, Signature};
+    ///
+    /// const FIELDS: [(&str, Signature<'static>); 2] =
+    ///     [("Name", <&str>::SIGNATURE), ("Count", u32::SIGNATURE)];
+    ///
+    /// assert_eq!(FIELDS[0].1, "s");
+    /// assert_eq!(FIELDS[1].1, "u");
+    /// ```
+    const SIGNATURE: Signature<'static> = Signature::from_static_str_unchecked(Self::SIGNATURE_STR);
 
     /// The required padding alignment for the given format.
     fn alignment(format: Format) -> usize;
@@ -96,7 +111,8 @@ impl Basic for bool {
     const SIGNATURE_CHAR: char = 'b';
     const SIGNATURE_STR: &'static str = "b";
 
-    alignment_method!(4);
+    // A `u32` in D-Bus but a single byte in GVariant.
+    alignment_method!(4, 1);
 }
 impl_type!(bool);
 
@@ -196,6 +212,26 @@ impl Basic for std::num::NonZeroU64 {
 }
 impl_type!(std::num::NonZeroU64);
 
+// `Wrapping` is (de)serialized as the wrapped integer. It's only implemented for the integer types,
+// so nonsensical wrappers like `Wrapping<char>` don't implement `Type`.
+macro_rules! wrapping_impl {
+    ($($int:ty)*) => {
+        $(
+            impl Basic for std::num::Wrapping<$int> {
+                const SIGNATURE_CHAR: char = <$int>::SIGNATURE_CHAR;
+                const SIGNATURE_STR: &'static str = <$int>::SIGNATURE_STR;
+
+                fn alignment(format: Format) -> usize {
+                    <$int>::alignment(format)
+                }
+            }
+            impl_type!(std::num::Wrapping<$int>);
+        )*
+    };
+}
+
+wrapping_impl!(u8 i8 i16 u16 i32 u32 i64 u64);
+
 // No f32 type in D-Bus/GVariant, let's pretend it's f64
 impl Basic for f32 {
     const SIGNATURE_CHAR: char = f64::SIGNATURE_CHAR;
@@ -216,6 +252,46 @@ impl Basic for f64 {
 }
 impl_type!(f64);
 
+#[cfg(feature = "ordered-float")]
+impl Basic for ordered_float::OrderedFloat<f32> {
+    const SIGNATURE_CHAR: char = f32::SIGNATURE_CHAR;
+    const SIGNATURE_STR: &'static str = f32::SIGNATURE_STR;
+
+    alignment_method!(
+        f32::alignment(Format::DBus),
+        f32::alignment(Format::GVariant)
+    );
+}
+#[cfg(feature = "ordered-float")]
+impl_type!(ordered_float::OrderedFloat<f32>);
+
+#[cfg(feature = "ordered-float")]
+impl Basic for ordered_float::OrderedFloat<f64> {
+    const SIGNATURE_CHAR: char = f64::SIGNATURE_CHAR;
+    const SIGNATURE_STR: &'static str = f64::SIGNATURE_STR;
+
+    alignment_method!(8);
+}
+#[cfg(feature = "ordered-float")]
+impl_type!(ordered_float::OrderedFloat<f64>);
+
+// Promoted to f64, just like f32. Its serde implementation (de)serializes its bits as a `u16`, which
+// the (de)serializers convert from/to an f64 where the signature has a `d`. Since the conversion back
+// is lossy, values with more precision than f16 are rounded on deserialization, and ones out of its
+// range become infinities.
+#[cfg(feature = "half")]
+impl Basic for half::f16 {
+    const SIGNATURE_CHAR: char = f32::SIGNATURE_CHAR;
+    const SIGNATURE_STR: &'static str = f32::SIGNATURE_STR;
+
+    alignment_method!(
+        f32::alignment(Format::DBus),
+        f32::alignment(Format::GVariant)
+    );
+}
+#[cfg(feature = "half")]
+impl_type!(half::f16);
+
 impl Basic for str {
     const SIGNATURE_CHAR: char = 's';
     const SIGNATURE_STR: &'static str = "s";
@@ -0,0 +1,373 @@
+//! Support for encoding [`Bound`] as a `(yv)` structure.
+//!
+//! The serde implementation of [`Bound`] (de)serializes it as an enum, with `Unbounded`, `Included`
+//! and `Excluded` as its variants 0, 1 and 2, in that order. Our (de)serializers encode it as a
+//! `(yv)` structure instead, with the variant index as the tag and the bound value as the payload,
+//! using the helpers here. The payload of `Unbounded` is a `0u8`, the way we encode unit types.
+//!
+//! Since the signature of the payload isn't available to the serializer, it's derived from how the
+//! bound value serializes itself, which is why only [`Basic`] bound values are supported. String-like
+//! values, including object paths and signatures, are all encoded as `s`.
+//!
+//! [`Bound`]: std::ops::Bound
+//! [`Basic`]: crate::Basic
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{
+        self, value::U32Deserializer, DeserializeSeed, Deserializer, EnumAccess, IgnoredAny,
+        SeqAccess, VariantAccess, Visitor,
+    },
+    ser::{Impossible, Serialize, SerializeStruct, Serializer},
+};
+
+use crate::{Basic, Error, Result, Signature};
+
+/// The name serde gives to the [`std::ops::Bound`] enum.
+pub(crate) const BOUND_ENUM_NAME: &str = "Bound";
+
+/// The signature of an encoded [`std::ops::Bound`].
+pub(crate) const BOUND_SIGNATURE_STR: &str = "(yv)";
+
+const UNBOUNDED_TAG: u32 = 0;
+const MAX_TAG: u8 = 2;
+
+const VALUE_FIELDS: &[&str] = &["zvariant::Value::Signature", "zvariant::Value::Value"];
+
+/// A variant of a serialized [`std::ops::Bound`], as its `(yv)` structure.
+pub(crate) struct BoundFields<'a, T: ?Sized> {
+    tag: u32,
+    value: Option<&'a T>,
+}
+
+impl<'a, T> BoundFields<'a, T>
+where
+    T: ?Sized + Serialize,
+{
+    /// The fields of a bound variant with a value, i-e `Included` or `Excluded`.
+    pub(crate) fn new(variant_index: u32, value: &'a T) -> Self {
+        Self {
+            tag: variant_index,
+            value: Some(value),
+        }
+    }
+}
+
+impl BoundFields<'static, u8> {
+    /// The fields of the `Unbounded` variant.
+    pub(crate) fn unbounded() -> Self {
+        Self {
+            tag: UNBOUNDED_TAG,
+            value: None,
+        }
+    }
+}
+
+impl<'a, T> Serialize for BoundFields<'a, T>
+where
+    T: ?Sized + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let tag = u8::try_from(self.tag)
+            .ok()
+            .filter(|tag| *tag <= MAX_TAG)
+            .ok_or_else(|| {
+                serde::ser::Error::custom(format!("invalid `Bound` tag {}", self.tag))
+            })?;
+
+        let mut structure = serializer.serialize_struct(BOUND_ENUM_NAME, 2)?;
+        structure.serialize_field("tag", &tag)?;
+        match self.value {
+            Some(value) => {
+                let signature = value
+                    .serialize(BasicSignature)
+                    .map_err(serde::ser::Error::custom)?;
+                structure.serialize_field("payload", &Payload { signature, value })?;
+            }
+            None => structure.serialize_field(
+                "payload",
+                &Payload {
+                    signature: u8::SIGNATURE_STR,
+                    value: &0u8,
+                },
+            )?,
+        }
+
+        structure.end()
+    }
+}
+
+// The payload, serialized the same way as a `Value`.
+struct Payload<'a, T: ?Sized> {
+    signature: &'static str,
+    value: &'a T,
+}
+
+impl<'a, T> Serialize for Payload<'a, T>
+where
+    T: ?Sized + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut structure = serializer.serialize_struct("zvariant::Value", 2)?;
+        structure.serialize_field(
+            VALUE_FIELDS[0],
+            &Signature::from_static_str_unchecked(self.signature),
+        )?;
+        structure.serialize_field(VALUE_FIELDS[1], self.value)?;
+
+        structure.end()
+    }
+}
+
+/// Visits the `(yv)` structure of an encoded [`std::ops::Bound`], handing it to the visitor of its
+/// serde implementation as an enum.
+pub(crate) struct BoundVisitor<V>(pub(crate) V);
+
+impl<'de, V> Visitor<'de> for BoundVisitor<V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a `Bound` structure")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let tag: u8 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        if tag > MAX_TAG {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(tag.into()),
+                &"a `Bound` tag of 0, 1 or 2",
+            ));
+        }
+
+        self.0.visit_enum(BoundAccess { tag, seq })
+    }
+}
+
+struct BoundAccess<A> {
+    tag: u8,
+    seq: A,
+}
+
+impl<'de, A> EnumAccess<'de> for BoundAccess<A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+    type Variant = Self;
+
+    fn variant_seed<S>(self, seed: S) -> std::result::Result<(S::Value, Self), A::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let index = U32Deserializer::<A::Error>::new(self.tag.into());
+
+        seed.deserialize(index).map(|v| (v, self))
+    }
+}
+
+impl<'de, A> VariantAccess<'de> for BoundAccess<A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn unit_variant(mut self) -> std::result::Result<(), A::Error> {
+        // `Unbounded` has no value, so whatever the payload holds is ignored.
+        self.seq
+            .next_element_seed(PayloadSeed(PhantomData::<IgnoredAny>))?
+            .map(|_| ())
+            .ok_or_else(|| de::Error::invalid_length(1, &"a `Bound` payload"))
+    }
+
+    fn newtype_variant_seed<S>(mut self, seed: S) -> std::result::Result<S::Value, A::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.seq
+            .next_element_seed(PayloadSeed(seed))?
+            .ok_or_else(|| de::Error::invalid_length(1, &"a `Bound` payload"))
+    }
+
+    fn tuple_variant<V>(self, _len: usize, _visitor: V) -> std::result::Result<V::Value, A::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::invalid_type(
+            de::Unexpected::TupleVariant,
+            &"a `Bound` variant",
+        ))
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> std::result::Result<V::Value, A::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::invalid_type(
+            de::Unexpected::StructVariant,
+            &"a `Bound` variant",
+        ))
+    }
+}
+
+// Deserializes the payload with `seed`, whatever its signature.
+struct PayloadSeed<S>(S);
+
+impl<'de, S> DeserializeSeed<'de> for PayloadSeed<S>
+where
+    S: DeserializeSeed<'de>,
+{
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<S::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("zvariant::Value", VALUE_FIELDS, PayloadVisitor(self.0))
+    }
+}
+
+struct PayloadVisitor<S>(S);
+
+impl<'de, S> Visitor<'de> for PayloadVisitor<S>
+where
+    S: DeserializeSeed<'de>,
+{
+    type Value = S::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a `Bound` payload")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<S::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        seq.next_element::<Signature<'_>>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+        seq.next_element_seed(self.0)?
+            .ok_or_else(|| de::Error::invalid_length(1, &"a `Bound` payload"))
+    }
+}
+
+// Finds out the signature of a basic value from how it serializes itself.
+struct BasicSignature;
+
+macro_rules! basic_signature {
+    ($($method:ident($ty:ty) => $signature:literal),* $(,)?) => {
+        $(
+            fn $method(self, _v: $ty) -> Result<&'static str> {
+                Ok($signature)
+            }
+        )*
+    };
+}
+
+macro_rules! not_basic {
+    ($($method:ident($($arg:ty),*) -> $ok:ty),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ok> {
+                Err(not_basic_error())
+            }
+        )*
+    };
+}
+
+fn not_basic_error() -> Error {
+    Error::Message("only basic types are supported as `Bound` values".into())
+}
+
+impl Serializer for BasicSignature {
+    type Ok = &'static str;
+    type Error = Error;
+    type SerializeSeq = Impossible<&'static str, Error>;
+    type SerializeTuple = Impossible<&'static str, Error>;
+    type SerializeTupleStruct = Impossible<&'static str, Error>;
+    type SerializeTupleVariant = Impossible<&'static str, Error>;
+    type SerializeMap = Impossible<&'static str, Error>;
+    type SerializeStruct = Impossible<&'static str, Error>;
+    type SerializeStructVariant = Impossible<&'static str, Error>;
+
+    basic_signature! {
+        serialize_bool(bool) => "b",
+        serialize_i8(i8) => "n",
+        serialize_i16(i16) => "n",
+        serialize_i32(i32) => "i",
+        serialize_i64(i64) => "x",
+        serialize_u8(u8) => "y",
+        serialize_u16(u16) => "q",
+        serialize_u32(u32) => "u",
+        serialize_u64(u64) => "t",
+        serialize_f32(f32) => "d",
+        serialize_f64(f64) => "d",
+        serialize_char(char) => "s",
+        serialize_str(&str) => "s",
+    }
+
+    not_basic! {
+        serialize_bytes(&[u8]) -> &'static str,
+        serialize_none() -> &'static str,
+        serialize_unit() -> &'static str,
+        serialize_unit_struct(&'static str) -> &'static str,
+        serialize_unit_variant(&'static str, u32, &'static str) -> &'static str,
+        serialize_seq(Option<usize>) -> Self::SerializeSeq,
+        serialize_tuple(usize) -> Self::SerializeTuple,
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct,
+        serialize_tuple_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeTupleVariant,
+        serialize_map(Option<usize>) -> Self::SerializeMap,
+        serialize_struct(&'static str, usize) -> Self::SerializeStruct,
+        serialize_struct_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeStructVariant,
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<&'static str>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(not_basic_error())
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<&'static str>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<&'static str>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(not_basic_error())
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
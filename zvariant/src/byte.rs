@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{serialized::Format, Basic, Error, Signature, Type, Value};
+
+/// A byte, encoded as a D-Bus `y`.
+///
+/// This is encoded exactly like [`u8`], but makes it explicit that the value is an opaque byte,
+/// rather than a small number or a part of a string. For example, `Vec<Byte>` is an `ay` byte
+/// array.
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::{serialized::Context, to_bytes, Basic, Byte, Type, LE};
+///
+/// assert_eq!(Byte::SIGNATURE_STR, "y");
+/// assert_eq!(<Vec<Byte>>::signature(), "ay");
+///
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let bytes = vec![Byte(0xde), Byte(0xad)];
+/// let encoded = to_bytes(ctxt, &bytes).unwrap();
+/// assert_eq!(encoded.bytes(), to_bytes(ctxt, &vec![0xdeu8, 0xad]).unwrap().bytes());
+/// let decoded: Vec<Byte> = encoded.deserialize().unwrap().0;
+/// assert_eq!(decoded, bytes);
+/// ```
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Byte(pub u8);
+
+impl Basic for Byte {
+    const SIGNATURE_CHAR: char = u8::SIGNATURE_CHAR;
+    const SIGNATURE_STR: &'static str = u8::SIGNATURE_STR;
+
+    fn alignment(format: Format) -> usize {
+        u8::alignment(format)
+    }
+}
+
+impl Type for Byte {
+    fn signature() -> Signature<'static> {
+        u8::signature()
+    }
+}
+
+impl From<u8> for Byte {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Byte> for u8 {
+    fn from(value: Byte) -> Self {
+        value.0
+    }
+}
+
+impl From<Byte> for Value<'_> {
+    fn from(value: Byte) -> Self {
+        Value::U8(value.0)
+    }
+}
+
+impl TryFrom<Value<'_>> for Byte {
+    type Error = Error;
+
+    fn try_from(value: Value<'_>) -> Result<Self, Self::Error> {
+        u8::try_from(value).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{serialized::Context, to_bytes, Basic, Byte, Type, Value, LE};
+
+    #[test]
+    fn byte() {
+        assert_eq!(Byte::SIGNATURE_CHAR, u8::SIGNATURE_CHAR);
+        assert_eq!(Byte::signature(), u8::signature());
+        assert_eq!(<Vec<Byte>>::signature(), "ay");
+        assert_eq!(<(Byte, u32)>::signature(), "(yu)");
+
+        // Identical on the wire, in both directions.
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &(Byte(7), 42u32)).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            to_bytes(ctxt, &(7u8, 42u32)).unwrap().bytes()
+        );
+        let decoded: (u8, u32) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, (7, 42));
+        let encoded = to_bytes(ctxt, &(7u8, 42u32)).unwrap();
+        let decoded: (Byte, u32) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, (Byte(7), 42));
+
+        #[cfg(feature = "gvariant")]
+        {
+            let ctxt = Context::new_gvariant(LE, 0);
+            let bytes = vec![Byte(1), Byte(2), Byte(3)];
+            let encoded = to_bytes(ctxt, &bytes).unwrap();
+            assert_eq!(encoded.bytes(), [1, 2, 3]);
+            let decoded: Vec<Byte> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, bytes);
+        }
+
+        let value = Value::from(Byte(0xff));
+        assert_eq!(value, Value::U8(0xff));
+        assert_eq!(Byte::try_from(value).unwrap(), Byte(0xff));
+        Byte::try_from(Value::U32(1)).unwrap_err();
+    }
+}
@@ -7,7 +7,9 @@ use std::{marker::PhantomData, str};
 use std::os::fd::AsFd;
 
 use crate::{
+    bound::{BoundVisitor, BOUND_ENUM_NAME, BOUND_SIGNATURE_STR},
     de::{DeserializerCommon, ValueParseStage},
+    duration::{visit_micros, DURATION_STRUCT_NAME},
     serialized::{Context, Format},
     signature_parser::SignatureParser,
     utils::*,
@@ -15,7 +17,10 @@ use crate::{
 };
 
 #[cfg(unix)]
-use crate::Fd;
+use crate::{
+    os_str::{OsStringAccess, OS_STRING_ENUM_NAME},
+    Fd,
+};
 
 /// Our D-Bus deserialization implementation.
 #[derive(Debug)]
@@ -134,7 +139,6 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
 
     deserialize_basic!(deserialize_i16 read_i16 visit_i16(i16));
     deserialize_basic!(deserialize_i64 read_i64 visit_i64(i64));
-    deserialize_basic!(deserialize_u16 read_u16 visit_u16(u16));
     deserialize_basic!(deserialize_u32 read_u32 visit_u32(u32));
     deserialize_basic!(deserialize_u64 read_u64 visit_u64(u64));
     deserialize_basic!(deserialize_f64 read_f64 visit_f64(f64));
@@ -143,6 +147,10 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
     where
         V: Visitor<'de>,
     {
+        if self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
+            return self.deserialize_str(visitor);
+        }
+
         let bytes = deserialize_ay(self)?;
         visitor.visit_byte_buf(bytes.into())
     }
@@ -151,6 +159,10 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
     where
         V: Visitor<'de>,
     {
+        if self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
+            return self.deserialize_str(visitor);
+        }
+
         let bytes = deserialize_ay(self)?;
         visitor.visit_borrowed_bytes(bytes)
     }
@@ -159,7 +171,6 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
     deserialize_as!(deserialize_string => deserialize_str);
     deserialize_as!(deserialize_tuple(_l: usize) => deserialize_struct("", &[]));
     deserialize_as!(deserialize_tuple_struct(n: &'static str, _l: usize) => deserialize_struct(n, &[]));
-    deserialize_as!(deserialize_struct(_n: &'static str, _f: &'static [&'static str]) => deserialize_seq());
     deserialize_as!(deserialize_map => deserialize_seq);
     deserialize_as!(deserialize_ignored_any => deserialize_any);
 
@@ -194,6 +205,32 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
         visitor.visit_u8(self.0.next_const_size_slice::<u8>().map(|bytes| bytes[0])?)
     }
 
+    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let v = match self.0.sig_parser.next_char()? {
+            // `half::f16` is deserialized from its bits, but encoded as a `d`, just like `f32`.
+            #[cfg(feature = "half")]
+            half::f16::SIGNATURE_CHAR => {
+                let v = self
+                    .0
+                    .ctxt
+                    .endian()
+                    .read_f64(self.0.next_const_size_slice::<f64>()?);
+
+                half::f16::from_f64(v).to_bits()
+            }
+            _ => self
+                .0
+                .ctxt
+                .endian()
+                .read_u16(self.0.next_const_size_slice::<u16>()?),
+        };
+
+        visitor.visit_u16(v)
+    }
+
     fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
@@ -364,6 +401,16 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
         V: Visitor<'de>,
     {
         let signature = self.0.sig_parser.next_signature()?;
+        if name == BOUND_ENUM_NAME && signature == BOUND_SIGNATURE_STR {
+            return self.deserialize_seq(BoundVisitor(visitor));
+        }
+        #[cfg(unix)]
+        if name == OS_STRING_ENUM_NAME && signature == <&str>::SIGNATURE_STR {
+            let s: String = serde::Deserialize::deserialize(&mut *self)?;
+
+            return visitor.visit_enum(OsStringAccess(s));
+        }
+
         let alignment = alignment_for_signature(&signature, self.0.ctxt.format())?;
         self.0.parse_padding(alignment)?;
 
@@ -390,6 +437,24 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
         Ok(v)
     }
 
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        if name == DURATION_STRUCT_NAME && self.0.sig_parser.next_char()? == u64::SIGNATURE_CHAR {
+            let micros: u64 = serde::Deserialize::deserialize(&mut *self)?;
+
+            return visit_micros(micros, visitor);
+        }
+
+        self.deserialize_seq(visitor)
+    }
+
     fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
@@ -6,7 +6,9 @@ use std::{
 };
 
 use crate::{
+    bound::{BoundFields, BOUND_ENUM_NAME, BOUND_SIGNATURE_STR},
     container_depths::ContainerDepths,
+    duration::{DurationFields, DURATION_STRUCT_NAME},
     serialized::{Context, Format},
     signature_parser::SignatureParser,
     utils::*,
@@ -14,7 +16,10 @@ use crate::{
 };
 
 #[cfg(unix)]
-use crate::Fd;
+use crate::{
+    os_str::{os_str_to_string, OS_STRING_ENUM_NAME},
+    Fd,
+};
 
 /// Our D-Bus serialization implementation.
 pub(crate) struct Serializer<'ser, 'sig, W>(pub(crate) crate::SerializerCommon<'ser, 'sig, W>);
@@ -116,7 +121,20 @@ where
             .map_err(|e| Error::InputOutput(e.into()))
     }
 
-    serialize_basic!(serialize_u16(u16) write_u16);
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        match self.0.sig_parser.next_char()? {
+            // `half::f16` is serialized as its bits, but encoded as a `d`, just like `f32`.
+            #[cfg(feature = "half")]
+            half::f16::SIGNATURE_CHAR => self.serialize_f64(half::f16::from_bits(v).into()),
+            _ => {
+                self.0.prep_serialize_basic::<u16>()?;
+                self.0
+                    .write_u16(self.0.ctxt.endian(), v)
+                    .map_err(|e| Error::InputOutput(e.into()))
+            }
+        }
+    }
+
     serialize_basic!(serialize_u32(u32) write_u32);
     serialize_basic!(serialize_u64(u64) write_u64);
     // No f32 type in D-Bus/GVariant, let's pretend it's f64
@@ -148,6 +166,13 @@ where
                     .map_err(|e| Error::InputOutput(e.into()))?;
             }
             Signature::SIGNATURE_CHAR | VARIANT_SIGNATURE_CHAR => {
+                // Unlike strings, signatures only have a single byte for the length.
+                if v.len() > u8::MAX as usize {
+                    return Err(serde::ser::Error::custom(format!(
+                        "signature of {} characters, expected <= 255 characters",
+                        v.len(),
+                    )));
+                }
                 self.0
                     .write_u8(self.0.ctxt.endian(), usize_to_u8(v.len()))
                     .map_err(|e| Error::InputOutput(e.into()))?;
@@ -179,6 +204,11 @@ where
     }
 
     fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        // Types like `CString` serialize as bytes but are encoded as strings.
+        if self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
+            return self.serialize_str(str::from_utf8(v).map_err(Error::Utf8)?);
+        }
+
         let seq = self.serialize_seq(Some(v.len()))?;
         seq.ser
             .0
@@ -227,10 +257,14 @@ where
 
     fn serialize_unit_variant(
         self,
-        _name: &'static str,
+        name: &'static str,
         variant_index: u32,
         variant: &'static str,
     ) -> Result<()> {
+        if name == BOUND_ENUM_NAME && self.0.sig_parser.next_signature()? == BOUND_SIGNATURE_STR {
+            return BoundFields::unbounded().serialize(self);
+        }
+
         if self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
             variant.serialize(self)
         } else {
@@ -249,7 +283,7 @@ where
 
     fn serialize_newtype_variant<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         variant_index: u32,
         _variant: &'static str,
         value: &T,
@@ -257,6 +291,14 @@ where
     where
         T: ?Sized + Serialize,
     {
+        if name == BOUND_ENUM_NAME && self.0.sig_parser.next_signature()? == BOUND_SIGNATURE_STR {
+            return BoundFields::new(variant_index, value).serialize(self);
+        }
+        #[cfg(unix)]
+        if name == OS_STRING_ENUM_NAME && self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
+            return os_str_to_string(variant_index, value)?.serialize(self);
+        }
+
         self.0.prep_serialize_enum_variant(variant_index)?;
         value.serialize(&mut *self)?;
         // Skip the `)`.
@@ -321,7 +363,14 @@ where
         self.serialize_seq(len)
     }
 
-    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+        if name == DURATION_STRUCT_NAME && self.0.sig_parser.next_char()? == u64::SIGNATURE_CHAR {
+            return Ok(StructSeqSerializer::Duration(DurationSerializer {
+                ser: self,
+                fields: DurationFields::default(),
+            }));
+        }
+
         if len == 0 {
             return StructSerializer::unit(self).map(StructSeqSerializer::Struct);
         }
@@ -549,6 +598,14 @@ where
 pub enum StructSeqSerializer<'ser, 'sig, 'b, W> {
     Struct(StructSerializer<'ser, 'sig, 'b, W>),
     Seq(SeqSerializer<'ser, 'sig, 'b, W>),
+    Duration(DurationSerializer<'ser, 'sig, 'b, W>),
+}
+
+#[doc(hidden)]
+/// Serializes a `Duration` as a count of microseconds.
+pub struct DurationSerializer<'ser, 'sig, 'b, W> {
+    ser: &'b mut Serializer<'ser, 'sig, W>,
+    fields: DurationFields,
 }
 
 macro_rules! serialize_struct_anon_fields {
@@ -586,6 +643,7 @@ macro_rules! serialize_struct_anon_fields {
                 match self {
                     StructSeqSerializer::Struct(ser) => ser.$method(value),
                     StructSeqSerializer::Seq(ser) => ser.serialize_element(value),
+                    StructSeqSerializer::Duration(ser) => ser.fields.add_field("", value),
                 }
             }
 
@@ -593,6 +651,7 @@ macro_rules! serialize_struct_anon_fields {
                 match self {
                     StructSeqSerializer::Struct(ser) => ser.end_struct(),
                     StructSeqSerializer::Seq(ser) => ser.end_seq(),
+                    StructSeqSerializer::Duration(ser) => ser.fields.micros()?.serialize(ser.ser),
                 }
             }
         }
@@ -648,6 +707,29 @@ where
         Ok(())
     }
 
+    // Same as `serialize_key` followed by `serialize_value`, but the signature of the entry is
+    // parsed in one go, so it's cloned once per entry rather than twice each for key and value.
+    // This matters for dicts of cheap entries, like the very common `a{ss}`.
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<()>
+    where
+        K: ?Sized + Serialize,
+        V: ?Sized + Serialize,
+    {
+        self.ser.0.add_padding(self.element_alignment)?;
+
+        let sig_parser = self.ser.0.sig_parser.clone();
+        // skip `{`
+        self.ser.0.sig_parser.skip_char()?;
+
+        // Keys are basic types, so the parser is at the value once the key is serialized.
+        key.serialize(&mut *self.ser)?;
+        value.serialize(&mut *self.ser)?;
+        // Restore the original parser
+        self.ser.0.sig_parser = sig_parser;
+
+        Ok(())
+    }
+
     fn end(self) -> Result<()> {
         self.end_seq()
     }
@@ -688,6 +770,7 @@ macro_rules! serialize_struct_named_fields {
                 match self {
                     StructSeqSerializer::Struct(ser) => ser.serialize_field(key, value),
                     StructSeqSerializer::Seq(ser) => ser.serialize_element(value),
+                    StructSeqSerializer::Duration(ser) => ser.fields.add_field(key, value),
                 }
             }
 
@@ -695,6 +778,7 @@ macro_rules! serialize_struct_named_fields {
                 match self {
                     StructSeqSerializer::Struct(ser) => ser.end_struct(),
                     StructSeqSerializer::Seq(ser) => ser.end_seq(),
+                    StructSeqSerializer::Duration(ser) => ser.fields.micros()?.serialize(ser.ser),
                 }
             }
         }
@@ -0,0 +1,184 @@
+use std::marker::PhantomData;
+
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct, Serializer},
+};
+use static_assertions::assert_impl_all;
+
+use crate::{DeserializeValue, SerializeValue, Signature, Type};
+
+/// A success or failure payload, transmitted in-band.
+///
+/// Some D-Bus APIs report the outcome of an operation as part of a reply or signal, rather than
+/// through D-Bus errors. `DBusResult` represents such values. It's encoded as a `(bv)` structure,
+/// where the boolean is `true` for [`DBusResult::Ok`] and the variant holds either the `T` or the
+/// `E` payload. On deserialization, the boolean decides the expected payload type.
+///
+/// It can be converted to and from a [`Result`].
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::{serialized::Context, to_bytes, DBusResult, Type, LE};
+///
+/// assert_eq!(DBusResult::<u32, String>::signature(), "(bv)");
+///
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let result = DBusResult::<u32, String>::Err("Nope".to_string());
+/// let encoded = to_bytes(ctxt, &result).unwrap();
+/// let decoded: DBusResult<u32, String> = encoded.deserialize().unwrap().0;
+/// assert_eq!(Result::from(decoded), Err("Nope".to_string()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DBusResult<T, E> {
+    /// The success payload.
+    Ok(T),
+    /// The failure payload.
+    Err(E),
+}
+
+assert_impl_all!(DBusResult<i32, String>: Send, Sync, Unpin);
+
+impl<T, E> DBusResult<T, E> {
+    /// Whether `self` is [`DBusResult::Ok`].
+    pub fn is_ok(&self) -> bool {
+        matches!(self, DBusResult::Ok(_))
+    }
+
+    /// Whether `self` is [`DBusResult::Err`].
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+}
+
+impl<T, E> Type for DBusResult<T, E> {
+    fn signature() -> Signature<'static> {
+        Signature::from_static_str_unchecked("(bv)")
+    }
+}
+
+impl<T, E> From<Result<T, E>> for DBusResult<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(v) => DBusResult::Ok(v),
+            Err(e) => DBusResult::Err(e),
+        }
+    }
+}
+
+impl<T, E> From<DBusResult<T, E>> for Result<T, E> {
+    fn from(result: DBusResult<T, E>) -> Self {
+        match result {
+            DBusResult::Ok(v) => Ok(v),
+            DBusResult::Err(e) => Err(e),
+        }
+    }
+}
+
+const FIELDS: &[&str] = &["ok", "payload"];
+
+impl<T, E> Serialize for DBusResult<T, E>
+where
+    T: Type + Serialize,
+    E: Type + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut structure = serializer.serialize_struct("DBusResult", FIELDS.len())?;
+        match self {
+            DBusResult::Ok(v) => {
+                structure.serialize_field(FIELDS[0], &true)?;
+                structure.serialize_field(FIELDS[1], &SerializeValue(v))?;
+            }
+            DBusResult::Err(e) => {
+                structure.serialize_field(FIELDS[0], &false)?;
+                structure.serialize_field(FIELDS[1], &SerializeValue(e))?;
+            }
+        }
+
+        structure.end()
+    }
+}
+
+impl<'de, T, E> Deserialize<'de> for DBusResult<T, E>
+where
+    T: Type + Deserialize<'de> + 'de,
+    E: Type + Deserialize<'de> + 'de,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("DBusResult", FIELDS, DBusResultVisitor(PhantomData))
+    }
+}
+
+struct DBusResultVisitor<T, E>(PhantomData<(T, E)>);
+
+impl<'de, T, E> Visitor<'de> for DBusResultVisitor<T, E>
+where
+    T: Type + Deserialize<'de> + 'de,
+    E: Type + Deserialize<'de> + 'de,
+{
+    type Value = DBusResult<T, E>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a DBusResult structure")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let ok: bool = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+        if ok {
+            seq.next_element::<DeserializeValue<'de, T>>()?
+                .map(|v| DBusResult::Ok(v.0))
+        } else {
+            seq.next_element::<DeserializeValue<'de, E>>()?
+                .map(|e| DBusResult::Err(e.0))
+        }
+        .ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{serialized::Context, to_bytes, DBusResult, Type, LE};
+
+    #[test]
+    fn dbus_result() {
+        assert_eq!(DBusResult::<u32, &str>::signature(), "(bv)");
+
+        let ctxt = Context::new_dbus(LE, 0);
+
+        let ok = DBusResult::<u32, &str>::Ok(42);
+        assert!(ok.is_ok());
+        let encoded = to_bytes(ctxt, &ok).unwrap();
+        // bool tag, `u` signature and the u32 payload, aligned to 4.
+        assert_eq!(encoded.len(), 12);
+        let (decoded, parsed): (DBusResult<u32, &str>, _) = encoded.deserialize().unwrap();
+        assert_eq!(parsed, 12);
+        assert_eq!(decoded, ok);
+        assert_eq!(Result::from(decoded), Ok(42));
+
+        let err = DBusResult::<u32, &str>::from(Err("Operation failed"));
+        assert!(err.is_err());
+        let encoded = to_bytes(ctxt, &err).unwrap();
+        let decoded: DBusResult<u32, &str> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, err);
+        assert_eq!(Result::from(decoded), Err("Operation failed"));
+
+        // The tag decides the expected payload type.
+        let encoded = to_bytes(ctxt, &(true, crate::Value::from("not a u32"))).unwrap();
+        encoded
+            .deserialize_for_signature::<_, DBusResult<u32, &str>>("(bv)")
+            .unwrap_err();
+    }
+}
@@ -162,6 +162,70 @@ impl<'k, 'v> Dict<'k, 'v> {
     // TODO: Provide more API like https://docs.rs/toml/0.5.5/toml/map/struct.Map.html
 }
 
+/// Use this to conveniently build an `a{sv}` dictionary.
+///
+/// Values of different types can be added, each of which is wrapped in a [`Value`]. The result is
+/// a `HashMap<String, Value<'_>>`, which encodes as `a{sv}`.
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::{DictBuilder, Type, Value};
+///
+/// let dict = DictBuilder::new()
+///     .add("answer", 42u32)
+///     .add("name", "zvariant")
+///     .build();
+/// assert_eq!(<std::collections::HashMap<String, Value<'_>>>::signature(), "a{sv}");
+/// assert_eq!(dict["answer"], Value::U32(42));
+/// ```
+///
+/// [`Value`]: enum.Value.html
+#[derive(Debug, Default, PartialEq)]
+pub struct DictBuilder<'a>(HashMap<String, Value<'a>>);
+
+assert_impl_all!(DictBuilder<'_>: Send, Sync, Unpin);
+
+impl<'a> DictBuilder<'a> {
+    /// Create a new `DictBuilder`.
+    ///
+    /// Same as `DictBuilder::default()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an entry with the given `key` and `value` to `self`.
+    ///
+    /// If an entry with the same key already exists, its value is replaced. This method returns
+    /// `Self` so that you can use the builder pattern to create a dictionary.
+    #[must_use]
+    pub fn add<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: DynamicType + Into<Value<'a>>,
+    {
+        self.push(key, value);
+
+        self
+    }
+
+    /// Add an entry with the given `key` and `value` to `self`.
+    ///
+    /// Identical to `add`, except it makes changes in-place.
+    pub fn push<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<String>,
+        V: DynamicType + Into<Value<'a>>,
+    {
+        self.0.insert(key.into(), Value::new(value));
+    }
+
+    /// Build the dictionary.
+    pub fn build(self) -> HashMap<String, Value<'a>> {
+        self.0
+    }
+}
+
 impl Display for Dict<'_, '_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         dict_display_fmt(self, f, true)
@@ -0,0 +1,187 @@
+//! Support for encoding [`Duration`] as a `t` count of microseconds.
+//!
+//! The serde implementation of [`Duration`] (de)serializes it as a struct of seconds and
+//! nanoseconds, which we encode as a `(tu)` structure by default. When the signature asks for a
+//! `t` instead, e.g for [`DurationMicros`], our (de)serializers convert between the two
+//! representations using the helpers here.
+//!
+//! [`Duration`]: std::time::Duration
+//! [`DurationMicros`]: crate::DurationMicros
+
+use serde::{
+    de::{value::SeqDeserializer, Visitor},
+    ser::Impossible,
+    Serialize, Serializer,
+};
+
+use crate::{Error, Result};
+
+/// The name serde gives to the [`std::time::Duration`] struct.
+pub(crate) const DURATION_STRUCT_NAME: &str = "Duration";
+
+const MICROS_PER_SEC: u64 = 1_000_000;
+const NANOS_PER_MICRO: u32 = 1_000;
+
+/// Collects the fields of a serialized [`std::time::Duration`], to encode it in microseconds.
+#[derive(Debug, Default)]
+pub(crate) struct DurationFields {
+    secs: Option<u64>,
+    nanos: Option<u32>,
+}
+
+impl DurationFields {
+    pub(crate) fn add_field<T>(&mut self, key: &str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        match key {
+            "secs" => self.secs = Some(capture(value)?),
+            "nanos" => self.nanos = Some(capture(value)?),
+            _ => {
+                return Err(Error::Message(format!(
+                    "unexpected field `{key}` in a `Duration`"
+                )))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The whole duration in microseconds. Any sub-microsecond precision is truncated.
+    pub(crate) fn micros(&self) -> Result<u64> {
+        let (Some(secs), Some(nanos)) = (self.secs, self.nanos) else {
+            return Err(Error::Message("incomplete `Duration`".into()));
+        };
+
+        secs.checked_mul(MICROS_PER_SEC)
+            .and_then(|micros| micros.checked_add(u64::from(nanos / NANOS_PER_MICRO)))
+            .ok_or_else(|| Error::Message("`Duration` too long to encode in microseconds".into()))
+    }
+}
+
+/// Hand `micros` to the visitor of a [`std::time::Duration`], as its seconds and nanoseconds.
+pub(crate) fn visit_micros<'de, V>(micros: u64, visitor: V) -> Result<V::Value>
+where
+    V: Visitor<'de>,
+{
+    let secs = micros / MICROS_PER_SEC;
+    let nanos = (micros % MICROS_PER_SEC) * u64::from(NANOS_PER_MICRO);
+
+    visitor.visit_seq(SeqDeserializer::<_, Error>::new([secs, nanos].into_iter()))
+}
+
+// Get the integer value of a field. Serializing straight to the integer avoids any allocation, which
+// matters for arrays of durations.
+fn capture<T, B>(value: &T) -> Result<B>
+where
+    T: ?Sized + Serialize,
+    B: TryFrom<u64>,
+{
+    let captured = value.serialize(IntegerCapture)?;
+
+    B::try_from(captured)
+        .map_err(|_| Error::Message(format!("`Duration` field out of range: {captured}")))
+}
+
+// A serializer that only accepts unsigned integers, and returns their value.
+struct IntegerCapture;
+
+macro_rules! capture_unsigned {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<u64> {
+                Ok(v.into())
+            }
+        )*
+    };
+}
+
+macro_rules! reject {
+    ($($method:ident($($arg:ty),*) -> $ok:ty),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ok> {
+                Err(not_an_integer())
+            }
+        )*
+    };
+}
+
+fn not_an_integer() -> Error {
+    Error::Message("expected an unsigned integer field in a `Duration`".into())
+}
+
+impl Serializer for IntegerCapture {
+    type Ok = u64;
+    type Error = Error;
+    type SerializeSeq = Impossible<u64, Error>;
+    type SerializeTuple = Impossible<u64, Error>;
+    type SerializeTupleStruct = Impossible<u64, Error>;
+    type SerializeTupleVariant = Impossible<u64, Error>;
+    type SerializeMap = Impossible<u64, Error>;
+    type SerializeStruct = Impossible<u64, Error>;
+    type SerializeStructVariant = Impossible<u64, Error>;
+
+    capture_unsigned! {
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+    }
+
+    reject! {
+        serialize_bool(bool) -> u64,
+        serialize_i8(i8) -> u64,
+        serialize_i16(i16) -> u64,
+        serialize_i32(i32) -> u64,
+        serialize_i64(i64) -> u64,
+        serialize_f32(f32) -> u64,
+        serialize_f64(f64) -> u64,
+        serialize_char(char) -> u64,
+        serialize_str(&str) -> u64,
+        serialize_bytes(&[u8]) -> u64,
+        serialize_none() -> u64,
+        serialize_unit() -> u64,
+        serialize_unit_struct(&'static str) -> u64,
+        serialize_unit_variant(&'static str, u32, &'static str) -> u64,
+        serialize_seq(Option<usize>) -> Self::SerializeSeq,
+        serialize_tuple(usize) -> Self::SerializeTuple,
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct,
+        serialize_tuple_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeTupleVariant,
+        serialize_map(Option<usize>) -> Self::SerializeMap,
+        serialize_struct(&'static str, usize) -> Self::SerializeStruct,
+        serialize_struct_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeStructVariant,
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<u64>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(not_an_integer())
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<u64>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<u64>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(not_an_integer())
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
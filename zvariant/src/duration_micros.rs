@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Signature, Type};
+
+/// A [`Duration`], encoded as a `t` count of microseconds.
+///
+/// [`Duration`] itself is (de)serialized by serde as a struct of seconds and nanoseconds, which we
+/// encode as a `(tu)` structure. Many D-Bus APIs (e.g systemd's `*USec` properties) use a count of
+/// microseconds instead, as a `t` (`u64`), and this type can be used for such APIs. Being a plain
+/// integer, it's also more compact than a [`Duration`] in arrays: `Vec<DurationMicros>` is an `at`
+/// array, and a fixed-size array in the GVariant format.
+///
+/// **Note:** Precision below the microsecond is dropped on serialization, e.g 1.5 µs is encoded as
+/// 1 µs. Serialization fails if the duration is too long to fit in a `u64` of microseconds.
+///
+/// The conversion from and to microseconds is done by our (de)serializers, which encode any
+/// [`Duration`] as a `t` when the signature asks for it. Other serde formats see a [`Duration`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use zvariant::{serialized::Context, to_bytes, DurationMicros, Type, LE};
+///
+/// assert_eq!(DurationMicros::signature(), "t");
+///
+/// let duration = Duration::from_millis(1_500);
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let encoded = to_bytes(ctxt, &DurationMicros::from(duration)).unwrap();
+/// assert_eq!(encoded.bytes(), 1_500_000u64.to_le_bytes());
+/// let decoded: DurationMicros = encoded.deserialize().unwrap().0;
+/// assert_eq!(decoded.as_duration(), duration);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DurationMicros(Duration);
+
+impl DurationMicros {
+    /// Create a new `DurationMicros` from a [`Duration`].
+    pub fn new(duration: Duration) -> Self {
+        Self(duration)
+    }
+
+    /// The duration.
+    pub fn as_duration(&self) -> Duration {
+        self.0
+    }
+}
+
+impl Type for DurationMicros {
+    fn signature() -> Signature<'static> {
+        u64::signature()
+    }
+}
+
+impl From<Duration> for DurationMicros {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl From<DurationMicros> for Duration {
+    fn from(value: DurationMicros) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for DurationMicros {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DurationMicros {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Duration::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{serialized::Context, to_bytes, DurationMicros, Error, Type, BE, LE};
+
+    #[test]
+    fn duration_micros() {
+        assert_eq!(DurationMicros::signature(), "t");
+        assert_eq!(<(u8, Vec<DurationMicros>)>::signature(), "(yat)");
+
+        let duration = Duration::new(42, 123_456_789);
+        let truncated = Duration::new(42, 123_456_000);
+        for ctxt in [
+            Context::new_dbus(LE, 0),
+            Context::new_dbus(BE, 0),
+            #[cfg(feature = "gvariant")]
+            Context::new_gvariant(LE, 0),
+        ] {
+            let encoded = to_bytes(ctxt, &DurationMicros::new(duration)).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &42_123_456u64).unwrap().bytes()
+            );
+            let decoded: DurationMicros = encoded.deserialize().unwrap().0;
+            // Nanoseconds are dropped.
+            assert_eq!(decoded.as_duration(), truncated);
+
+            // Arrays of durations are plain arrays of integers, including nested ones.
+            let durations: Vec<_> = (0..1_000)
+                .map(|micros| DurationMicros::new(Duration::from_micros(micros)))
+                .collect();
+            let encoded = to_bytes(ctxt, &(7u8, &durations)).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &(7u8, (0..1_000).collect::<Vec<u64>>()))
+                    .unwrap()
+                    .bytes()
+            );
+            let decoded: (u8, Vec<DurationMicros>) = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, (7, durations));
+        }
+
+        // The largest encodable duration round trips.
+        let ctxt = Context::new_dbus(LE, 0);
+        let max = Duration::from_micros(u64::MAX);
+        let encoded = to_bytes(ctxt, &DurationMicros::from(max)).unwrap();
+        assert_eq!(encoded.bytes(), u64::MAX.to_le_bytes());
+        let decoded: DurationMicros = encoded.deserialize().unwrap().0;
+        assert_eq!(Duration::from(decoded), max);
+
+        // Too long to be encoded in microseconds.
+        assert!(matches!(
+            to_bytes(ctxt, &DurationMicros::new(Duration::MAX)),
+            Err(Error::Message(_)),
+        ));
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Signature, Type};
+
+/// A double that must be finite on the wire, i-e neither NaN nor infinite.
+///
+/// D-Bus doubles can carry NaN and infinite values, and [`f64`] (de)serializes them as is. Some
+/// peers can't handle those though, so this wrapper can be used instead, to fail serialization and
+/// deserialization of non-finite values.
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::{serialized::Context, to_bytes, FiniteF64, Type, LE};
+///
+/// assert_eq!(FiniteF64::signature(), "d");
+///
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let encoded = to_bytes(ctxt, &FiniteF64(1.5)).unwrap();
+/// let decoded: FiniteF64 = encoded.deserialize().unwrap().0;
+/// assert_eq!(decoded, FiniteF64(1.5));
+///
+/// assert!(to_bytes(ctxt, &FiniteF64(f64::NAN)).is_err());
+/// let encoded = to_bytes(ctxt, &f64::INFINITY).unwrap();
+/// assert!(encoded.deserialize::<FiniteF64>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct FiniteF64(pub f64);
+
+impl FiniteF64 {
+    /// Whether the inner value is finite, i-e whether it can be (de)serialized.
+    pub fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+}
+
+impl Type for FiniteF64 {
+    fn signature() -> Signature<'static> {
+        f64::signature()
+    }
+}
+
+impl From<f64> for FiniteF64 {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<FiniteF64> for f64 {
+    fn from(value: FiniteF64) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for FiniteF64 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !self.is_finite() {
+            return Err(ser::Error::custom(format!(
+                "can't serialize non-finite double `{}`",
+                self.0
+            )));
+        }
+
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FiniteF64 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = f64::deserialize(deserializer)?;
+        if !value.is_finite() {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Float(value),
+                &"a finite double",
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{serialized::Context, to_bytes, Error, FiniteF64, LE};
+
+    #[test]
+    fn finite_f64() {
+        let ctxt = Context::new_dbus(LE, 0);
+
+        let encoded = to_bytes(ctxt, &FiniteF64(-273.15)).unwrap();
+        assert_eq!(encoded.bytes(), (-273.15f64).to_le_bytes());
+        let decoded: FiniteF64 = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, FiniteF64(-273.15));
+
+        for non_finite in [f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                to_bytes(ctxt, &FiniteF64(non_finite)),
+                Err(Error::Message(_))
+            ));
+
+            // Plain doubles still carry non-finite values, which are rejected on deserialization.
+            let encoded = to_bytes(ctxt, &non_finite).unwrap();
+            let decoded: f64 = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded.is_nan(), non_finite.is_nan());
+            assert!(matches!(
+                encoded.deserialize::<FiniteF64>(),
+                Err(Error::Message(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Signature, Type};
+
+/// A fixed-size array, encoded as a D-Bus array.
+///
+/// Serde (de)serializes Rust arrays as tuples, which is why `[T; N]` is encoded as a structure of
+/// `N` fields, e.g `(uuu)` for `[u32; 3]`. This wrapper encodes it as an array of `N` elements
+/// instead, i-e `au` for `[u32; 3]`, with any element type. Deserialization fails if the number
+/// of elements on the wire isn't exactly `N`.
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::{serialized::Context, to_bytes, FixedArray, Type, LE};
+///
+/// let array = FixedArray::from([(1u32, "one".to_string()), (2, "two".to_string())]);
+/// assert_eq!(<FixedArray<(u32, String), 2>>::signature(), "a(us)");
+///
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let encoded = to_bytes(ctxt, &array).unwrap();
+/// let decoded: FixedArray<(u32, String), 2> = encoded.deserialize().unwrap().0;
+/// assert_eq!(decoded, array);
+///
+/// // The wrong number of elements.
+/// assert!(encoded.deserialize::<FixedArray<(u32, String), 3>>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedArray<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> FixedArray<T, N> {
+    /// Get the inner array.
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for FixedArray<T, N> {
+    fn from(array: [T; N]) -> Self {
+        Self(array)
+    }
+}
+
+impl<T, const N: usize> From<FixedArray<T, N>> for [T; N] {
+    fn from(array: FixedArray<T, N>) -> Self {
+        array.0
+    }
+}
+
+impl<T, const N: usize> Deref for FixedArray<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for FixedArray<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> Type for FixedArray<T, N>
+where
+    T: Type,
+{
+    fn signature() -> Signature<'static> {
+        <[T]>::signature()
+    }
+}
+
+impl<T, const N: usize> Serialize for FixedArray<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(N))?;
+        for element in &self.0 {
+            seq.serialize_element(element)?;
+        }
+
+        seq.end()
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for FixedArray<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(FixedArrayVisitor(PhantomData))
+    }
+}
+
+struct FixedArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T, const N: usize> Visitor<'de> for FixedArrayVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = FixedArray<T, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "an array of {N} elements")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Collected in a `Vec` first, so elements don't need to be `Copy` or `Default` and no
+        // partially initialized array ever exists.
+        let mut elements = Vec::with_capacity(N);
+        while elements.len() < N {
+            match seq.next_element()? {
+                Some(element) => elements.push(element),
+                None => return Err(de::Error::invalid_length(elements.len(), &self)),
+            }
+        }
+        // The elements are typed by the signature, so any extra one is read as a `T` too.
+        if seq.next_element::<T>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+
+        match elements.try_into() {
+            Ok(array) => Ok(FixedArray(array)),
+            Err(_) => unreachable!("exactly {N} elements were collected"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{serialized::Context, to_bytes, Error, FixedArray, Type, LE};
+
+    #[test]
+    fn fixed_array() {
+        let array = FixedArray([
+            (1u32, "one".to_string()),
+            (2, "two".to_string()),
+            (3, "three".to_string()),
+        ]);
+        assert_eq!(<FixedArray<(u32, String), 3>>::signature(), "a(us)");
+
+        let check = |ctxt: Context| {
+            let encoded = to_bytes(ctxt, &array).unwrap();
+            // Same encoding as a `Vec`.
+            let vec: Vec<(u32, String)> = encoded.deserialize().unwrap().0;
+            assert_eq!(vec, array.to_vec());
+
+            let (decoded, parsed): (FixedArray<(u32, String), 3>, _) =
+                encoded.deserialize().unwrap();
+            assert_eq!(parsed, encoded.len());
+            assert_eq!(decoded, array);
+
+            // Too few and too many elements.
+            assert!(matches!(
+                encoded.deserialize::<FixedArray<(u32, String), 4>>(),
+                Err(Error::Message(_))
+            ));
+            assert!(matches!(
+                encoded.deserialize::<FixedArray<(u32, String), 2>>(),
+                Err(Error::Message(_))
+            ));
+        };
+        check(Context::new_dbus(LE, 0));
+        #[cfg(feature = "gvariant")]
+        check(Context::new_gvariant(LE, 0));
+    }
+}
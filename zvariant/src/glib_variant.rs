@@ -0,0 +1,123 @@
+//! Conversions between [`glib::Variant`] and [`Value`].
+//!
+//! Since neither serde traits nor [`Type`](crate::Type) can be implemented for a foreign type, a
+//! [`glib::Variant`] is converted to and from a [`Value`] instead, which can then be used anywhere a
+//! [`Value`] is accepted, e.g. as an argument of a D-Bus method call. The conversion goes through the
+//! GVariant wire format, which is the format GLib keeps its variants in.
+
+use glib::{Variant, VariantTy};
+
+use crate::{
+    serialized::{Context, Data},
+    to_bytes, Error, OwnedValue, Result, Value, NATIVE_ENDIAN,
+};
+
+// GLib keeps variants in the GVariant format, in the native byte order.
+fn context() -> Context {
+    Context::new_gvariant(NATIVE_ENDIAN, 0)
+}
+
+impl TryFrom<&Variant> for OwnedValue {
+    type Error = Error;
+
+    fn try_from(variant: &Variant) -> Result<Self> {
+        // Box it in a `v`, so its type is encoded along with it.
+        let boxed = Variant::from_variant(variant);
+        let data = Data::new(boxed.data(), context());
+        let (value, _): (Value<'_>, _) = data.deserialize()?;
+
+        value.try_to_owned()
+    }
+}
+
+impl TryFrom<Variant> for OwnedValue {
+    type Error = Error;
+
+    fn try_from(variant: Variant) -> Result<Self> {
+        Self::try_from(&variant)
+    }
+}
+
+impl TryFrom<&Value<'_>> for Variant {
+    type Error = Error;
+
+    fn try_from(value: &Value<'_>) -> Result<Self> {
+        // A `Value` is encoded as a `v`, from which GLib can get the type of the contained value.
+        let encoded = to_bytes(context(), value)?;
+        let boxed = Variant::from_data_with_type(encoded.bytes().to_vec(), VariantTy::VARIANT);
+
+        boxed
+            .as_variant()
+            .ok_or_else(|| Error::Message("invalid GVariant encoding of a `Value`".into()))
+    }
+}
+
+impl TryFrom<Value<'_>> for Variant {
+    type Error = Error;
+
+    fn try_from(value: Value<'_>) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<OwnedValue> for Variant {
+    type Error = Error;
+
+    fn try_from(value: OwnedValue) -> Result<Self> {
+        Self::try_from(&*value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use glib::{ToVariant, Variant};
+
+    use crate::{OwnedValue, Str, StructureBuilder, Value};
+
+    fn round_trip(variant: Variant) -> OwnedValue {
+        let value = OwnedValue::try_from(&variant).unwrap();
+        assert_eq!(value.value_signature(), variant.type_().as_str());
+        assert_eq!(Variant::try_from(&*value).unwrap(), variant);
+
+        value
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(*round_trip(42u32.to_variant()), Value::U32(42));
+        assert_eq!(
+            *round_trip("hello".to_variant()),
+            Value::Str(Str::from("hello"))
+        );
+    }
+
+    #[test]
+    fn structure() {
+        let value = round_trip(("hello", 42i32).to_variant());
+        let expected = StructureBuilder::new()
+            .add_field("hello")
+            .add_field(42i32)
+            .build();
+        assert_eq!(*value, Value::from(expected));
+    }
+
+    #[test]
+    fn containers() {
+        round_trip(vec!["a", "b", "c"].to_variant());
+        round_trip(Some(7i64).to_variant());
+        round_trip(None::<i64>.to_variant());
+
+        let mut dict = HashMap::new();
+        dict.insert("answer".to_string(), 42u8.to_variant());
+        dict.insert("question".to_string(), "unknown".to_variant());
+        round_trip(dict.to_variant());
+    }
+
+    #[test]
+    fn from_value() {
+        let variant = Variant::try_from(Value::from(vec![1u16, 2, 3])).unwrap();
+        assert_eq!(variant.get::<Vec<u16>>().unwrap(), vec![1, 2, 3]);
+    }
+}
@@ -7,7 +7,9 @@ use std::{ffi::CStr, marker::PhantomData, str};
 use std::os::fd::AsFd;
 
 use crate::{
+    bound::{BoundVisitor, BOUND_ENUM_NAME, BOUND_SIGNATURE_STR},
     de::{DeserializerCommon, ValueParseStage},
+    duration::{visit_micros, DURATION_STRUCT_NAME},
     framing_offset_size::FramingOffsetSize,
     framing_offsets::FramingOffsets,
     serialized::{Context, Format},
@@ -16,6 +18,9 @@ use crate::{
     Basic, Error, Result, Signature,
 };
 
+#[cfg(unix)]
+use crate::os_str::{OsStringAccess, OS_STRING_ENUM_NAME};
+
 /// Our GVariant deserialization implementation.
 #[derive(Debug)]
 pub struct Deserializer<'de, 'sig, 'f, F>(pub(crate) DeserializerCommon<'de, 'sig, 'f, F>);
@@ -110,7 +115,25 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
         crate::de::deserialize_any::<Self, V>(self, c, visitor)
     }
 
-    deserialize_basic!(deserialize_bool);
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        // Unlike D-Bus, GVariant encodes booleans as a single byte.
+        let v = self.0.next_const_size_slice::<bool>()?[0];
+        let b = match v {
+            1 => true,
+            0 => false,
+            _ => {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Unsigned(v as u64),
+                    &"0 or 1",
+                ))
+            }
+        };
+
+        visitor.visit_bool(b)
+    }
     deserialize_basic!(deserialize_i8);
     deserialize_basic!(deserialize_i16);
     deserialize_basic!(deserialize_i32);
@@ -127,6 +150,10 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
     where
         V: Visitor<'de>,
     {
+        if self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
+            return self.deserialize_str(visitor);
+        }
+
         let bytes = deserialize_ay(self)?;
         visitor.visit_byte_buf(bytes.into())
     }
@@ -135,6 +162,10 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
     where
         V: Visitor<'de>,
     {
+        if self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
+            return self.deserialize_str(visitor);
+        }
+
         let bytes = deserialize_ay(self)?;
         visitor.visit_borrowed_bytes(bytes)
     }
@@ -143,7 +174,6 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
     deserialize_as!(deserialize_string => deserialize_str);
     deserialize_as!(deserialize_tuple(_l: usize) => deserialize_struct("", &[]));
     deserialize_as!(deserialize_tuple_struct(n: &'static str, _l: usize) => deserialize_struct(n, &[]));
-    deserialize_as!(deserialize_struct(_n: &'static str, _f: &'static [&'static str]) => deserialize_seq());
     deserialize_as!(deserialize_map => deserialize_seq);
     deserialize_as!(deserialize_ignored_any => deserialize_any);
 
@@ -310,6 +340,7 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
             STRUCT_SIG_START_CHAR => {
                 let signature = self.0.sig_parser.next_signature()?;
                 let alignment = alignment_for_signature(&signature, self.0.ctxt.format())?;
+                let fixed_sized = crate::utils::is_fixed_sized_signature(&signature)?;
                 self.0.parse_padding(alignment)?;
 
                 self.0.sig_parser.skip_char()?;
@@ -326,6 +357,10 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
                     offset_size,
                 });
                 self.0.container_depths = self.0.container_depths.dec_structure();
+                if fixed_sized && v.is_ok() {
+                    // The size of a fixed-sized structure is a multiple of its alignment.
+                    self.0.parse_padding(alignment)?;
+                }
 
                 v
             }
@@ -363,6 +398,16 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
         V: Visitor<'de>,
     {
         let signature = self.0.sig_parser.next_signature()?;
+        if name == BOUND_ENUM_NAME && signature == BOUND_SIGNATURE_STR {
+            return self.deserialize_seq(BoundVisitor(visitor));
+        }
+        #[cfg(unix)]
+        if name == OS_STRING_ENUM_NAME && signature == <&str>::SIGNATURE_STR {
+            let s: String = serde::Deserialize::deserialize(&mut *self)?;
+
+            return visitor.visit_enum(OsStringAccess(s));
+        }
+
         let alignment = alignment_for_signature(&signature, self.0.ctxt.format())?;
         self.0.parse_padding(alignment)?;
 
@@ -389,6 +434,24 @@ impl<'de, 'd, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F> de::Deseriali
         Ok(v)
     }
 
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        if name == DURATION_STRUCT_NAME && self.0.sig_parser.next_char()? == u64::SIGNATURE_CHAR {
+            let micros: u64 = serde::Deserialize::deserialize(&mut *self)?;
+
+            return visit_micros(micros, visitor);
+        }
+
+        self.deserialize_seq(visitor)
+    }
+
     fn is_human_readable(&self) -> bool {
         false
     }
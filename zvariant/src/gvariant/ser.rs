@@ -6,7 +6,9 @@ use std::{
 };
 
 use crate::{
+    bound::{BoundFields, BOUND_ENUM_NAME, BOUND_SIGNATURE_STR},
     container_depths::ContainerDepths,
+    duration::{DurationFields, DURATION_STRUCT_NAME},
     framing_offset_size::FramingOffsetSize,
     framing_offsets::FramingOffsets,
     serialized::{Context, Format},
@@ -15,6 +17,9 @@ use crate::{
     Basic, Error, Result, Signature,
 };
 
+#[cfg(unix)]
+use crate::os_str::{os_str_to_string, OS_STRING_ENUM_NAME};
+
 /// Our serialization implementation.
 pub(crate) struct Serializer<'ser, 'sig, W>(pub(crate) crate::SerializerCommon<'ser, 'sig, W>);
 
@@ -131,7 +136,14 @@ where
     type SerializeStruct = StructSeqSerializer<'ser, 'sig, 'b, W>;
     type SerializeStructVariant = StructSeqSerializer<'ser, 'sig, 'b, W>;
 
-    serialize_basic!(serialize_bool, bool);
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.0.prep_serialize_basic::<bool>()?;
+        // Unlike D-Bus, GVariant encodes booleans as a single byte.
+        self.0
+            .write_all(&[u8::from(v)])
+            .map_err(|e| Error::InputOutput(e.into()))
+    }
+
     serialize_basic!(serialize_i16, i16);
     serialize_basic!(serialize_i32, i32);
     serialize_basic!(serialize_i64, i64);
@@ -188,6 +200,11 @@ where
     }
 
     fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        // Types like `CString` serialize as bytes but are encoded as strings.
+        if self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
+            return self.serialize_str(str::from_utf8(v).map_err(Error::Utf8)?);
+        }
+
         let seq = self.serialize_seq(Some(v.len()))?;
         seq.ser
             .0
@@ -234,10 +251,14 @@ where
 
     fn serialize_unit_variant(
         self,
-        _name: &'static str,
+        name: &'static str,
         variant_index: u32,
         variant: &'static str,
     ) -> Result<()> {
+        if name == BOUND_ENUM_NAME && self.0.sig_parser.next_signature()? == BOUND_SIGNATURE_STR {
+            return BoundFields::unbounded().serialize(self);
+        }
+
         if self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
             variant.serialize(self)
         } else {
@@ -256,7 +277,7 @@ where
 
     fn serialize_newtype_variant<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         variant_index: u32,
         _variant: &'static str,
         value: &T,
@@ -264,6 +285,14 @@ where
     where
         T: ?Sized + Serialize,
     {
+        if name == BOUND_ENUM_NAME && self.0.sig_parser.next_signature()? == BOUND_SIGNATURE_STR {
+            return BoundFields::new(variant_index, value).serialize(self);
+        }
+        #[cfg(unix)]
+        if name == OS_STRING_ENUM_NAME && self.0.sig_parser.next_char()? == <&str>::SIGNATURE_CHAR {
+            return os_str_to_string(variant_index, value)?.serialize(self);
+        }
+
         self.0.prep_serialize_enum_variant(variant_index)?;
 
         value.serialize(self)
@@ -327,7 +356,14 @@ where
         self.serialize_seq(len)
     }
 
-    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+        if name == DURATION_STRUCT_NAME && self.0.sig_parser.next_char()? == u64::SIGNATURE_CHAR {
+            return Ok(StructSeqSerializer::Duration(DurationSerializer {
+                ser: self,
+                fields: DurationFields::default(),
+            }));
+        }
+
         if len == 0 {
             return StructSerializer::unit(self).map(StructSeqSerializer::Struct);
         }
@@ -440,6 +476,8 @@ pub struct StructSerializer<'ser, 'sig, 'b, W> {
     end_parens: u8,
     // All offsets
     offsets: Option<FramingOffsets>,
+    // Alignment of a fixed-sized structure, whose size must be padded to a multiple of it.
+    fixed_sized_alignment: Option<usize>,
     // The original container depths. We restore to that at the end.
     container_depths: ContainerDepths,
 }
@@ -463,6 +501,7 @@ where
             ser,
             end_parens: 0,
             offsets,
+            fixed_sized_alignment: None,
             start,
             container_depths,
         })
@@ -481,6 +520,9 @@ where
 
         let signature = ser.0.sig_parser.next_signature()?;
         let alignment = alignment_for_signature(&signature, Format::GVariant)?;
+        let fixed_sized_alignment = (c == STRUCT_SIG_START_CHAR
+            && crate::utils::is_fixed_sized_signature(&signature)?)
+        .then_some(alignment);
         ser.0.add_padding(alignment)?;
 
         ser.0.sig_parser.skip_char()?;
@@ -498,6 +540,7 @@ where
             ser,
             end_parens: 1,
             offsets,
+            fixed_sized_alignment,
             start,
             container_depths,
         })
@@ -513,6 +556,7 @@ where
             ser,
             end_parens: 0,
             offsets: None,
+            fixed_sized_alignment: None,
             start,
             container_depths,
         })
@@ -591,6 +635,11 @@ where
         // Restore the original container depths.
         self.ser.0.container_depths = self.container_depths;
 
+        if let Some(alignment) = self.fixed_sized_alignment {
+            // The size of a fixed-sized structure is a multiple of its alignment.
+            self.ser.0.add_padding(alignment)?;
+        }
+
         let mut offsets = match self.offsets {
             Some(offsets) => offsets,
             None => return Ok(()),
@@ -616,6 +665,14 @@ where
 pub enum StructSeqSerializer<'ser, 'sig, 'b, W> {
     Struct(StructSerializer<'ser, 'sig, 'b, W>),
     Seq(SeqSerializer<'ser, 'sig, 'b, W>),
+    Duration(DurationSerializer<'ser, 'sig, 'b, W>),
+}
+
+#[doc(hidden)]
+/// Serializes a `Duration` as a count of microseconds.
+pub struct DurationSerializer<'ser, 'sig, 'b, W> {
+    ser: &'b mut Serializer<'ser, 'sig, W>,
+    fields: DurationFields,
 }
 
 macro_rules! serialize_struct_anon_fields {
@@ -653,6 +710,7 @@ macro_rules! serialize_struct_anon_fields {
                 match self {
                     StructSeqSerializer::Struct(ser) => ser.$method(value),
                     StructSeqSerializer::Seq(ser) => ser.serialize_element(value),
+                    StructSeqSerializer::Duration(ser) => ser.fields.add_field("", value),
                 }
             }
 
@@ -660,6 +718,7 @@ macro_rules! serialize_struct_anon_fields {
                 match self {
                     StructSeqSerializer::Struct(ser) => ser.end_struct(),
                     StructSeqSerializer::Seq(ser) => ser.end_seq(),
+                    StructSeqSerializer::Duration(ser) => ser.fields.micros()?.serialize(ser.ser),
                 }
             }
         }
@@ -775,6 +834,7 @@ macro_rules! serialize_struct_named_fields {
                 match self {
                     StructSeqSerializer::Struct(ser) => ser.serialize_field(key, value),
                     StructSeqSerializer::Seq(ser) => ser.serialize_element(value),
+                    StructSeqSerializer::Duration(ser) => ser.fields.add_field(key, value),
                 }
             }
 
@@ -782,6 +842,7 @@ macro_rules! serialize_struct_named_fields {
                 match self {
                     StructSeqSerializer::Struct(ser) => ser.end_struct(),
                     StructSeqSerializer::Seq(ser) => ser.end_seq(),
+                    StructSeqSerializer::Duration(ser) => ser.fields.micros()?.serialize(ser.ser),
                 }
             }
         }
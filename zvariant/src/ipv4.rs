@@ -0,0 +1,266 @@
+use std::net::Ipv4Addr;
+
+use serde::{
+    de::{Deserializer, Error as _},
+    ser::Serializer,
+    Deserialize, Serialize,
+};
+
+use crate::{Signature, Type};
+
+/// An IPv4 address, encoded as a `u` (`u32`) in network byte order.
+///
+/// This is the encoding NetworkManager uses for IPv4 addresses. The octets of the address are
+/// stored in memory order, i.e the `u32` is [`u32::from_ne_bytes`] of [`Ipv4Addr::octets`]. Hence,
+/// the numeric value of the `u32` depends on the endianness of the host: `127.0.0.1` is
+/// `0x0100007f` on little-endian hosts and `0x7f000001` on big-endian ones. In a message encoded in
+/// the native endianness, the octets appear on the wire in their usual order.
+///
+/// Use [`Ipv4AsBytes`] for APIs that encode IPv4 addresses as byte arrays instead.
+///
+/// # Arrays
+///
+/// Since `u32` is a fixed-size type, a `Vec<Ipv4AsU32>` is encoded as a plain `au` array of 4
+/// bytes per address, e.g for the address lists of NetworkManager, and likewise for structures of
+/// fixed-size fields like routes. In the GVariant format, such arrays don't have any framing
+/// offsets. In comparison, a `Vec<Ipv4AsBytes>` (and a `Vec<Ipv4Addr>`) is an `aay` array of
+/// arrays, each with its own length.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use zvariant::{serialized::Context, to_bytes, Ipv4AsU32, Type, NATIVE_ENDIAN};
+///
+/// assert_eq!(Ipv4AsU32::signature(), "u");
+///
+/// let addr = Ipv4Addr::new(192, 168, 1, 1);
+/// let ctxt = Context::new_dbus(NATIVE_ENDIAN, 0);
+/// let encoded = to_bytes(ctxt, &Ipv4AsU32::from(addr)).unwrap();
+/// assert_eq!(encoded.bytes(), [192, 168, 1, 1]);
+/// let decoded: Ipv4AsU32 = encoded.deserialize().unwrap().0;
+/// assert_eq!(Ipv4Addr::from(decoded), addr);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ipv4AsU32(pub Ipv4Addr);
+
+impl Ipv4AsU32 {
+    /// The address, as the `u32` it's encoded as.
+    pub fn to_u32(&self) -> u32 {
+        u32::from_ne_bytes(self.0.octets())
+    }
+}
+
+impl Type for Ipv4AsU32 {
+    fn signature() -> Signature<'static> {
+        u32::signature()
+    }
+}
+
+impl Serialize for Ipv4AsU32 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_u32().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ipv4AsU32 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u32::deserialize(deserializer).map(|addr| Self(Ipv4Addr::from(addr.to_ne_bytes())))
+    }
+}
+
+impl From<Ipv4Addr> for Ipv4AsU32 {
+    fn from(addr: Ipv4Addr) -> Self {
+        Self(addr)
+    }
+}
+
+impl From<Ipv4AsU32> for Ipv4Addr {
+    fn from(value: Ipv4AsU32) -> Self {
+        value.0
+    }
+}
+
+/// An IPv4 address, encoded as an `ay` array of 4 bytes.
+///
+/// The bytes are the octets of the address in network byte order, i.e in the usual order, with the
+/// most significant octet first: `127.0.0.1` is encoded as `[127, 0, 0, 1]`, regardless of the
+/// endianness of the host or of the message. Deserialization fails if the array doesn't have
+/// exactly 4 bytes.
+///
+/// Use [`Ipv4AsU32`] for APIs that encode IPv4 addresses as integers instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use zvariant::{serialized::Context, to_bytes, Ipv4AsBytes, Type, BE, LE};
+///
+/// assert_eq!(Ipv4AsBytes::signature(), "ay");
+///
+/// let addr = Ipv4Addr::new(192, 168, 1, 1);
+/// for ctxt in [Context::new_dbus(LE, 0), Context::new_dbus(BE, 0)] {
+///     let encoded = to_bytes(ctxt, &Ipv4AsBytes::from(addr)).unwrap();
+///     // The array length, followed by the octets.
+///     assert_eq!(encoded.bytes()[4..], [192, 168, 1, 1]);
+///     let decoded: Ipv4AsBytes = encoded.deserialize().unwrap().0;
+///     assert_eq!(Ipv4Addr::from(decoded), addr);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ipv4AsBytes(pub Ipv4Addr);
+
+impl Type for Ipv4AsBytes {
+    fn signature() -> Signature<'static> {
+        <&[u8]>::signature()
+    }
+}
+
+impl Serialize for Ipv4AsBytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.octets().as_slice().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ipv4AsBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        let octets = <[u8; 4]>::try_from(bytes.as_slice())
+            .map_err(|_| D::Error::invalid_length(bytes.len(), &"an array of 4 bytes"))?;
+
+        Ok(Self(Ipv4Addr::from(octets)))
+    }
+}
+
+impl From<Ipv4Addr> for Ipv4AsBytes {
+    fn from(addr: Ipv4Addr) -> Self {
+        Self(addr)
+    }
+}
+
+impl From<Ipv4AsBytes> for Ipv4Addr {
+    fn from(value: Ipv4AsBytes) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use crate::{
+        serialized::Context, to_bytes, Ipv4AsBytes, Ipv4AsU32, Type, BE, LE, NATIVE_ENDIAN,
+    };
+
+    #[test]
+    fn ipv4_as_u32() {
+        assert_eq!(Ipv4AsU32::signature(), "u");
+
+        for addr in [
+            Ipv4Addr::UNSPECIFIED,
+            Ipv4Addr::LOCALHOST,
+            Ipv4Addr::new(10, 20, 30, 40),
+            Ipv4Addr::BROADCAST,
+        ] {
+            // In the native endianness, the octets are in order on the wire.
+            let ctxt = Context::new_dbus(NATIVE_ENDIAN, 0);
+            let encoded = to_bytes(ctxt, &Ipv4AsU32(addr)).unwrap();
+            assert_eq!(encoded.bytes(), addr.octets());
+            let decoded: Ipv4AsU32 = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded.0, addr);
+
+            // In any endianness, it's the same `u32`.
+            for ctxt in [Context::new_dbus(LE, 0), Context::new_dbus(BE, 0)] {
+                let encoded = to_bytes(ctxt, &Ipv4AsU32(addr)).unwrap();
+                let decoded: u32 = encoded.deserialize().unwrap().0;
+                assert_eq!(decoded, u32::from_ne_bytes(addr.octets()));
+                let decoded: Ipv4AsU32 = encoded.deserialize().unwrap().0;
+                assert_eq!(Ipv4Addr::from(decoded), addr);
+            }
+        }
+
+        #[cfg(target_endian = "little")]
+        assert_eq!(Ipv4AsU32(Ipv4Addr::LOCALHOST).to_u32(), 0x0100_007f);
+        #[cfg(target_endian = "big")]
+        assert_eq!(Ipv4AsU32(Ipv4Addr::LOCALHOST).to_u32(), 0x7f00_0001);
+    }
+
+    #[test]
+    fn ipv4_as_u32_array() {
+        assert_eq!(Vec::<Ipv4AsU32>::signature(), "au");
+        assert_eq!(Vec::<Ipv4AsBytes>::signature(), "aay");
+
+        let addrs: Vec<_> = (0..1000u32)
+            .map(|i| Ipv4AsU32(Ipv4Addr::from(0x0a00_0000 + i)))
+            .collect();
+
+        // The length of the array, followed by 4 bytes per address.
+        let ctxt = Context::new_dbus(NATIVE_ENDIAN, 0);
+        let encoded = to_bytes(ctxt, &addrs).unwrap();
+        assert_eq!(encoded.len(), 4 + 4 * addrs.len());
+        assert_eq!(&encoded.bytes()[4..8], [10, 0, 0, 0]);
+        assert_eq!(&encoded.bytes()[4 * 1000..], [10, 0, 3, 231]);
+        let decoded: Vec<Ipv4AsU32> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, addrs);
+
+        // No framing offsets in GVariant.
+        #[cfg(feature = "gvariant")]
+        {
+            let ctxt = Context::new_gvariant(NATIVE_ENDIAN, 0);
+            let encoded = to_bytes(ctxt, &addrs).unwrap();
+            assert_eq!(encoded.len(), 4 * addrs.len());
+            let decoded: Vec<Ipv4AsU32> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, addrs);
+        }
+    }
+
+    #[test]
+    fn ipv4_as_bytes() {
+        assert_eq!(Ipv4AsBytes::signature(), "ay");
+
+        for addr in [
+            Ipv4Addr::UNSPECIFIED,
+            Ipv4Addr::LOCALHOST,
+            Ipv4Addr::new(10, 20, 30, 40),
+            Ipv4Addr::BROADCAST,
+        ] {
+            for ctxt in [Context::new_dbus(LE, 0), Context::new_dbus(BE, 0)] {
+                let encoded = to_bytes(ctxt, &Ipv4AsBytes(addr)).unwrap();
+                assert_eq!(
+                    encoded.bytes(),
+                    to_bytes(ctxt, &addr.octets().to_vec()).unwrap().bytes()
+                );
+                let decoded: Ipv4AsBytes = encoded.deserialize().unwrap().0;
+                assert_eq!(Ipv4Addr::from(decoded), addr);
+            }
+
+            #[cfg(feature = "gvariant")]
+            {
+                let ctxt = Context::new_gvariant(LE, 0);
+                let encoded = to_bytes(ctxt, &Ipv4AsBytes(addr)).unwrap();
+                assert_eq!(encoded.bytes(), addr.octets());
+                let decoded: Ipv4AsBytes = encoded.deserialize().unwrap().0;
+                assert_eq!(decoded.0, addr);
+            }
+        }
+
+        // Only arrays of exactly 4 bytes are addresses.
+        let ctxt = Context::new_dbus(LE, 0);
+        for bytes in [vec![], vec![127u8, 0, 1], vec![127u8, 0, 0, 0, 1]] {
+            let encoded = to_bytes(ctxt, &bytes).unwrap();
+            encoded.deserialize::<Ipv4AsBytes>().unwrap_err();
+        }
+    }
+}
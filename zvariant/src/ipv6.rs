@@ -0,0 +1,151 @@
+use std::net::{Ipv6Addr, SocketAddrV6};
+
+use serde::{
+    de::{Deserializer, Error as _},
+    ser::{SerializeTuple, Serializer},
+    Deserialize, Serialize,
+};
+
+use crate::{Signature, Type};
+
+/// An IPv6 address with its scope id, encoded as a `(ayu)` structure.
+///
+/// A plain [`Ipv6Addr`] doesn't carry a scope id, which is needed to use link-local addresses
+/// (`fe80::/10`), e.g to tell which network interface they belong to. This type keeps it along with
+/// the address. The address is encoded as an array of its 16 bytes, in network byte order, followed
+/// by the scope id. Deserialization fails if the array doesn't have exactly 16 bytes.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv6Addr;
+/// use zvariant::{serialized::Context, to_bytes, Ipv6WithScope, Type, LE};
+///
+/// assert_eq!(Ipv6WithScope::signature(), "(ayu)");
+///
+/// let addr = Ipv6WithScope::new("fe80::1".parse().unwrap(), 2);
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let encoded = to_bytes(ctxt, &addr).unwrap();
+/// let decoded: Ipv6WithScope = encoded.deserialize().unwrap().0;
+/// assert_eq!(decoded.addr(), "fe80::1".parse::<Ipv6Addr>().unwrap());
+/// assert_eq!(decoded.scope_id(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ipv6WithScope {
+    addr: Ipv6Addr,
+    scope_id: u32,
+}
+
+impl Ipv6WithScope {
+    /// Create a new `Ipv6WithScope` from an address and its scope id.
+    pub fn new(addr: Ipv6Addr, scope_id: u32) -> Self {
+        Self { addr, scope_id }
+    }
+
+    /// The address.
+    pub fn addr(&self) -> Ipv6Addr {
+        self.addr
+    }
+
+    /// The scope id of the address, `0` if it has none.
+    pub fn scope_id(&self) -> u32 {
+        self.scope_id
+    }
+}
+
+impl Type for Ipv6WithScope {
+    fn signature() -> Signature<'static> {
+        <(&[u8], u32)>::signature()
+    }
+}
+
+impl Serialize for Ipv6WithScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(self.addr.octets().as_slice())?;
+        tuple.serialize_element(&self.scope_id)?;
+
+        tuple.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Ipv6WithScope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (bytes, scope_id) = <(Vec<u8>, u32)>::deserialize(deserializer)?;
+        let octets = <[u8; 16]>::try_from(bytes.as_slice())
+            .map_err(|_| D::Error::invalid_length(bytes.len(), &"an array of 16 bytes"))?;
+
+        Ok(Self::new(Ipv6Addr::from(octets), scope_id))
+    }
+}
+
+impl From<Ipv6Addr> for Ipv6WithScope {
+    /// An address without any scope id.
+    fn from(addr: Ipv6Addr) -> Self {
+        Self::new(addr, 0)
+    }
+}
+
+impl From<(Ipv6Addr, u32)> for Ipv6WithScope {
+    fn from((addr, scope_id): (Ipv6Addr, u32)) -> Self {
+        Self::new(addr, scope_id)
+    }
+}
+
+impl From<SocketAddrV6> for Ipv6WithScope {
+    /// The address of the socket, with its scope id. The port and flow info are dropped.
+    fn from(addr: SocketAddrV6) -> Self {
+        Self::new(*addr.ip(), addr.scope_id())
+    }
+}
+
+impl From<Ipv6WithScope> for (Ipv6Addr, u32) {
+    fn from(value: Ipv6WithScope) -> Self {
+        (value.addr, value.scope_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv6Addr, SocketAddrV6};
+
+    use crate::{serialized::Context, to_bytes, Ipv6WithScope, Type, BE, LE};
+
+    #[test]
+    fn ipv6_with_scope() {
+        assert_eq!(Ipv6WithScope::signature(), "(ayu)");
+
+        let link_local: Ipv6Addr = "fe80::1ff:fe23:4567:890a".parse().unwrap();
+        let addr = Ipv6WithScope::from(SocketAddrV6::new(link_local, 80, 0, 3));
+        assert_eq!(addr.scope_id(), 3);
+
+        for ctxt in [
+            Context::new_dbus(LE, 0),
+            Context::new_dbus(BE, 0),
+            #[cfg(feature = "gvariant")]
+            Context::new_gvariant(LE, 0),
+        ] {
+            let encoded = to_bytes(ctxt, &addr).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &(link_local.octets().to_vec(), 3u32))
+                    .unwrap()
+                    .bytes()
+            );
+            let decoded: Ipv6WithScope = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, addr);
+            assert_eq!(<(Ipv6Addr, u32)>::from(decoded), (link_local, 3));
+        }
+
+        // Only arrays of exactly 16 bytes are addresses.
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &(vec![0xfeu8, 0x80], 3u32)).unwrap();
+        encoded.deserialize::<Ipv6WithScope>().unwrap_err();
+    }
+}
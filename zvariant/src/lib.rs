@@ -26,6 +26,12 @@ pub use basic::*;
 mod dict;
 pub use dict::*;
 
+mod fixed_array;
+pub use fixed_array::*;
+
+mod ordered_dict;
+pub use ordered_dict::*;
+
 #[deprecated(since = "4.0.0", note = "Use `serialized::Context` instead")]
 #[doc(hidden)]
 pub type EncodingContext = serialized::Context;
@@ -72,6 +78,38 @@ pub use crate::maybe::*;
 mod optional;
 pub use crate::optional::*;
 
+mod optional_struct;
+pub use crate::optional_struct::*;
+
+mod byte;
+pub use crate::byte::*;
+
+mod ipv4;
+pub use crate::ipv4::*;
+
+mod ipv6;
+pub use crate::ipv6::*;
+
+mod signed_micros;
+pub use crate::signed_micros::*;
+
+mod system_time_micros;
+pub use crate::system_time_micros::*;
+
+mod duration_micros;
+pub use crate::duration_micros::*;
+
+mod finite_f64;
+pub use crate::finite_f64::*;
+
+#[cfg(feature = "chrono")]
+mod utc_timestamp;
+#[cfg(feature = "chrono")]
+pub use crate::utc_timestamp::*;
+
+mod dbus_result;
+pub use crate::dbus_result::*;
+
 mod value;
 pub use value::*;
 
@@ -106,6 +144,18 @@ mod signature_parser;
 
 mod container_depths;
 
+mod duration;
+
+mod bound;
+
+#[cfg(unix)]
+mod os_str;
+
+pub mod weak;
+
+#[cfg(feature = "glib")]
+mod glib_variant;
+
 pub use zvariant_derive::{DeserializeDict, OwnedValue, SerializeDict, Type, Value};
 
 // Required for the macros to function within this crate.
@@ -143,9 +193,9 @@ mod tests {
     use crate::Fd;
     use crate::{
         serialized::{Context, Format},
-        Array, Basic, DeserializeDict, DeserializeValue, Dict, Error, ObjectPath, Result,
-        SerializeDict, SerializeValue, Signature, Str, Structure, Type, Value, BE, LE,
-        NATIVE_ENDIAN,
+        Array, Basic, DeserializeDict, DeserializeValue, Dict, DictBuilder, Error, ObjectPath,
+        OwnedValue, Result, SerializeDict, SerializeValue, Signature, Str, Structure, Type, Value,
+        BE, LE, NATIVE_ENDIAN,
     };
 
     // Test through both generic and specific API (wrt byte order)
@@ -636,6 +686,322 @@ mod tests {
             .0;
     }
 
+    #[test]
+    fn empty_array_padding() {
+        // In the D-Bus format, the padding to the element alignment after the array length must be
+        // present even if the array is empty.
+        fn pad(bytes: &mut Vec<u8>, alignment: usize) {
+            bytes.resize(bytes.len().div_ceil(alignment) * alignment, 0);
+        }
+        let ctxt = Context::new_dbus(LE, 0);
+
+        // Length ends at offset 4 so 4 bytes of padding follow.
+        let mut expected = vec![];
+        expected.extend(0u32.to_le_bytes());
+        pad(&mut expected, 8);
+        expected.push(0xAB);
+        let value = (Vec::<u64>::new(), 0xABu8);
+        let encoded = to_bytes(ctxt, &value).unwrap();
+        assert_eq!(encoded.bytes(), expected);
+        assert_eq!(encoded.deserialize::<(Vec<u64>, u8)>().unwrap(), (value, 9));
+        // Dict entries are 8-byte aligned as well.
+        let value = (HashMap::<String, u64>::new(), 0xABu8);
+        let encoded = to_bytes(ctxt, &value).unwrap();
+        assert_eq!(encoded.bytes(), expected);
+        assert_eq!(
+            encoded.deserialize::<(HashMap<String, u64>, u8)>().unwrap(),
+            (value, 9)
+        );
+
+        // Length ends at offset 12, and the following field is aligned past the padding.
+        let mut expected = vec![];
+        expected.extend(1u32.to_le_bytes());
+        expected.push(2u8);
+        pad(&mut expected, 4);
+        expected.extend(0u32.to_le_bytes());
+        pad(&mut expected, 8);
+        expected.extend(3u16.to_le_bytes());
+        let value = (1u32, 2u8, Vec::<u64>::new(), 3u16);
+        let encoded = to_bytes(ctxt, &value).unwrap();
+        assert_eq!(encoded.bytes(), expected);
+        assert_eq!(
+            encoded.deserialize::<(u32, u8, Vec<u64>, u16)>().unwrap(),
+            (value, 18)
+        );
+
+        // Length ends at an 8-byte boundary so no padding is needed.
+        let mut expected = vec![];
+        expected.extend(1u32.to_le_bytes());
+        expected.extend(0u32.to_le_bytes());
+        expected.push(0xAB);
+        let value = (1u32, Vec::<u64>::new(), 0xABu8);
+        let encoded = to_bytes(ctxt, &value).unwrap();
+        assert_eq!(encoded.bytes(), expected);
+        assert_eq!(
+            encoded.deserialize::<(u32, Vec<u64>, u8)>().unwrap(),
+            (value, 9)
+        );
+
+        // Same through a `Value`.
+        let mut expected = vec![];
+        expected.extend(1u32.to_le_bytes());
+        expected.push(2);
+        expected.extend(b"at\0");
+        expected.extend(0u32.to_le_bytes());
+        pad(&mut expected, 8);
+        expected.push(0xAB);
+        let value = (1u32, Value::from(Vec::<u64>::new()), 0xABu8);
+        let encoded = to_bytes(ctxt, &value).unwrap();
+        assert_eq!(encoded.bytes(), expected);
+        let (decoded, parsed) = encoded.deserialize::<(u32, Value<'_>, u8)>().unwrap();
+        assert_eq!(parsed, 17);
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn array_owned() {
+        use serde::de::DeserializeOwned;
+
+        // The decoded value must not borrow from the buffer, which is dropped before returning.
+        fn decode<T>(encoded: &[u8], ctxt: Context) -> T
+        where
+            T: DeserializeOwned + Type,
+        {
+            let bytes = encoded.to_vec();
+            let data = Data::new(&bytes[..], ctxt);
+
+            data.deserialize_owned().unwrap().0
+        }
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let strings = vec!["hello", "world", ""];
+        let encoded = to_bytes(ctxt, &strings).unwrap();
+        assert_eq!(<Vec<String>>::signature(), "as");
+        let decoded: Vec<String> = decode(encoded.bytes(), ctxt);
+        assert_eq!(decoded, strings);
+
+        #[cfg(feature = "gvariant")]
+        {
+            let ctxt = Context::new_gvariant(LE, 0);
+            let encoded = to_bytes(ctxt, &strings).unwrap();
+            let decoded: Vec<String> = decode(encoded.bytes(), ctxt);
+            assert_eq!(decoded, strings);
+        }
+    }
+
+    #[test]
+    fn linked_list() {
+        use std::collections::LinkedList;
+
+        assert_eq!(<LinkedList<u32>>::signature(), "au");
+        let list: LinkedList<u32> = [3, 1, 4, 1, 5].into_iter().collect();
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &list).unwrap();
+        // Same encoding as the equivalent `Vec`.
+        let vec: Vec<u32> = list.iter().copied().collect();
+        assert_eq!(encoded.bytes(), to_bytes(ctxt, &vec).unwrap().bytes());
+        let decoded: LinkedList<u32> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, list);
+
+        #[cfg(feature = "gvariant")]
+        {
+            let ctxt = Context::new_gvariant(LE, 0);
+            let encoded = to_bytes(ctxt, &list).unwrap();
+            let decoded: LinkedList<u32> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, list);
+        }
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn array_vec() {
+        assert_eq!(<ArrayVec<u32, 4>>::signature(), "au");
+        assert_eq!(<ArrayVec<(String, u8), 2>>::signature(), "a(sy)");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let full: ArrayVec<u32, 4> = ArrayVec::from([3, 1, 4, 1]);
+        let mut partial = ArrayVec::<u32, 4>::new();
+        partial.extend([5, 9]);
+        for v in [full, partial, ArrayVec::new()] {
+            let encoded = to_bytes(ctxt, &v).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &v.to_vec()).unwrap().bytes()
+            );
+            let decoded: ArrayVec<u32, 4> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, v);
+        }
+
+        // More elements than the capacity.
+        let encoded = to_bytes(ctxt, &vec![3u32, 1, 4, 1, 5]).unwrap();
+        encoded.deserialize::<ArrayVec<u32, 4>>().unwrap_err();
+        // The capacity is only an upper bound.
+        let decoded: ArrayVec<u32, 8> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded.as_slice(), [3, 1, 4, 1, 5]);
+
+        #[cfg(feature = "gvariant")]
+        {
+            let ctxt = Context::new_gvariant(LE, 0);
+            let encoded = to_bytes(ctxt, &vec![3u32, 1, 4, 1, 5]).unwrap();
+            encoded.deserialize::<ArrayVec<u32, 4>>().unwrap_err();
+            let encoded = to_bytes(ctxt, &vec![3u32, 1]).unwrap();
+            let decoded: ArrayVec<u32, 4> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded.as_slice(), [3, 1]);
+        }
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn smallvec() {
+        use smallvec::{smallvec, SmallVec};
+
+        assert_eq!(<SmallVec<[u32; 4]>>::signature(), "au");
+        assert_eq!(<SmallVec<[(String, u8); 2]>>::signature(), "a(sy)");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        // Whether the elements are inline or spilled to the heap, the encoding is the same as the
+        // equivalent `Vec`.
+        let inline: SmallVec<[u32; 4]> = smallvec![3, 1, 4];
+        assert!(!inline.spilled());
+        let spilled: SmallVec<[u32; 4]> = smallvec![3, 1, 4, 1, 5, 9];
+        assert!(spilled.spilled());
+        for v in [inline, spilled] {
+            let encoded = to_bytes(ctxt, &v).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &v.to_vec()).unwrap().bytes()
+            );
+            let decoded: SmallVec<[u32; 4]> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, v);
+
+            #[cfg(feature = "gvariant")]
+            {
+                let ctxt = Context::new_gvariant(LE, 0);
+                let encoded = to_bytes(ctxt, &v).unwrap();
+                let decoded: SmallVec<[u32; 4]> = encoded.deserialize().unwrap().0;
+                assert_eq!(decoded, v);
+            }
+        }
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn index_set() {
+        use indexmap::IndexSet;
+
+        assert_eq!(<IndexSet<u32>>::signature(), "au");
+        assert_eq!(<IndexSet<(String, u8)>>::signature(), "a(sy)");
+
+        let set: IndexSet<&str> = ["zebra", "apple", "mango"].into_iter().collect();
+        for ctxt in [
+            Context::new_dbus(LE, 0),
+            #[cfg(feature = "gvariant")]
+            Context::new_gvariant(LE, 0),
+        ] {
+            // Encoded as an array, in insertion order rather than sorted or hashed order.
+            let encoded = to_bytes(ctxt, &set).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &vec!["zebra", "apple", "mango"])
+                    .unwrap()
+                    .bytes()
+            );
+            let decoded: IndexSet<String> = encoded.deserialize().unwrap().0;
+            assert!(decoded.iter().eq(["zebra", "apple", "mango"]));
+
+            // Duplicates are dropped, keeping the first occurrence.
+            let encoded = to_bytes(ctxt, &vec!["mango", "apple", "mango", "zebra"]).unwrap();
+            let decoded: IndexSet<String> = encoded.deserialize().unwrap().0;
+            assert!(decoded.iter().eq(["mango", "apple", "zebra"]));
+        }
+    }
+
+    #[cfg(feature = "ordered-float")]
+    #[test]
+    fn ordered_float() {
+        use ordered_float::OrderedFloat;
+
+        assert_eq!(OrderedFloat::<f64>::signature(), "d");
+        assert_eq!(OrderedFloat::<f64>::SIGNATURE_CHAR, 'd');
+        assert_eq!(<BTreeMap<OrderedFloat<f64>, &str>>::signature(), "a{ds}");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let value = OrderedFloat(-1.5f64);
+        let encoded = to_bytes(ctxt, &value).unwrap();
+        // Same encoding as the plain `f64`.
+        assert_eq!(encoded.bytes(), to_bytes(ctxt, &-1.5f64).unwrap().bytes());
+        let decoded: OrderedFloat<f64> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, value);
+
+        let map = BTreeMap::from([(OrderedFloat(2.5), "b"), (OrderedFloat(-0.5), "a")]);
+        let encoded = to_bytes(ctxt, &map).unwrap();
+        let decoded: BTreeMap<OrderedFloat<f64>, String> = encoded.deserialize().unwrap().0;
+        assert_eq!(
+            decoded.into_iter().collect::<Vec<_>>(),
+            [
+                (OrderedFloat(-0.5), "a".to_string()),
+                (OrderedFloat(2.5), "b".to_string())
+            ],
+        );
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn half_f16() {
+        use half::f16;
+
+        assert_eq!(f16::signature(), "d");
+        assert_eq!(f16::SIGNATURE_CHAR, 'd');
+        assert_eq!(<Vec<f16>>::signature(), "ad");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        for value in [
+            f16::ZERO,
+            f16::ONE,
+            f16::from_f32(-1.5),
+            f16::MAX,
+            f16::MIN_POSITIVE,
+        ] {
+            let encoded = to_bytes(ctxt, &value).unwrap();
+            // Same encoding as the equivalent `f64`.
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &f64::from(value)).unwrap().bytes()
+            );
+            let decoded: f16 = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, value);
+
+            #[cfg(feature = "gvariant")]
+            {
+                let ctxt = Context::new_gvariant(LE, 0);
+                let encoded = to_bytes(ctxt, &value).unwrap();
+                let decoded: f16 = encoded.deserialize().unwrap().0;
+                assert_eq!(decoded, value);
+            }
+        }
+
+        // Precision loss: rounded to the nearest `f16`.
+        let encoded = to_bytes(ctxt, &0.1f64).unwrap();
+        let decoded: f16 = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, f16::from_f64(0.1));
+        assert_ne!(f64::from(decoded), 0.1);
+        // Out of range.
+        let encoded = to_bytes(ctxt, &1e6f64).unwrap();
+        let decoded: f16 = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, f16::INFINITY);
+
+        // In containers too.
+        let values = (7u16, vec![f16::ONE, f16::from_f32(-0.25)]);
+        let encoded = to_bytes(ctxt, &values).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            to_bytes(ctxt, &(7u16, vec![1f64, -0.25])).unwrap().bytes()
+        );
+        let decoded: (u16, Vec<f16>) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, values);
+    }
+
     #[test]
     fn array_value() {
         // Let's use D-Bus/GVariant terms
@@ -1102,6 +1468,147 @@ mod tests {
         assert_eq!(decoded, [1u32, 2u32]);
     }
 
+    #[cfg(feature = "gvariant")]
+    #[test]
+    fn non_zero_gvariant() {
+        use std::num::{
+            NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64,
+            NonZeroU8,
+        };
+
+        macro_rules! check {
+            ($non_zero:ty, $base:ty, $value:expr) => {{
+                assert_eq!(<$non_zero>::signature(), <$base>::signature());
+                let format = Format::GVariant;
+                assert_eq!(<$non_zero>::alignment(format), <$base>::alignment(format));
+
+                let ctxt = Context::new_gvariant(LE, 0);
+                let value = <$non_zero>::new($value).unwrap();
+                // A leading byte, so the alignment of the value matters.
+                let encoded = to_bytes(ctxt, &(1u8, value)).unwrap();
+                assert_eq!(
+                    encoded.bytes(),
+                    to_bytes(ctxt, &(1u8, $value)).unwrap().bytes(),
+                );
+                let decoded: (u8, $non_zero) = encoded.deserialize().unwrap().0;
+                assert_eq!(decoded, (1, value));
+
+                let encoded = to_bytes(ctxt, &vec![value, value]).unwrap();
+                assert_eq!(
+                    encoded.bytes(),
+                    to_bytes(ctxt, &vec![$value, $value]).unwrap().bytes(),
+                );
+                let decoded: Vec<$non_zero> = encoded.deserialize().unwrap().0;
+                assert_eq!(decoded, [value, value]);
+
+                // Zero is rejected.
+                let zero: $base = 0;
+                let encoded = to_bytes(ctxt, &(1u8, zero)).unwrap();
+                encoded.deserialize::<(u8, $non_zero)>().unwrap_err();
+                let encoded = to_bytes(ctxt, &vec![$value, zero]).unwrap();
+                encoded.deserialize::<Vec<$non_zero>>().unwrap_err();
+            }};
+        }
+
+        check!(NonZeroU8, u8, 0xabu8);
+        check!(NonZeroI8, i8, -5i8);
+        check!(NonZeroU16, u16, 0xabcdu16);
+        check!(NonZeroI16, i16, -0x1234i16);
+        check!(NonZeroU32, u32, 0xdead_beefu32);
+        check!(NonZeroI32, i32, -0x1234_5678i32);
+        check!(NonZeroU64, u64, 0xdead_beef_cafe_babeu64);
+        check!(NonZeroI64, i64, -0x1234_5678_9abc_def0i64);
+    }
+
+    #[test]
+    fn single_field_tuple() {
+        // A one-element tuple is a structure with a single field, not the bare field.
+        assert_eq!(<(u32,)>::signature().as_str(), "(u)");
+        assert_eq!(u32::signature().as_str(), "u");
+        assert_eq!(<(Vec<(String,)>,)>::signature().as_str(), "(a(s))");
+
+        // The structure alignment makes the difference at unaligned positions.
+        let ctxt = Context::new_dbus(LE, 4);
+        let encoded = to_bytes(ctxt, &(42u32,)).unwrap();
+        assert_eq!(encoded.len(), 8);
+        let decoded: (u32,) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, (42,));
+        let encoded = to_bytes(ctxt, &42u32).unwrap();
+        assert_eq!(encoded.len(), 4);
+
+        let v = Value::from((42u32,));
+        assert_eq!(v.value_signature().as_str(), "(u)");
+        let encoded = to_bytes(ctxt, &v).unwrap();
+        let decoded: Value<'_> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded.value_signature().as_str(), "(u)");
+        assert_eq!(<(u32,)>::try_from(decoded).unwrap(), (42,));
+
+        #[cfg(feature = "gvariant")]
+        {
+            let ctxt = Context::new_gvariant(LE, 0);
+            let encoded = to_bytes(ctxt, &("hello".to_string(),)).unwrap();
+            let decoded: (String,) = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded.0, "hello");
+        }
+    }
+
+    #[test]
+    fn wrapping() {
+        use std::num::Wrapping;
+
+        assert_eq!(<Wrapping<u8>>::signature(), "y");
+        assert_eq!(<Wrapping<i8>>::signature(), "n");
+        assert_eq!(<Wrapping<u64>>::signature(), "t");
+        // Can be used as dict keys, like the wrapped integers.
+        assert_eq!(<HashMap<Wrapping<i32>, &str>>::signature(), "a{is}");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let value = (Wrapping(u32::MAX) + Wrapping(2), Wrapping(-3i16));
+        let encoded = to_bytes(ctxt, &value).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            to_bytes(ctxt, &(1u32, -3i16)).unwrap().bytes()
+        );
+        let decoded: (Wrapping<u32>, Wrapping<i16>) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn heterogeneous_dict() {
+        let mut map: HashMap<&str, Value<'_>> = HashMap::new();
+        map.insert("count", Value::from(42i32));
+        map.insert("name", Value::from("zbus"));
+        assert_eq!(<HashMap<String, OwnedValue>>::signature(), "a{sv}");
+
+        let check = |ctxt: Context| {
+            let encoded = to_bytes(ctxt, &map).unwrap();
+
+            // Each value keeps its own signature, so it can be downcast to its original type.
+            let decoded: HashMap<String, OwnedValue> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded.len(), 2);
+            assert_eq!(decoded["count"].value_signature(), "i");
+            assert_eq!(decoded["name"].value_signature(), "s");
+            assert_eq!(decoded["count"].downcast_ref::<i32>().unwrap(), 42);
+            assert_eq!(decoded["name"].downcast_ref::<&str>().unwrap(), "zbus");
+            // But not to another one.
+            decoded["count"].downcast_ref::<&str>().unwrap_err();
+            decoded["name"].downcast_ref::<i32>().unwrap_err();
+            let count = decoded["count"].try_clone().unwrap();
+            assert_eq!(i32::try_from(count).unwrap(), 42);
+
+            // Same with borrowed values.
+            let decoded: HashMap<&str, Value<'_>> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded["count"], Value::I32(42));
+            assert_eq!(decoded["name"], Value::from("zbus"));
+
+            // The values are not unified into a common type.
+            encoded.deserialize::<HashMap<String, i32>>().unwrap_err();
+        };
+        check(Context::new_dbus(LE, 0));
+        #[cfg(feature = "gvariant")]
+        check(Context::new_gvariant(LE, 0));
+    }
+
     #[test]
     fn dict_value() {
         let mut map: HashMap<i64, &str> = HashMap::new();
@@ -1303,6 +1810,188 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dict_custom_hasher() {
+        use std::hash::{BuildHasherDefault, Hasher};
+
+        // A simple FNV-1a hasher.
+        struct FnvHasher(u64);
+
+        impl Default for FnvHasher {
+            fn default() -> Self {
+                Self(0xcbf2_9ce4_8422_2325)
+            }
+        }
+
+        impl Hasher for FnvHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    self.0 ^= u64::from(*byte);
+                    self.0 = self.0.wrapping_mul(0x100_0000_01b3);
+                }
+            }
+        }
+
+        type FnvHashMap<K, V> = HashMap<K, V, BuildHasherDefault<FnvHasher>>;
+
+        assert_eq!(FnvHashMap::<String, u32>::signature(), "a{su}");
+
+        // A single entry, so the iteration order is the same for both hashers.
+        let mut map = FnvHashMap::default();
+        map.insert(String::from("answer"), 42u32);
+        let mut default_map = HashMap::new();
+        default_map.insert(String::from("answer"), 42u32);
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &map).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            to_bytes(ctxt, &default_map).unwrap().bytes()
+        );
+        let decoded: FnvHashMap<String, u32> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, map);
+
+        map.insert(String::from("question"), 0);
+        let encoded = to_bytes(ctxt, &map).unwrap();
+        let decoded: FnvHashMap<String, u32> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, map);
+        // A map encoded with one hasher decodes into a map with another.
+        let decoded: HashMap<String, u32> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded["question"], 0);
+
+        // Through `Value` as well.
+        let value = Value::from(map.clone());
+        assert_eq!(value.value_signature(), "a{su}");
+        let decoded = FnvHashMap::<String, u32>::try_from(value).unwrap();
+        assert_eq!(decoded, map);
+    }
+
+    #[test]
+    fn btree_ordering() {
+        use std::collections::BTreeSet;
+
+        assert_eq!(<BTreeMap<String, u32>>::signature(), "a{su}");
+        assert_eq!(<BTreeSet<String>>::signature(), "as");
+
+        let keys = ["zebra", "apple", "mango", "kiwi"];
+        let map1: BTreeMap<String, u32> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| (k.to_string(), i as u32))
+            .collect();
+        let map2: BTreeMap<String, u32> = keys
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, k)| (k.to_string(), i as u32))
+            .collect();
+        let set1: BTreeSet<&str> = keys.iter().copied().collect();
+        let set2: BTreeSet<&str> = keys.iter().rev().copied().collect();
+
+        let check = |ctxt: Context| {
+            // Equal maps encode to the same bytes, whatever the insertion order.
+            let encoded = to_bytes(ctxt, &map1).unwrap();
+            assert_eq!(encoded.bytes(), to_bytes(ctxt, &map2).unwrap().bytes());
+            let decoded: BTreeMap<String, u32> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, map1);
+            // With the entries in key order.
+            let positions: Vec<_> = ["apple", "kiwi", "mango", "zebra"]
+                .iter()
+                .map(|k| {
+                    encoded
+                        .bytes()
+                        .windows(k.len())
+                        .position(|w| w == k.as_bytes())
+                        .unwrap()
+                })
+                .collect();
+            assert!(positions.windows(2).all(|p| p[0] < p[1]));
+
+            let encoded = to_bytes(ctxt, &set1).unwrap();
+            assert_eq!(encoded.bytes(), to_bytes(ctxt, &set2).unwrap().bytes());
+            let decoded: BTreeSet<String> = encoded.deserialize().unwrap().0;
+            assert!(decoded.iter().eq(["apple", "kiwi", "mango", "zebra"]));
+        };
+        check(Context::new_dbus(LE, 0));
+        #[cfg(feature = "gvariant")]
+        check(Context::new_gvariant(LE, 0));
+    }
+
+    #[test]
+    fn dict_entries() {
+        use serde::ser::SerializeMap;
+
+        // Serializes the entries through `serialize_key` and `serialize_value`, instead of
+        // `serialize_entry` as maps do.
+        struct SplitEntries<'m, M>(&'m M);
+
+        impl<'m, M> Type for SplitEntries<'m, M>
+        where
+            M: Type,
+        {
+            fn signature() -> Signature<'static> {
+                M::signature()
+            }
+        }
+
+        impl<'m, M> Serialize for SplitEntries<'m, M>
+        where
+            &'m M: IntoIterator<Item = (&'m String, &'m String)>,
+        {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let mut map = serializer.serialize_map(None)?;
+                for (key, value) in self.0 {
+                    map.serialize_key(key)?;
+                    map.serialize_value(value)?;
+                }
+
+                map.end()
+            }
+        }
+
+        let entries = [
+            ("PATH", "/usr/bin"),
+            ("HOME", "/home/zeenix"),
+            ("LANG", "C"),
+        ];
+        let btree: BTreeMap<String, String> = entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let hash: HashMap<String, String> = btree.clone().into_iter().collect();
+
+        for ctxt in [
+            Context::new_dbus(LE, 0),
+            // Not at the start, so entries need padding.
+            Context::new_dbus(BE, 3),
+            #[cfg(feature = "gvariant")]
+            Context::new_gvariant(LE, 0),
+        ] {
+            let encoded = to_bytes(ctxt, &btree).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &SplitEntries(&btree)).unwrap().bytes()
+            );
+            let decoded: BTreeMap<String, String> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, btree);
+
+            let encoded = to_bytes(ctxt, &hash).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &SplitEntries(&hash)).unwrap().bytes()
+            );
+            let decoded: HashMap<String, String> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, hash);
+        }
+    }
+
     #[test]
     fn dict_compare() {
         // the order in which a dict has been constructed must not play a role
@@ -1318,6 +2007,45 @@ mod tests {
         assert_eq!(dict1, dict2);
     }
 
+    #[test]
+    fn dict_builder() {
+        let dict = DictBuilder::new()
+            .add("int", 42i32)
+            .add("string", "hello")
+            .add("array", vec![1u8, 2, 3])
+            .build();
+        assert_eq!(dict.len(), 3);
+        assert_eq!(dict["int"], Value::I32(42));
+        assert_eq!(dict["string"], Value::from("hello"));
+        assert_eq!(dict["array"], Value::from(vec![1u8, 2, 3]));
+
+        // Must encode the same as a manually built map. Entry order isn't deterministic so we
+        // compare the decoded maps rather than the bytes.
+        let mut map: HashMap<String, Value<'_>> = HashMap::new();
+        map.insert("int".into(), Value::I32(42));
+        map.insert("string".into(), Value::from("hello"));
+        map.insert("array".into(), Value::from(vec![1u8, 2, 3]));
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &dict).unwrap();
+        let decoded: HashMap<String, OwnedValue> = encoded.deserialize().unwrap().0;
+        let expected: HashMap<String, OwnedValue> =
+            to_bytes(ctxt, &map).unwrap().deserialize().unwrap().0;
+        assert_eq!(decoded, expected);
+
+        assert_eq!(i32::try_from(&decoded["int"]).unwrap(), 42);
+        assert_eq!(<&str>::try_from(&decoded["string"]).unwrap(), "hello");
+        assert_eq!(
+            <Vec<u8>>::try_from(decoded["array"].try_clone().unwrap()).unwrap(),
+            vec![1, 2, 3]
+        );
+
+        // In-place too.
+        let mut builder = DictBuilder::new();
+        builder.push("int", 42i32);
+        builder.push(String::from("int"), 7i32);
+        assert_eq!(builder.build()["int"], Value::I32(7));
+    }
+
     #[test]
     fn value_value() {
         let ctxt = Context::new_dbus(BE, 0);
@@ -1567,6 +2295,41 @@ mod tests {
         let decoded: StrEnum = encoded.deserialize().unwrap().0;
         assert_eq!(decoded, StrEnum::Variant2);
 
+        // Unit enums are encoded as their variant index, and unknown indices are rejected.
+        #[derive(Deserialize, Serialize, Type, Debug, PartialEq)]
+        #[zvariant(signature = "u")]
+        enum Color {
+            Red,
+            Green,
+        }
+
+        assert_eq!(Color::signature(), u32::signature());
+        let encoded = to_bytes(ctxt, &Color::Green).unwrap();
+        assert_eq!(encoded.bytes(), 1u32.to_le_bytes());
+        let decoded: Color = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, Color::Green);
+        let encoded = to_bytes(ctxt, &2u32).unwrap();
+        encoded.deserialize::<Color>().unwrap_err();
+
+        // For explicit discriminants, `serde_repr` is needed since serde uses the variant index.
+        #[repr(u32)]
+        #[derive(Deserialize_repr, Serialize_repr, Type, Debug, PartialEq)]
+        enum ReprColor {
+            Red = 1,
+            Green = 4,
+        }
+
+        assert_eq!(ReprColor::signature(), u32::signature());
+        let encoded = to_bytes(ctxt, &ReprColor::Green).unwrap();
+        assert_eq!(encoded.bytes(), 4u32.to_le_bytes());
+        let decoded: ReprColor = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, ReprColor::Green);
+        let encoded = to_bytes(ctxt, &1u32).unwrap();
+        let decoded: ReprColor = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, ReprColor::Red);
+        let encoded = to_bytes(ctxt, &2u32).unwrap();
+        encoded.deserialize::<ReprColor>().unwrap_err();
+
         #[derive(Deserialize, Serialize, Type)]
         enum NewType {
             Variant1(f64),
@@ -1657,6 +2420,117 @@ mod tests {
         assert_eq!(encoded.len(), 528);
         let decoded: Struct<'_> = encoded.deserialize().unwrap().0;
         assert_eq!(decoded, s);
+
+        // All the wrappers are byte arrays, encoded the same as a `Vec<u8>`: the length followed by
+        // the bytes as is.
+        assert_eq!(<&Bytes>::signature(), "ay");
+        assert_eq!(ByteBuf::signature(), "ay");
+        assert_eq!(<ByteArray<4>>::signature(), "ay");
+        let data = vec![0xDEu8, 0xAD, 0xBE, 0xEF];
+        let expected = to_bytes(ctxt, &data).unwrap();
+        assert_eq!(expected.bytes(), [4, 0, 0, 0, 0xDE, 0xAD, 0xBE, 0xEF]);
+        let buf = ByteBuf::from(data.clone());
+        assert_eq!(to_bytes(ctxt, &buf).unwrap().bytes(), expected.bytes());
+        let array = ByteArray::new([0xDEu8, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(to_bytes(ctxt, &array).unwrap().bytes(), expected.bytes());
+        let decoded: ByteArray<4> = expected.deserialize().unwrap().0;
+        assert_eq!(decoded, array);
+        // The wrong number of bytes.
+        expected.deserialize::<ByteArray<3>>().unwrap_err();
+
+        #[derive(Deserialize, Serialize, Type, PartialEq, Debug)]
+        struct OwnedStruct {
+            #[serde(with = "serde_bytes")]
+            field1: Vec<u8>,
+            field2: ByteArray<2>,
+        }
+        assert_eq!(OwnedStruct::signature(), "(ayay)");
+        let s = OwnedStruct {
+            field1: data,
+            field2: ByteArray::new([1, 2]),
+        };
+        let encoded = to_bytes(ctxt, &s).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            [4, 0, 0, 0, 0xDE, 0xAD, 0xBE, 0xEF, 2, 0, 0, 0, 1, 2]
+        );
+        let decoded: OwnedStruct = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, s);
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn bytes() {
+        use bytes::{Bytes, BytesMut};
+
+        assert_eq!(Bytes::signature(), "ay");
+        assert_eq!(BytesMut::signature(), "ay");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let data: Vec<u8> = (0..8192).map(|i| (i % 256) as u8).collect();
+        let ay = Bytes::from(data.clone());
+        let encoded = to_bytes(ctxt, &ay).unwrap();
+        assert_eq!(encoded.len(), 8196);
+        let decoded: Bytes = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, data);
+
+        let ay = BytesMut::from(&data[..]);
+        let encoded = to_bytes(ctxt, &ay).unwrap();
+        assert_eq!(encoded.len(), 8196);
+        let decoded: BytesMut = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, data);
+
+        // Same encoding as a `Vec<u8>`.
+        let decoded: Vec<u8> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn cow_bytes() {
+        use std::borrow::Cow;
+
+        assert_eq!(<Cow<'_, [u8]>>::signature(), "ay");
+
+        let data: Vec<u8> = (0..1_000_000).map(|i| (i % 251) as u8).collect();
+        for ctxt in [
+            Context::new_dbus(LE, 0),
+            #[cfg(feature = "gvariant")]
+            Context::new_gvariant(LE, 0),
+        ] {
+            let expected = to_bytes(ctxt, &data).unwrap();
+
+            // Borrowed and owned variants are encoded the same as a `Vec<u8>`.
+            let borrowed: Cow<'_, [u8]> = Cow::Borrowed(&data);
+            let owned: Cow<'_, [u8]> = Cow::Owned(data.clone());
+            for cow in [borrowed, owned] {
+                let encoded = to_bytes(ctxt, &cow).unwrap();
+                assert_eq!(encoded.bytes(), expected.bytes());
+
+                let decoded: Cow<'_, [u8]> = encoded.deserialize().unwrap().0;
+                assert!(matches!(decoded, Cow::Owned(_)));
+                assert_eq!(decoded, data);
+            }
+        }
+
+        // With `serde_bytes`, the bytes are written all at once, to the same encoding.
+        #[cfg(feature = "serde_bytes")]
+        {
+            #[derive(Deserialize, Serialize, Type, PartialEq, Debug)]
+            struct Blob<'b> {
+                #[serde(borrow, with = "serde_bytes")]
+                data: Cow<'b, [u8]>,
+            }
+            assert_eq!(Blob::signature(), "(ay)");
+
+            let ctxt = Context::new_dbus(LE, 0);
+            let blob = Blob {
+                data: Cow::Borrowed(&data),
+            };
+            let encoded = to_bytes(ctxt, &blob).unwrap();
+            assert_eq!(encoded.bytes(), to_bytes(ctxt, &data).unwrap().bytes());
+            let decoded: Blob<'_> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, blob);
+        }
     }
 
     #[test]
@@ -1930,6 +2804,526 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(all(feature = "gvariant", not(feature = "option-as-array")))]
+    fn option_bool() {
+        use glib::ToVariant;
+
+        let ctxt = Context::new_gvariant(NATIVE_ENDIAN, 0);
+        assert_eq!(<Option<bool>>::signature(), "mb");
+
+        // A GVariant boolean is a single byte, and a maybe of a fixed-sized value has no extra
+        // bytes.
+        for (mb, expected) in [
+            (None, &[][..]),
+            (Some(false), &[0][..]),
+            (Some(true), &[1][..]),
+        ] {
+            let encoded = to_bytes(ctxt, &mb).unwrap();
+            assert_eq!(encoded.bytes(), expected);
+            let decoded: Option<bool> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, mb);
+
+            // Check encoding against GLib
+            assert_eq!(mb.to_variant().data(), expected);
+            let bytes = Bytes::from_owned(encoded);
+            let variant = Variant::from_bytes::<Option<bool>>(&bytes);
+            assert_eq!(variant.get::<Option<bool>>().unwrap(), mb);
+
+            // The boolean doesn't pad the fields following it.
+            let s = (mb, 7u8, mb);
+            let encoded = to_bytes(ctxt, &s).unwrap();
+            assert_eq!(encoded.bytes(), s.to_variant().data());
+            let decoded: (Option<bool>, u8, Option<bool>) = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, s);
+        }
+
+        // Only 0 and 1 are valid booleans.
+        let encoded = Data::new(&[2u8][..], ctxt);
+        encoded.deserialize::<Option<bool>>().unwrap_err();
+    }
+
+    #[test]
+    fn bool_array() {
+        let a = [true, false, true];
+        let v = a.to_vec();
+        assert_eq!(<[bool; 3]>::signature(), "(bbb)");
+        assert_eq!(<Vec<bool>>::signature(), "ab");
+
+        // D-Bus booleans are 4-byte integers.
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &a).unwrap();
+        assert_eq!(encoded.bytes(), &[1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0]);
+        let decoded: [bool; 3] = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, a);
+        let encoded = to_bytes(ctxt, &v).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            &[12, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0]
+        );
+        let decoded: Vec<bool> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, v);
+        // The array is encoded as a structure, aligned to 8 bytes.
+        let encoded = to_bytes(ctxt, &(7u8, a)).unwrap();
+        assert_eq!(encoded.len(), 20);
+        let decoded: (u8, [bool; 3]) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, (7, a));
+
+        #[cfg(feature = "gvariant")]
+        {
+            use glib::ToVariant;
+
+            // GVariant booleans are single bytes, with an alignment of 1.
+            let ctxt = Context::new_gvariant(LE, 0);
+            let encoded = to_bytes(ctxt, &a).unwrap();
+            assert_eq!(encoded.bytes(), &[1, 0, 1]);
+            let decoded: [bool; 3] = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, a);
+            let encoded = to_bytes(ctxt, &v).unwrap();
+            assert_eq!(encoded.bytes(), &[1, 0, 1]);
+            let decoded: Vec<bool> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, v);
+            // Only the `u32` following the array is padded.
+            let s = (a, 7u32);
+            let encoded = to_bytes(ctxt, &s).unwrap();
+            assert_eq!(encoded.bytes(), &[1, 0, 1, 0, 7, 0, 0, 0]);
+            let decoded: ([bool; 3], u32) = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, s);
+
+            // Check encoding against GLib
+            if cfg!(target_endian = "little") {
+                assert_eq!((true, false, true).to_variant().data(), &[1, 0, 1]);
+                assert_eq!(v.to_variant().data(), &[1, 0, 1]);
+                assert_eq!(
+                    ((true, false, true), 7u32).to_variant().data(),
+                    &[1, 0, 1, 0, 7, 0, 0, 0]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn duration() {
+        use std::time::Duration;
+
+        let duration = Duration::new(42, 123_456_789);
+        let ctxt = Context::new_dbus(LE, 0);
+
+        assert_eq!(Duration::signature(), "(tu)");
+        assert_eq!(<(u8, Vec<Duration>)>::signature(), "(ya(tu))");
+
+        let encoded = to_bytes(ctxt, &duration).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            to_bytes(ctxt, &(42u64, 123_456_789u32)).unwrap().bytes()
+        );
+        let decoded: Duration = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, duration);
+
+        // Arrays of durations are arrays of structures.
+        assert_eq!(Vec::<Duration>::signature(), "a(tu)");
+        let durations = vec![duration, Duration::ZERO, Duration::MAX];
+        let encoded = to_bytes(ctxt, &durations).unwrap();
+        let decoded: Vec<Duration> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, durations);
+
+        // Either encoding can be picked explicitly through the signature.
+        let encoded = to_bytes_for_signature(ctxt, "t", &duration).unwrap();
+        assert_eq!(encoded.bytes(), 42_123_456u64.to_le_bytes());
+        let decoded: Duration = encoded.deserialize_for_signature("t").unwrap().0;
+        assert_eq!(decoded, Duration::new(42, 123_456_000));
+        let encoded = to_bytes_for_signature(ctxt, "(tu)", &duration).unwrap();
+        let decoded: Duration = encoded.deserialize_for_signature("(tu)").unwrap().0;
+        assert_eq!(decoded, duration);
+    }
+
+    #[test]
+    #[cfg(all(feature = "gvariant", not(feature = "option-as-array")))]
+    fn option_duration() {
+        use std::time::Duration;
+
+        let ctxt = Context::new_gvariant(LE, 0);
+        assert_eq!(<Option<Duration>>::signature(), "m(tu)");
+
+        // `(tu)` is fixed-sized, so it's padded to a multiple of its alignment (8) and the maybe
+        // doesn't get a trailing nul byte.
+        let mut expected = vec![];
+        expected.extend(42u64.to_le_bytes());
+        expected.extend(123_456_789u32.to_le_bytes());
+        expected.extend([0; 4]);
+        let md = Some(Duration::new(42, 123_456_789));
+        let encoded = to_bytes(ctxt, &md).unwrap();
+        assert_eq!(encoded.bytes(), expected);
+        let (decoded, parsed): (Option<Duration>, _) = encoded.deserialize().unwrap();
+        assert_eq!(decoded, md);
+        assert_eq!(parsed, 16);
+
+        // Check encoding against GLib
+        let bytes = Bytes::from_owned(encoded);
+        let variant = Variant::from_bytes::<Option<(u64, u32)>>(&bytes);
+        assert_eq!(
+            variant.get::<Option<(u64, u32)>>().unwrap(),
+            Some((42, 123_456_789))
+        );
+
+        let md: Option<Duration> = None;
+        let encoded = to_bytes(ctxt, &md).unwrap();
+        assert_eq!(encoded.len(), 0);
+        let decoded: Option<Duration> = encoded.deserialize().unwrap().0;
+        assert!(decoded.is_none());
+
+        // The maybe's alignment is that of `(tu)`.
+        let value = (0xABu8, Some(Duration::new(42, 123_456_789)));
+        let encoded = to_bytes(ctxt, &value).unwrap();
+        let mut expected_struct = vec![0xAB, 0, 0, 0, 0, 0, 0, 0];
+        expected_struct.extend(&expected);
+        assert_eq!(encoded.bytes(), expected_struct);
+        let decoded: (u8, Option<Duration>) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, value);
+
+        let bytes = Bytes::from_owned(encoded);
+        let variant = Variant::from_bytes::<(u8, Option<(u64, u32)>)>(&bytes);
+        assert_eq!(
+            variant.get::<(u8, Option<(u64, u32)>)>().unwrap(),
+            (0xAB, Some((42, 123_456_789)))
+        );
+
+        // Padding is still added before an empty maybe.
+        let value = (0xABu8, None::<Duration>);
+        let encoded = to_bytes(ctxt, &value).unwrap();
+        assert_eq!(encoded.bytes(), [0xAB, 0, 0, 0, 0, 0, 0, 0]);
+        let decoded: (u8, Option<Duration>) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, value);
+    }
+
+    #[cfg(feature = "camino")]
+    #[test]
+    fn camino_path() {
+        use camino::{Utf8Path, Utf8PathBuf};
+
+        assert_eq!(<&Utf8Path>::signature(), "s");
+        assert_eq!(Utf8PathBuf::signature(), "s");
+        assert_eq!(<Vec<Utf8PathBuf>>::signature(), "as");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let path = Utf8Path::new("/usr/share/dbus-1/services");
+        let encoded = to_bytes(ctxt, &path).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            to_bytes(ctxt, "/usr/share/dbus-1/services")
+                .unwrap()
+                .bytes()
+        );
+        let decoded: &Utf8Path = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, path);
+        let decoded: Utf8PathBuf = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, path);
+        assert_eq!(to_bytes(ctxt, &decoded).unwrap().bytes(), encoded.bytes());
+
+        let paths = vec![Utf8PathBuf::from("relative/dir"), Utf8PathBuf::from("/")];
+        let encoded = to_bytes(ctxt, &paths).unwrap();
+        let decoded: Vec<Utf8PathBuf> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, paths);
+
+        #[cfg(feature = "gvariant")]
+        {
+            let ctxt = Context::new_gvariant(LE, 0);
+            let encoded = to_bytes(ctxt, &path).unwrap();
+            let decoded: Utf8PathBuf = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, path);
+        }
+    }
+
+    #[test]
+    fn path() {
+        use std::{
+            borrow::Cow,
+            path::{Path, PathBuf},
+        };
+
+        assert_eq!(<&Path>::signature(), "s");
+        assert_eq!(PathBuf::signature(), "s");
+        assert_eq!(<Cow<'_, Path>>::signature(), "s");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let path = Path::new("/usr/share/dbus-1");
+        let encoded = to_bytes(ctxt, &path).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            to_bytes(ctxt, "/usr/share/dbus-1").unwrap().bytes()
+        );
+
+        // Borrowed and owned variants are encoded the same.
+        let borrowed: Cow<'_, Path> = Cow::Borrowed(path);
+        let owned: Cow<'_, Path> = Cow::Owned(path.to_path_buf());
+        assert_eq!(to_bytes(ctxt, &borrowed).unwrap().bytes(), encoded.bytes());
+        assert_eq!(to_bytes(ctxt, &owned).unwrap().bytes(), encoded.bytes());
+
+        let decoded: Cow<'_, Path> = encoded.deserialize().unwrap().0;
+        assert!(matches!(decoded, Cow::Owned(_)));
+        assert_eq!(decoded, path);
+        let decoded: PathBuf = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, path);
+
+        #[cfg(unix)]
+        {
+            use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+            let non_utf8 = Path::new(OsStr::from_bytes(b"/tmp/\xff"));
+            to_bytes(ctxt, &non_utf8).unwrap_err();
+            let err = to_bytes(ctxt, &Cow::Borrowed(non_utf8)).unwrap_err();
+            // Same error as for the path itself.
+            assert_eq!(
+                err.to_string(),
+                to_bytes(ctxt, &non_utf8).unwrap_err().to_string(),
+            );
+            to_bytes(ctxt, &non_utf8.to_path_buf()).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn reverse() {
+        use std::{cmp::Reverse, collections::BinaryHeap};
+
+        assert_eq!(<Reverse<u32>>::signature(), "u");
+        assert_eq!(<Vec<Reverse<(String, u8)>>>::signature(), "a(sy)");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &Reverse(42u32)).unwrap();
+        assert_eq!(encoded.bytes(), to_bytes(ctxt, &42u32).unwrap().bytes());
+        let decoded: Reverse<u32> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, Reverse(42));
+
+        // A min-heap.
+        let heap: BinaryHeap<_> = [3u32, 1, 2].into_iter().map(Reverse).collect();
+        let encoded = to_bytes(ctxt, &heap.into_sorted_vec()).unwrap();
+        let decoded: Vec<u32> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, [3, 2, 1]);
+    }
+
+    #[test]
+    fn const_signature() {
+        const SIGNATURES: [(&str, Signature<'static>); 4] = [
+            ("name", <&str>::SIGNATURE),
+            ("label", String::SIGNATURE),
+            ("path", ObjectPath::SIGNATURE),
+            ("count", u32::SIGNATURE),
+        ];
+
+        assert_eq!(SIGNATURES[0].1, <&str>::signature());
+        assert_eq!(SIGNATURES[1].1, String::signature());
+        assert_eq!(SIGNATURES[2].1, ObjectPath::signature());
+        assert_eq!(SIGNATURES[3].1, u32::signature());
+        let (_, signature) = SIGNATURES
+            .into_iter()
+            .find(|(name, _)| *name == "label")
+            .unwrap();
+        assert_eq!(signature.as_str(), "s");
+    }
+
+    #[test]
+    fn bound() {
+        use std::ops::Bound;
+
+        assert_eq!(Bound::<u32>::signature(), "(yv)");
+        assert_eq!(<(Bound<u32>, Bound<u32>)>::signature(), "((yv)(yv))");
+
+        for ctxt in [
+            Context::new_dbus(LE, 0),
+            #[cfg(feature = "gvariant")]
+            Context::new_gvariant(LE, 0),
+        ] {
+            for (bound, expected) in [
+                (Bound::Unbounded, (0u8, Value::U8(0))),
+                (Bound::Included(42u32), (1u8, Value::U32(42))),
+                (Bound::Excluded(7u32), (2u8, Value::U32(7))),
+            ] {
+                let encoded = to_bytes(ctxt, &bound).unwrap();
+                assert_eq!(encoded.bytes(), to_bytes(ctxt, &expected).unwrap().bytes());
+                let decoded: Bound<u32> = encoded.deserialize().unwrap().0;
+                assert_eq!(decoded, bound);
+            }
+
+            // Nested, and with a string.
+            let range = (Bound::Included("a"), Bound::<&str>::Unbounded);
+            let encoded = to_bytes(ctxt, &range).unwrap();
+            let decoded: (Bound<&str>, Bound<&str>) = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, range);
+
+            // Unknown tags are rejected.
+            let encoded = to_bytes(ctxt, &(3u8, Value::U32(42))).unwrap();
+            assert!(encoded.deserialize::<Bound<u32>>().is_err());
+        }
+    }
+
+    #[test]
+    fn weak() {
+        use std::{rc, sync};
+
+        #[derive(Serialize, Type)]
+        struct Nodes {
+            #[serde(serialize_with = "crate::weak::rc::serialize")]
+            local: rc::Weak<u32>,
+            #[serde(serialize_with = "crate::weak::sync::serialize")]
+            shared: sync::Weak<str>,
+        }
+
+        assert_eq!(rc::Weak::<u32>::signature(), "u");
+        assert_eq!(sync::Weak::<str>::signature(), "s");
+        assert_eq!(Nodes::signature(), "(us)");
+
+        let local = rc::Rc::new(42u32);
+        let shared: sync::Arc<str> = sync::Arc::from("shared");
+        let nodes = Nodes {
+            local: rc::Rc::downgrade(&local),
+            shared: sync::Arc::downgrade(&shared),
+        };
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &nodes).unwrap();
+        let decoded: (u32, String) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, (42, "shared".to_string()));
+
+        // Dangling references can't be serialized.
+        drop(local);
+        assert!(to_bytes(ctxt, &nodes).is_err());
+        let local = rc::Rc::new(42u32);
+        let nodes = Nodes {
+            local: rc::Rc::downgrade(&local),
+            shared: sync::Arc::downgrade(&sync::Arc::<str>::from("dropped")),
+        };
+        assert!(to_bytes(ctxt, &nodes).is_err());
+    }
+
+    #[cfg(feature = "enumflags2")]
+    #[test]
+    fn bitflags() {
+        use enumflags2::{bitflags, BitFlags};
+
+        #[bitflags]
+        #[repr(u32)]
+        #[derive(Copy, Clone, Debug, PartialEq, Type)]
+        enum Capability {
+            Read = 0x1,
+            Write = 0x2,
+            Execute = 0x8,
+        }
+
+        assert_eq!(BitFlags::<Capability>::signature(), "u");
+
+        // Encoded as the underlying integer.
+        let ctxt = Context::new_dbus(LE, 0);
+        let flags = Capability::Read | Capability::Execute;
+        let encoded = to_bytes(ctxt, &flags).unwrap();
+        assert_eq!(encoded.bytes(), to_bytes(ctxt, &0x9u32).unwrap().bytes());
+        let decoded: BitFlags<Capability> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, flags);
+
+        // Unknown bits are rejected.
+        let encoded = to_bytes(ctxt, &0x5u32).unwrap();
+        assert!(encoded.deserialize::<BitFlags<Capability>>().is_err());
+    }
+
+    #[test]
+    fn c_string() {
+        use std::ffi::{CStr, CString};
+
+        assert_eq!(<&CStr>::signature(), "s");
+        assert_eq!(CString::signature(), "s");
+        assert_eq!(<(CString, u32)>::signature(), "(su)");
+
+        // Encoded as the string, without the trailing nul.
+        let ctxt = Context::new_dbus(LE, 0);
+        let c_string = CString::new("hello").unwrap();
+        let encoded = to_bytes(ctxt, &(&c_string, 42u32)).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            to_bytes(ctxt, &("hello", 42u32)).unwrap().bytes()
+        );
+        let decoded: (CString, u32) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, (c_string.clone(), 42));
+        let decoded: Box<CStr> = to_bytes(ctxt, c_string.as_c_str())
+            .unwrap()
+            .deserialize()
+            .unwrap()
+            .0;
+        assert_eq!(&*decoded, c_string.as_c_str());
+
+        #[cfg(feature = "gvariant")]
+        {
+            let ctxt = Context::new_gvariant(LE, 0);
+            let encoded = to_bytes(ctxt, &c_string).unwrap();
+            assert_eq!(encoded.bytes(), b"hello\0");
+            let decoded: CString = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, c_string);
+        }
+
+        // Only valid UTF-8 can be encoded as a string.
+        let non_utf8 = CString::new(b"\xffhello".to_vec()).unwrap();
+        let err = to_bytes(ctxt, &non_utf8).unwrap_err();
+        assert!(matches!(err, Error::Utf8(_)));
+
+        // A string with an interior nul can't be decoded as a C string. Such a string can't be
+        // encoded either, so write its bytes out by hand.
+        let encoded = Data::new(&b"\x06\0\0\0hel\0lo\0"[..], ctxt);
+        encoded.deserialize::<CString>().unwrap_err();
+        encoded.deserialize::<Box<CStr>>().unwrap_err();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn os_string() {
+        use std::{
+            borrow::Cow,
+            ffi::{OsStr, OsString},
+            os::unix::ffi::OsStrExt,
+        };
+
+        assert_eq!(<&OsStr>::signature(), "s");
+        assert_eq!(OsString::signature(), "s");
+        assert_eq!(<Cow<'_, OsStr>>::signature(), "s");
+        assert_eq!(<(Cow<'_, OsStr>, u32)>::signature(), "(su)");
+
+        let s = OsStr::new("/home/user/Téléchargements");
+        let borrowed: Cow<'_, OsStr> = Cow::Borrowed(s);
+        let owned: Cow<'_, OsStr> = Cow::Owned(s.to_os_string());
+        for ctxt in [
+            Context::new_dbus(LE, 0),
+            Context::new_dbus(BE, 0),
+            #[cfg(feature = "gvariant")]
+            Context::new_gvariant(LE, 0),
+        ] {
+            let encoded = to_bytes(ctxt, &(&borrowed, 7u32)).unwrap();
+            // Same encoding as the string, for both borrowed and owned variants.
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &("/home/user/Téléchargements", 7u32))
+                    .unwrap()
+                    .bytes()
+            );
+            assert_eq!(
+                to_bytes(ctxt, &(&owned, 7u32)).unwrap().bytes(),
+                encoded.bytes()
+            );
+
+            let (decoded, n): (Cow<'_, OsStr>, u32) = encoded.deserialize().unwrap().0;
+            assert!(matches!(decoded, Cow::Owned(_)));
+            assert_eq!(decoded, s);
+            assert_eq!(n, 7);
+            let decoded: (OsString, u32) = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, (s.to_os_string(), 7));
+        }
+
+        // Only valid UTF-8 can be encoded as a string.
+        let ctxt = Context::new_dbus(LE, 0);
+        let non_utf8 = OsStr::from_bytes(b"caf\xe9");
+        let err = to_bytes(ctxt, &non_utf8).unwrap_err();
+        assert!(matches!(err, Error::Utf8(_)));
+        let err = to_bytes(ctxt, &Cow::Borrowed(non_utf8)).unwrap_err();
+        assert!(matches!(err, Error::Utf8(_)));
+        let err = to_bytes(ctxt, &non_utf8.to_os_string()).unwrap_err();
+        assert!(matches!(err, Error::Utf8(_)));
+    }
+
     #[test]
     fn struct_with_hashmap() {
         use serde::{Deserialize, Serialize};
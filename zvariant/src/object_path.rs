@@ -396,4 +396,52 @@ mod unit {
         let json_str = "\"/some/path\"";
         serde_json::de::from_reader::<_, OwnedObjectPath>(json_str.as_bytes()).unwrap();
     }
+
+    #[test]
+    fn basic() {
+        assert_eq!(ObjectPath::SIGNATURE_CHAR, 'o');
+        assert_eq!(ObjectPath::SIGNATURE_STR, "o");
+        assert_eq!(OwnedObjectPath::SIGNATURE_CHAR, 'o');
+        assert_eq!(ObjectPath::signature(), "o");
+
+        assert_eq!(ObjectPath::alignment(Format::DBus), 4);
+        assert_eq!(
+            ObjectPath::alignment(Format::DBus),
+            <&str>::alignment(Format::DBus)
+        );
+        assert_eq!(OwnedObjectPath::alignment(Format::DBus), 4);
+        #[cfg(feature = "gvariant")]
+        {
+            assert_eq!(ObjectPath::alignment(Format::GVariant), 1);
+            assert_eq!(
+                ObjectPath::alignment(Format::GVariant),
+                <&str>::alignment(Format::GVariant)
+            );
+            assert_eq!(OwnedObjectPath::alignment(Format::GVariant), 1);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for path in [
+            "",
+            "no/leading/slash",
+            "/trailing/",
+            "/double//slash",
+            "/in-valid",
+        ] {
+            assert!(
+                ObjectPath::try_from(path).is_err(),
+                "`{path}` should be invalid"
+            );
+            OwnedObjectPath::try_from(path).unwrap_err();
+        }
+
+        // Also rejected on deserialization.
+        let ctxt = crate::serialized::Context::new_dbus(crate::LE, 0);
+        let encoded = crate::to_bytes(ctxt, &"/in-valid").unwrap();
+        encoded
+            .deserialize_for_signature::<_, ObjectPath<'_>>("o")
+            .unwrap_err();
+    }
 }
@@ -0,0 +1,186 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{ser::SerializeTuple, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Signature, Type};
+
+/// An optional value, encoded as a `(bT)` structure of a presence flag and the value.
+///
+/// D-Bus doesn't have maybe types, so `Option<T>` can't be used with the D-Bus format, unless the
+/// `option-as-array` feature is enabled. [`Optional`] can be used if the value has a special value
+/// meaning none, e.g an empty string. Otherwise, this type can be used instead: it encodes the
+/// value in a structure, after a `b` flag telling whether it's present.
+///
+/// Since there's always a value slot in the structure, it's filled with the [`Default`] value of
+/// `T` when the value is absent, and the value slot is ignored on deserialization if the flag is
+/// `false`.
+///
+/// # Encodings
+///
+/// The signature of a type can't depend on the format, so this type is encoded as a `(bT)`
+/// structure in the GVariant format as well. For peers using GVariant's native maybe type (`mT`),
+/// use `Option<T>` directly instead, which is encoded as such in the GVariant format:
+///
+/// | Type                | D-Bus                     | GVariant |
+/// | ---                 | ---                       | ---      |
+/// | `OptionalStruct<T>` | `(bT)`                    | `(bT)`   |
+/// | `Option<T>`         | unsupported (or `aT`[^1]) | `mT`     |
+///
+/// [^1]: with the `option-as-array` feature.
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::{serialized::Context, to_bytes, OptionalStruct, Type, LE};
+///
+/// assert_eq!(OptionalStruct::<u32>::signature(), "(bu)");
+///
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let encoded = to_bytes(ctxt, &OptionalStruct::from(Some(42u32))).unwrap();
+/// assert_eq!(encoded.bytes(), &[1, 0, 0, 0, 42, 0, 0, 0]);
+/// let decoded: OptionalStruct<u32> = encoded.deserialize().unwrap().0;
+/// assert_eq!(*decoded, Some(42));
+///
+/// // The value slot is always written, here with the default `u32`.
+/// let encoded = to_bytes(ctxt, &OptionalStruct::<u32>::default()).unwrap();
+/// assert_eq!(encoded.bytes(), &[0, 0, 0, 0, 0, 0, 0, 0]);
+/// let decoded: OptionalStruct<u32> = encoded.deserialize().unwrap().0;
+/// assert_eq!(*decoded, None);
+/// ```
+///
+/// [`Optional`]: crate::Optional
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OptionalStruct<T>(Option<T>);
+
+impl<T> Type for OptionalStruct<T>
+where
+    T: Type,
+{
+    fn signature() -> Signature<'static> {
+        <(bool, T)>::signature()
+    }
+}
+
+impl<T> Serialize for OptionalStruct<T>
+where
+    T: Default + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&self.0.is_some())?;
+        match &self.0 {
+            Some(value) => tuple.serialize_element(value)?,
+            None => tuple.serialize_element(&T::default())?,
+        }
+
+        tuple.end()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for OptionalStruct<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (present, value) = <(bool, T)>::deserialize(deserializer)?;
+
+        Ok(Self(present.then_some(value)))
+    }
+}
+
+impl<T> From<Option<T>> for OptionalStruct<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<OptionalStruct<T>> for Option<T> {
+    fn from(value: OptionalStruct<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> Deref for OptionalStruct<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for OptionalStruct<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> Default for OptionalStruct<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{serialized::Context, to_bytes, OptionalStruct, Type, LE};
+
+    #[test]
+    fn optional_struct() {
+        assert_eq!(OptionalStruct::<String>::signature(), "(bs)");
+        assert_eq!(OptionalStruct::<(u8, i64)>::signature(), "(b(yx))");
+
+        let check = |ctxt: Context| {
+            let some = OptionalStruct::from(Some("hello".to_string()));
+            let encoded = to_bytes(ctxt, &some).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &(true, "hello")).unwrap().bytes()
+            );
+            let decoded: OptionalStruct<String> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, some);
+
+            // The value slot of an absent value has the default value.
+            let none = OptionalStruct::<String>::default();
+            let encoded = to_bytes(ctxt, &none).unwrap();
+            assert_eq!(
+                encoded.bytes(),
+                to_bytes(ctxt, &(false, "")).unwrap().bytes()
+            );
+            let decoded: OptionalStruct<String> = encoded.deserialize().unwrap().0;
+            assert_eq!(Option::from(decoded), None::<String>);
+
+            // Whatever the value slot holds, it's ignored if the flag isn't set.
+            let encoded = to_bytes(ctxt, &(false, "ignored")).unwrap();
+            let decoded: OptionalStruct<String> = encoded.deserialize().unwrap().0;
+            assert_eq!(*decoded, None);
+        };
+        check(Context::new_dbus(LE, 0));
+        #[cfg(feature = "gvariant")]
+        check(Context::new_gvariant(LE, 0));
+    }
+
+    #[cfg(all(feature = "gvariant", not(feature = "option-as-array")))]
+    #[test]
+    fn optional_struct_vs_gvariant_maybe() {
+        let ctxt = Context::new_gvariant(LE, 0);
+
+        // `Option<T>` uses the native maybe type of GVariant, so it's encoded differently.
+        assert_eq!(Option::<u32>::signature(), "mu");
+        for value in [Some(42u32), None] {
+            let maybe = to_bytes(ctxt, &value).unwrap();
+            let decoded: Option<u32> = maybe.deserialize().unwrap().0;
+            assert_eq!(decoded, value);
+
+            let optional = to_bytes(ctxt, &OptionalStruct::from(value)).unwrap();
+            assert_ne!(maybe.bytes(), optional.bytes());
+            let decoded: OptionalStruct<u32> = optional.deserialize().unwrap().0;
+            assert_eq!(*decoded, value);
+        }
+    }
+}
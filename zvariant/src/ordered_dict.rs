@@ -0,0 +1,188 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Basic, Signature, Type};
+
+/// An ordered list of key-value pairs, encoded as a D-Bus dictionary.
+///
+/// Maps like [`HashMap`] and [`BTreeMap`] are encoded as an array of dict entries (`a{kv}`) but
+/// have their own ordering of the entries. This type keeps the entries in the order given, both
+/// when serializing and when deserializing, where they're kept in the order they're on the wire.
+/// Keys are not checked for uniqueness.
+///
+/// Not to be confused with [`Dict`](crate::Dict), the dictionary variant of [`Value`].
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::{serialized::Context, to_bytes, OrderedDict, Type, LE};
+///
+/// let dict = OrderedDict::from(vec![("z", 1u32), ("a", 2)]);
+/// assert_eq!(<OrderedDict<&str, u32>>::signature(), "a{su}");
+///
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let encoded = to_bytes(ctxt, &dict).unwrap();
+/// let decoded: OrderedDict<String, u32> = encoded.deserialize().unwrap().0;
+/// assert_eq!(decoded.into_inner(), vec![("z".to_string(), 1), ("a".to_string(), 2)]);
+/// ```
+///
+/// [`HashMap`]: std::collections::HashMap
+/// [`BTreeMap`]: std::collections::BTreeMap
+/// [`Value`]: enum@crate::Value
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OrderedDict<K, V>(Vec<(K, V)>);
+
+impl<K, V> OrderedDict<K, V> {
+    /// Create a new empty `OrderedDict`.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Append an entry.
+    pub fn push(&mut self, key: K, value: V) {
+        self.0.push((key, value));
+    }
+
+    /// The entries, in order.
+    pub fn entries(&self) -> &[(K, V)] {
+        &self.0
+    }
+
+    /// Get the inner vector of entries.
+    pub fn into_inner(self) -> Vec<(K, V)> {
+        self.0
+    }
+}
+
+impl<K, V> From<Vec<(K, V)>> for OrderedDict<K, V> {
+    fn from(entries: Vec<(K, V)>) -> Self {
+        Self(entries)
+    }
+}
+
+impl<K, V> From<OrderedDict<K, V>> for Vec<(K, V)> {
+    fn from(dict: OrderedDict<K, V>) -> Self {
+        dict.0
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for OrderedDict<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<K, V> IntoIterator for OrderedDict<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<K, V> Type for OrderedDict<K, V>
+where
+    K: Basic,
+    V: Type,
+{
+    fn signature() -> Signature<'static> {
+        Signature::from_string_unchecked(format!("a{{{}{}}}", K::signature(), V::signature()))
+    }
+}
+
+impl<K, V> Serialize for OrderedDict<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+
+        map.end()
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for OrderedDict<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(OrderedDictVisitor(PhantomData))
+    }
+}
+
+struct OrderedDictVisitor<K, V>(PhantomData<(K, V)>);
+
+impl<'de, K, V> Visitor<'de> for OrderedDictVisitor<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = OrderedDict<K, V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a dictionary")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(OrderedDict(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{serialized::Context, to_bytes, OrderedDict, Type, LE};
+
+    #[test]
+    fn ordered_dict() {
+        let dict: OrderedDict<String, i32> = vec![
+            ("zebra".to_string(), 3),
+            ("apple".to_string(), -1),
+            ("mango".to_string(), 42),
+        ]
+        .into();
+        assert_eq!(<OrderedDict<String, i32>>::signature(), "a{si}");
+
+        let check = |ctxt: Context| {
+            let encoded = to_bytes(ctxt, &dict).unwrap();
+            let (decoded, parsed): (OrderedDict<String, i32>, _) = encoded.deserialize().unwrap();
+            assert_eq!(parsed, encoded.len());
+            // The wire order is kept.
+            assert_eq!(decoded, dict);
+
+            // Same encoding as a map, so it's interchangeable with one.
+            let map: HashMap<String, i32> = encoded.deserialize().unwrap().0;
+            assert_eq!(map.len(), 3);
+            assert_eq!(map["mango"], 42);
+        };
+        check(Context::new_dbus(LE, 0));
+        #[cfg(feature = "gvariant")]
+        check(Context::new_gvariant(LE, 0));
+    }
+}
@@ -0,0 +1,241 @@
+//! Support for encoding [`OsStr`] and [`OsString`] as D-Bus strings, on Unix.
+//!
+//! Serde (de)serializes OS strings as an `OsString` enum, with the raw bytes of the string in its
+//! `Unix` variant (variant 0) on Unix. Our (de)serializers encode them as plain strings instead,
+//! when the signature asks for one, using the helpers here. Serialization fails if the string
+//! isn't valid UTF-8.
+//!
+//! [`OsStr`]: std::ffi::OsStr
+//! [`OsString`]: std::ffi::OsString
+
+use serde::{
+    de::{
+        self,
+        value::{SeqDeserializer, U32Deserializer},
+        DeserializeSeed, EnumAccess, VariantAccess, Visitor,
+    },
+    ser::{Impossible, Serialize, SerializeSeq, Serializer},
+};
+
+use crate::{Error, Result};
+
+/// The name serde gives to the OS string enum.
+pub(crate) const OS_STRING_ENUM_NAME: &str = "OsString";
+
+// The index of the `Unix` variant of the OS string enum.
+const UNIX_VARIANT_INDEX: u32 = 0;
+
+/// The UTF-8 string of a serialized OS string, given the variant it's serialized as.
+pub(crate) fn os_str_to_string<T>(variant_index: u32, value: &T) -> Result<String>
+where
+    T: ?Sized + Serialize,
+{
+    if variant_index != UNIX_VARIANT_INDEX {
+        return Err(Error::Message(
+            "only Unix OS strings can be encoded as strings".into(),
+        ));
+    }
+    let bytes = value.serialize(OsBytes(Vec::new()))?;
+
+    String::from_utf8(bytes).map_err(|e| Error::Utf8(e.utf8_error()))
+}
+
+/// Hands a deserialized string to the visitor of the serde implementation of OS strings, as the
+/// bytes of its `Unix` variant.
+pub(crate) struct OsStringAccess(pub(crate) String);
+
+impl<'de> EnumAccess<'de> for OsStringAccess {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<S>(self, seed: S) -> Result<(S::Value, Self)>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let index = U32Deserializer::<Error>::new(UNIX_VARIANT_INDEX);
+
+        seed.deserialize(index).map(|v| (v, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for OsStringAccess {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Err(de::Error::invalid_type(
+            de::Unexpected::UnitVariant,
+            &"an OS string",
+        ))
+    }
+
+    fn newtype_variant_seed<S>(self, seed: S) -> Result<S::Value>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        // `SeqDeserializer` checks that every byte was consumed once the seed returns.
+        seed.deserialize(SeqDeserializer::<_, Error>::new(
+            self.0.into_bytes().into_iter(),
+        ))
+    }
+
+    fn tuple_variant<V>(self, _len: usize, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::invalid_type(
+            de::Unexpected::TupleVariant,
+            &"an OS string",
+        ))
+    }
+
+    fn struct_variant<V>(self, _fields: &'static [&'static str], _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::invalid_type(
+            de::Unexpected::StructVariant,
+            &"an OS string",
+        ))
+    }
+}
+
+// Captures the bytes of an OS string, serialized as a sequence of `u8`.
+struct OsBytes(Vec<u8>);
+
+// Captures a single byte.
+struct OsByte;
+
+macro_rules! unexpected {
+    ($($method:ident($($arg:ty),*) -> $ok:ty),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ok> {
+                Err(unexpected_error())
+            }
+        )*
+    };
+}
+
+fn unexpected_error() -> Error {
+    Error::Message("unexpected OS string encoding".into())
+}
+
+macro_rules! os_serializer {
+    ($ty:ty, $ok:ty, $seq:ty { $($method:ident($arg:ty))* } $($impls:tt)*) => {
+        impl Serializer for $ty {
+            type Ok = $ok;
+            type Error = Error;
+            type SerializeSeq = $seq;
+            type SerializeTuple = Impossible<$ok, Error>;
+            type SerializeTupleStruct = Impossible<$ok, Error>;
+            type SerializeTupleVariant = Impossible<$ok, Error>;
+            type SerializeMap = Impossible<$ok, Error>;
+            type SerializeStruct = Impossible<$ok, Error>;
+            type SerializeStructVariant = Impossible<$ok, Error>;
+
+            unexpected! {
+                $($method($arg) -> $ok,)*
+                serialize_bool(bool) -> $ok,
+                serialize_i8(i8) -> $ok,
+                serialize_i16(i16) -> $ok,
+                serialize_i32(i32) -> $ok,
+                serialize_i64(i64) -> $ok,
+                serialize_u16(u16) -> $ok,
+                serialize_u32(u32) -> $ok,
+                serialize_u64(u64) -> $ok,
+                serialize_f32(f32) -> $ok,
+                serialize_f64(f64) -> $ok,
+                serialize_char(char) -> $ok,
+                serialize_str(&str) -> $ok,
+                serialize_none() -> $ok,
+                serialize_unit() -> $ok,
+                serialize_unit_struct(&'static str) -> $ok,
+                serialize_unit_variant(&'static str, u32, &'static str) -> $ok,
+                serialize_tuple(usize) -> Self::SerializeTuple,
+                serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct,
+                serialize_tuple_variant(&'static str, u32, &'static str, usize)
+                    -> Self::SerializeTupleVariant,
+                serialize_map(Option<usize>) -> Self::SerializeMap,
+                serialize_struct(&'static str, usize) -> Self::SerializeStruct,
+                serialize_struct_variant(&'static str, u32, &'static str, usize)
+                    -> Self::SerializeStructVariant,
+            }
+
+            fn serialize_some<T>(self, _value: &T) -> Result<$ok>
+            where
+                T: ?Sized + Serialize,
+            {
+                Err(unexpected_error())
+            }
+
+            fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<$ok>
+            where
+                T: ?Sized + Serialize,
+            {
+                value.serialize(self)
+            }
+
+            fn serialize_newtype_variant<T>(
+                self,
+                _name: &'static str,
+                _variant_index: u32,
+                _variant: &'static str,
+                _value: &T,
+            ) -> Result<$ok>
+            where
+                T: ?Sized + Serialize,
+            {
+                Err(unexpected_error())
+            }
+
+            fn is_human_readable(&self) -> bool {
+                false
+            }
+
+            $($impls)*
+        }
+    };
+}
+
+os_serializer! {
+    OsBytes, Vec<u8>, Self { serialize_u8(u8) }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Vec<u8>> {
+        Ok(v.to_vec())
+    }
+
+    fn serialize_seq(mut self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        self.0.reserve(len.unwrap_or(0));
+
+        Ok(self)
+    }
+}
+
+os_serializer! {
+    OsByte, u8, Impossible<u8, Error> { serialize_bytes(&[u8]) }
+
+    fn serialize_u8(self, v: u8) -> Result<u8> {
+        Ok(v)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(unexpected_error())
+    }
+}
+
+impl SerializeSeq for OsBytes {
+    type Ok = Vec<u8>;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.push(value.serialize(OsByte)?);
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Vec<u8>> {
+        Ok(self.0)
+    }
+}
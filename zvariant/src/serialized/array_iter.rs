@@ -0,0 +1,59 @@
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+
+use crate::{serialized::Data, Result, Type};
+
+/// An iterator over the elements of a serialized array, deserializing them on demand.
+///
+/// This is created by [`Data::array_iter`]. The iterator keeps the underlying serialized data
+/// alive, until it's dropped.
+#[derive(Debug, Clone)]
+pub struct ArrayIter<'bytes, 'fds, T> {
+    // The serialized elements, and nothing more.
+    elements: Data<'bytes, 'fds>,
+    pos: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'bytes, 'fds, T> ArrayIter<'bytes, 'fds, T> {
+    pub(super) fn new(elements: Data<'bytes, 'fds>) -> Self {
+        Self {
+            elements,
+            pos: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'bytes, 'fds, T> Iterator for ArrayIter<'bytes, 'fds, T>
+where
+    T: DeserializeOwned + Type,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.elements.len() {
+            return None;
+        }
+
+        match self.elements.slice(self.pos..).deserialize() {
+            Ok((element, parsed)) => {
+                self.pos += parsed;
+
+                Some(Ok(element))
+            }
+            Err(e) => {
+                // Without the element's size, we can't go on.
+                self.pos = self.elements.len();
+
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<'bytes, 'fds, T> std::iter::FusedIterator for ArrayIter<'bytes, 'fds, T> where
+    T: DeserializeOwned + Type
+{
+}
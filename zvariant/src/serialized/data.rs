@@ -6,11 +6,15 @@ use std::{
     sync::Arc,
 };
 
-use serde::{de::DeserializeSeed, Deserialize};
+use serde::{
+    de::{DeserializeOwned, DeserializeSeed},
+    Deserialize,
+};
 
 use crate::{
     de::Deserializer,
-    serialized::{Context, Format},
+    serialized::{ArrayIter, Context, Format},
+    utils::{alignment_for_signature, padding_for_n_bytes},
     DynamicDeserialize, DynamicType, Error, Result, Signature, Type,
 };
 
@@ -147,6 +151,88 @@ impl<'bytes, 'fds> Data<'bytes, 'fds> {
         self.deserialize_for_signature(&signature)
     }
 
+    /// Deserialize `T` from `self`, without borrowing from `self`.
+    ///
+    /// This is the same as [`Data::deserialize`] but for types that own all their data, such as
+    /// `Vec<String>`. Since the returned value is not tied to the lifetime of `self`, it's
+    /// useful in generic code, where a `Deserialize<'d>` bound would require `self` to outlive the
+    /// deserialized value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde::de::DeserializeOwned;
+    /// use zvariant::{serialized::{Context, Data}, to_bytes, Type, LE};
+    ///
+    /// fn decode<T>(bytes: &[u8]) -> zvariant::Result<T>
+    /// where
+    ///     T: DeserializeOwned + Type,
+    /// {
+    ///     let data = Data::new(bytes, Context::new_dbus(LE, 0));
+    ///
+    ///     data.deserialize_owned().map(|(value, _)| value)
+    /// }
+    ///
+    /// let ctxt = Context::new_dbus(LE, 0);
+    /// let encoded = to_bytes(ctxt, &vec!["hello", "world"]).unwrap();
+    /// let decoded: Vec<String> = decode(encoded.bytes()).unwrap();
+    /// assert_eq!(decoded, ["hello", "world"]);
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A tuple containing the deserialized value and the number of bytes parsed from `bytes`.
+    pub fn deserialize_owned<T>(&self) -> Result<(T, usize)>
+    where
+        T: DeserializeOwned + Type,
+    {
+        self.deserialize()
+    }
+
+    /// Iterate over the elements of the array of `T` that `self` starts with.
+    ///
+    /// Unlike deserializing a `Vec<T>`, elements are only deserialized as the returned iterator
+    /// is advanced. This is useful for large arrays, when not all the elements are needed at the
+    /// same time. An element that fails to deserialize ends the iteration, since the position of
+    /// the following ones can't be known.
+    ///
+    /// This is only supported for the D-Bus format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::{serialized::Context, to_bytes, LE};
+    ///
+    /// let ctxt = Context::new_dbus(LE, 0);
+    /// let encoded = to_bytes(ctxt, &vec![1u64, 2, 3]).unwrap();
+    /// let mut iter = encoded.array_iter::<u64>().unwrap();
+    /// assert_eq!(iter.next().unwrap().unwrap(), 1);
+    /// assert_eq!(iter.map(Result::unwrap).sum::<u64>(), 5);
+    /// ```
+    pub fn array_iter<T>(&self) -> Result<ArrayIter<'bytes, 'fds, T>>
+    where
+        T: DeserializeOwned + Type,
+    {
+        let format = self.context.format();
+        if format != Format::DBus {
+            return Err(Error::IncompatibleFormat(<Vec<T>>::signature(), format));
+        }
+
+        let (len, parsed) = self.deserialize::<u32>()?;
+        // Padding for the first element is present, even if the array is empty.
+        let alignment = alignment_for_signature(&T::signature(), format)?;
+        let start = parsed + padding_for_n_bytes(self.context.position() + parsed, alignment);
+        let end = start + len as usize;
+        if end > self.len() {
+            return Err(Error::OutOfBounds);
+        }
+        if let Some(byte) = self.bytes()[parsed..start].iter().find(|byte| **byte != 0) {
+            return Err(Error::PaddingNot0(*byte));
+        }
+
+        Ok(ArrayIter::new(self.slice(start..end)))
+    }
+
     /// Deserialize `T` from `self` with the given signature.
     ///
     /// Use this method instead of [`Data::deserialize`] if the value being deserialized does not
@@ -1,5 +1,7 @@
 mod data;
 pub use data::Data;
+mod array_iter;
+pub use array_iter::ArrayIter;
 mod size;
 pub use size::Size;
 mod written;
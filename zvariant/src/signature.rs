@@ -122,6 +122,20 @@ impl Hash for Bytes<'_> {
 /// This is implemented so that multiple instances can share the same underlying signature string.
 /// Use [`slice`] method to create new signature that represents a portion of a signature
 ///
+/// Note that D-Bus and GVariant have no 8-bit signed integer and no 32-bit floating point types. So
+/// [`i8`] and [`f32`] are encoded as [`i16`] (`n`) and [`f64`] (`d`) respectively, and their
+/// [`Type::signature`](crate::Type::signature) is the one of the type they're encoded as. When
+/// validating a received signature before deserializing it into a type `T`, compare it against
+/// `T::signature()` rather than a signature you built by hand:
+///
+/// ```
+/// use zvariant::{Signature, Type};
+///
+/// let received = Signature::try_from("(nd)").unwrap();
+/// assert_eq!(received, <(i8, f32)>::signature());
+/// assert_ne!(received, "(yd)");
+/// ```
+///
 /// [identifies]: https://dbus.freedesktop.org/doc/dbus-specification.html#type-system
 /// [`slice`]: #method.slice
 #[derive(Hash, Clone, PartialOrd, Ord)]
@@ -336,6 +350,121 @@ impl<'a> Signature<'a> {
         }
         Ok(count)
     }
+
+    /// The maximum nesting depth of containers in the signature.
+    ///
+    /// Each array, structure, dictionary entry and maybe type adds a level to the depth of the types
+    /// it contains, so a signature made of only basic types and variants has a depth of `0`.
+    ///
+    /// Note that the D-Bus specification limits the nesting of arrays and of structures to 32
+    /// levels each, separately, so a signature of a depth over 32 can still be valid. A depth of at
+    /// most 32 is within both limits though, which the signature validation doesn't enforce.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::Signature;
+    ///
+    /// assert_eq!(Signature::try_from("yus").unwrap().depth(), 0);
+    /// assert_eq!(Signature::try_from("a{sv}").unwrap().depth(), 2);
+    /// assert_eq!(Signature::try_from("(aas(u))").unwrap().depth(), 3);
+    /// ```
+    pub fn depth(&self) -> usize {
+        let bytes = self.as_bytes();
+        let mut pos = 0;
+        let mut depth = 0;
+        while pos < bytes.len() {
+            depth = depth.max(complete_type_depth(bytes, &mut pos));
+        }
+
+        depth
+    }
+
+    /// A [`Display`] implementation that separates the complete types with spaces.
+    ///
+    /// This is meant for humans reading complex signatures, e.g in logs. The [`Display`]
+    /// implementation of `Signature` itself always writes the exact signature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::Signature;
+    ///
+    /// let sig = Signature::try_from("sa{sv}(ua(yy))").unwrap();
+    /// assert_eq!(sig.pretty().to_string(), "s a{s v} (u a(y y))");
+    /// assert_eq!(sig.to_string(), "sa{sv}(ua(yy))");
+    /// ```
+    pub fn pretty(&self) -> PrettySignature<'_> {
+        PrettySignature(self.as_ref())
+    }
+}
+
+// The depth of the complete type starting at `pos`, which is advanced past it.
+fn complete_type_depth(bytes: &[u8], pos: &mut usize) -> usize {
+    let c = bytes[*pos];
+    *pos += 1;
+    match c {
+        b'a' | b'm' if *pos < bytes.len() => 1 + complete_type_depth(bytes, pos),
+        b'(' | b'{' => {
+            let mut depth = 0;
+            while *pos < bytes.len() && !matches!(bytes[*pos], b')' | b'}') {
+                depth = depth.max(complete_type_depth(bytes, pos));
+            }
+            // Skip the closing character.
+            *pos += 1;
+
+            1 + depth
+        }
+        _ => 0,
+    }
+}
+
+// Writes the complete type starting at `pos`, which is advanced past it.
+fn fmt_pretty_complete_type(bytes: &[u8], pos: &mut usize, f: &mut Formatter<'_>) -> fmt::Result {
+    let c = bytes[*pos];
+    *pos += 1;
+    write!(f, "{}", c as char)?;
+    match c {
+        b'a' | b'm' if *pos < bytes.len() => fmt_pretty_complete_type(bytes, pos, f),
+        b'(' | b'{' => {
+            let mut first = true;
+            while *pos < bytes.len() && !matches!(bytes[*pos], b')' | b'}') {
+                if !first {
+                    f.write_str(" ")?;
+                }
+                first = false;
+                fmt_pretty_complete_type(bytes, pos, f)?;
+            }
+            if let Some(close) = bytes.get(*pos) {
+                *pos += 1;
+                write!(f, "{}", *close as char)?;
+            }
+
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// A [`Display`] wrapper for [`Signature`], that makes it easier to read.
+///
+/// This is created by [`Signature::pretty`].
+#[derive(Debug, Clone)]
+pub struct PrettySignature<'s>(Signature<'s>);
+
+impl Display for PrettySignature<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let bytes = self.0.as_bytes();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            if pos > 0 {
+                f.write_str(" ")?;
+            }
+            fmt_pretty_complete_type(bytes, &mut pos, f)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> Debug for Signature<'a> {
@@ -580,8 +709,65 @@ impl std::fmt::Display for OwnedSignature {
 #[cfg(test)]
 mod tests {
     use super::{Bytes, Signature};
+    use crate::{
+        serialized::{Context, Format},
+        to_bytes, to_bytes_for_signature, Basic, Error, OwnedSignature, Type, LE,
+    };
     use std::sync::Arc;
 
+    #[test]
+    fn basic() {
+        assert_eq!(Signature::SIGNATURE_CHAR, 'g');
+        assert_eq!(Signature::SIGNATURE_STR, "g");
+        assert_eq!(OwnedSignature::SIGNATURE_CHAR, 'g');
+
+        assert_eq!(Signature::alignment(Format::DBus), 1);
+        assert_eq!(OwnedSignature::alignment(Format::DBus), 1);
+        #[cfg(feature = "gvariant")]
+        {
+            assert_eq!(Signature::alignment(Format::GVariant), 1);
+            assert_eq!(OwnedSignature::alignment(Format::GVariant), 1);
+        }
+    }
+
+    #[test]
+    fn encoding() {
+        let ctxt = Context::new_dbus(LE, 0);
+
+        // Single byte length prefix, unlike strings.
+        let sig = Signature::try_from("a{sv}").unwrap();
+        let encoded = to_bytes(ctxt, &sig).unwrap();
+        assert_eq!(encoded.bytes(), b"\x05a{sv}\0");
+        let decoded: Signature<'_> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, sig);
+
+        // Maximum length.
+        let max = "y".repeat(255);
+        let sig = Signature::try_from(max.as_str()).unwrap();
+        let encoded = to_bytes(ctxt, &sig).unwrap();
+        assert_eq!(encoded.len(), 257);
+        let decoded: Signature<'_> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, sig);
+
+        // Over-long signatures are rejected and can't be encoded.
+        let too_long = "y".repeat(256);
+        Signature::try_from(too_long.as_str()).unwrap_err();
+        assert!(matches!(
+            to_bytes_for_signature(ctxt, "g", &too_long),
+            Err(Error::Message(_))
+        ));
+
+        // The grammar is validated on deserialization.
+        let encoded = to_bytes_for_signature(ctxt, "g", "az").unwrap();
+        encoded
+            .deserialize_for_signature::<_, Signature<'_>>("g")
+            .unwrap_err();
+        let encoded = to_bytes_for_signature(ctxt, "g", "(xs").unwrap();
+        encoded
+            .deserialize_for_signature::<_, Signature<'_>>("g")
+            .unwrap_err();
+    }
+
     #[test]
     fn bytes_equality() {
         let borrowed1 = Bytes::Borrowed(b"foo");
@@ -622,6 +808,91 @@ mod tests {
         assert_eq!(slice.slice(1..), "");
     }
 
+    #[test]
+    fn signature_depth() {
+        // Flat.
+        assert_eq!(Signature::from_static_str("").unwrap().depth(), 0);
+        assert_eq!(
+            Signature::from_static_str("ybnqiuxtdsogvh")
+                .unwrap()
+                .depth(),
+            0
+        );
+        assert_eq!(Signature::from_static_str("as").unwrap().depth(), 1);
+        assert_eq!(Signature::from_static_str("(ss)(uu)").unwrap().depth(), 1);
+
+        // Nested, where only the deepest branch counts.
+        let sig = Signature::from_static_str("a(sa{sv})").unwrap();
+        assert_eq!(sig.depth(), 4);
+        let sig = Signature::from_static_str("(y(y(y))y)a{s((u))}").unwrap();
+        assert_eq!(sig.depth(), 4);
+        let sig = Signature::from_static_str("((((((((((u))))))))))").unwrap();
+        assert_eq!(sig.depth(), 10);
+
+        // At the D-Bus limits, of 32 nested arrays and 32 nested structures.
+        let arrays = format!("{}y", "a".repeat(32));
+        assert_eq!(Signature::try_from(arrays.as_str()).unwrap().depth(), 32);
+        let structs = format!("{}y{}", "(".repeat(32), ")".repeat(32));
+        assert_eq!(Signature::try_from(structs.as_str()).unwrap().depth(), 32);
+        let mixed = format!("{}y{}", "a(".repeat(16), ")".repeat(16));
+        assert_eq!(Signature::try_from(mixed.as_str()).unwrap().depth(), 32);
+        // Both kinds count towards the depth, even though their limits are separate.
+        let over = format!("a{mixed}");
+        assert_eq!(Signature::try_from(over.as_str()).unwrap().depth(), 33);
+    }
+
+    #[test]
+    fn signature_pretty() {
+        let sig = Signature::from_static_str("ua{sv}a(ss)").unwrap();
+        assert_eq!(sig.pretty().to_string(), "u a{s v} a(s s)");
+        // `Display` stays exact.
+        assert_eq!(sig.to_string(), "ua{sv}a(ss)");
+
+        let sig = Signature::from_static_str("(y(ay(y))aay)").unwrap();
+        assert_eq!(sig.pretty().to_string(), "(y (ay (y)) aay)");
+
+        assert_eq!(
+            Signature::from_static_str("").unwrap().pretty().to_string(),
+            ""
+        );
+        assert_eq!(
+            Signature::from_static_str("s")
+                .unwrap()
+                .pretty()
+                .to_string(),
+            "s"
+        );
+    }
+
+    #[test]
+    fn signature_aliases() {
+        // `i8` is encoded as an `i16` and `f32` as an `f64`.
+        let sig = Signature::from_static_str_unchecked("n");
+        assert_eq!(sig, i8::signature());
+        assert_eq!(sig, i16::signature());
+        assert_ne!(sig, u16::signature());
+        let sig = Signature::from_static_str_unchecked("d");
+        assert_eq!(sig, f32::signature());
+        assert_eq!(sig, f64::signature());
+        let sig = Signature::from_static_str_unchecked("a{sd}");
+        assert_eq!(sig, std::collections::HashMap::<String, f32>::signature());
+        let sig = Signature::from_static_str_unchecked("(nd)");
+        assert_eq!(sig, <(i8, f32)>::signature());
+        assert_eq!(sig, <(i16, f64)>::signature());
+
+        // Genuine mismatches.
+        assert_ne!(Signature::from_static_str_unchecked("y"), i8::signature());
+        assert_ne!(Signature::from_static_str_unchecked("i"), f32::signature());
+        assert_ne!(
+            Signature::from_static_str_unchecked("(nd)"),
+            <(i8, f32, u8)>::signature()
+        );
+        assert_ne!(
+            Signature::from_static_str_unchecked("ay"),
+            Vec::<i8>::signature()
+        );
+    }
+
     #[test]
     fn signature_equality() {
         let sig_a = Signature::from_str_unchecked("(asta{sv})");
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Signature, Type};
+
+/// A signed time offset, in microseconds.
+///
+/// Some D-Bus APIs represent time deltas as a signed count of microseconds, encoded as an `x`
+/// (`i64`). Since [`Duration`] can't represent negative values, this type can be used for such
+/// APIs instead. It can be converted to and from a `(bool, Duration)` pair, where the `bool` is
+/// `true` if the offset is negative.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use zvariant::{serialized::Context, to_bytes, SignedMicros, Type, LE};
+///
+/// assert_eq!(SignedMicros::signature(), "x");
+///
+/// let offset = SignedMicros::try_from((true, Duration::from_millis(2))).unwrap();
+/// assert_eq!(offset.as_micros(), -2_000);
+///
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let encoded = to_bytes(ctxt, &offset).unwrap();
+/// assert_eq!(encoded.len(), 8);
+/// let decoded: SignedMicros = encoded.deserialize().unwrap().0;
+/// assert_eq!(decoded, offset);
+///
+/// let (negative, duration) = decoded.into();
+/// assert!(negative);
+/// assert_eq!(duration, Duration::from_millis(2));
+/// ```
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct SignedMicros(i64);
+
+impl SignedMicros {
+    /// Create a new `SignedMicros` from a signed count of microseconds.
+    pub fn new(micros: i64) -> Self {
+        Self(micros)
+    }
+
+    /// The signed count of microseconds.
+    pub fn as_micros(&self) -> i64 {
+        self.0
+    }
+
+    /// Whether the offset is negative.
+    pub fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+}
+
+impl Type for SignedMicros {
+    fn signature() -> Signature<'static> {
+        i64::signature()
+    }
+}
+
+impl From<i64> for SignedMicros {
+    fn from(micros: i64) -> Self {
+        Self(micros)
+    }
+}
+
+impl From<SignedMicros> for i64 {
+    fn from(value: SignedMicros) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<(bool, Duration)> for SignedMicros {
+    type Error = Error;
+
+    /// Fails with [`Error::OutOfBounds`] if the duration doesn't fit in an `i64` of microseconds.
+    fn try_from((negative, duration): (bool, Duration)) -> Result<Self, Self::Error> {
+        let micros = i128::try_from(duration.as_micros()).map_err(|_| Error::OutOfBounds)?;
+        let micros = if negative { -micros } else { micros };
+
+        i64::try_from(micros)
+            .map(Self)
+            .map_err(|_| Error::OutOfBounds)
+    }
+}
+
+impl From<SignedMicros> for (bool, Duration) {
+    fn from(value: SignedMicros) -> Self {
+        (
+            value.is_negative(),
+            Duration::from_micros(value.0.unsigned_abs()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{serialized::Context, to_bytes, Error, SignedMicros, Type, LE};
+
+    fn round_trip(negative: bool, duration: Duration, micros: i64) {
+        let offset = SignedMicros::try_from((negative, duration)).unwrap();
+        assert_eq!(offset.as_micros(), micros);
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &offset).unwrap();
+        assert_eq!(encoded.bytes(), micros.to_le_bytes());
+        let (decoded, parsed): (SignedMicros, _) = encoded.deserialize().unwrap();
+        assert_eq!(parsed, 8);
+        assert_eq!(decoded, offset);
+
+        assert_eq!(<(bool, Duration)>::from(decoded), (negative, duration));
+    }
+
+    #[test]
+    fn signed_micros() {
+        assert_eq!(SignedMicros::signature(), "x");
+
+        round_trip(false, Duration::from_millis(1500), 1_500_000);
+        round_trip(true, Duration::from_micros(42), -42);
+        round_trip(false, Duration::ZERO, 0);
+        // Zero is never negative.
+        assert_eq!(
+            <(bool, Duration)>::from(SignedMicros::try_from((true, Duration::ZERO)).unwrap()),
+            (false, Duration::ZERO),
+        );
+
+        assert_eq!(
+            SignedMicros::try_from((true, Duration::from_micros(i64::MIN.unsigned_abs())))
+                .unwrap()
+                .as_micros(),
+            i64::MIN,
+        );
+        assert!(matches!(
+            SignedMicros::try_from((false, Duration::from_micros(i64::MIN.unsigned_abs()))),
+            Err(Error::OutOfBounds),
+        ));
+        assert!(matches!(
+            SignedMicros::try_from((true, Duration::MAX)),
+            Err(Error::OutOfBounds),
+        ));
+    }
+}
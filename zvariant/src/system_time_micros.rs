@@ -0,0 +1,166 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{
+    de::{Deserializer, Error as _, Unexpected},
+    ser::{Error as _, Serializer},
+    Deserialize, Serialize,
+};
+
+use crate::{Signature, Type};
+
+/// A point in time, encoded as a `t` count of microseconds since the Unix epoch.
+///
+/// [`SystemTime`] itself is (de)serialized by serde as a struct of seconds and nanoseconds since
+/// the epoch. systemd however uses a count of microseconds of realtime clock instead, as a `t`
+/// (`u64`), for its timestamps (e.g the `*Timestamp` properties of its `Manager` and units), and
+/// this type can be used for such APIs. Precision below the microsecond is dropped on
+/// serialization.
+///
+/// Serialization fails if the time is before the epoch or too far after it to fit in a `u64` of
+/// microseconds. Deserialization fails if the time isn't representable by [`SystemTime`] on this
+/// platform.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use zvariant::{serialized::Context, to_bytes, SystemTimeMicros, Type, LE};
+///
+/// assert_eq!(SystemTimeMicros::signature(), "t");
+///
+/// let time = UNIX_EPOCH + Duration::from_micros(1_700_000_000_123_456);
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let encoded = to_bytes(ctxt, &SystemTimeMicros::from(time)).unwrap();
+/// assert_eq!(encoded.bytes(), 1_700_000_000_123_456u64.to_le_bytes());
+/// let decoded: SystemTimeMicros = encoded.deserialize().unwrap().0;
+/// assert_eq!(decoded.as_system_time(), time);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SystemTimeMicros(SystemTime);
+
+impl SystemTimeMicros {
+    /// Create a new `SystemTimeMicros` from a [`SystemTime`].
+    pub fn new(time: SystemTime) -> Self {
+        Self(time)
+    }
+
+    /// The point in time.
+    pub fn as_system_time(&self) -> SystemTime {
+        self.0
+    }
+}
+
+impl Type for SystemTimeMicros {
+    fn signature() -> Signature<'static> {
+        u64::signature()
+    }
+}
+
+impl From<SystemTime> for SystemTimeMicros {
+    fn from(time: SystemTime) -> Self {
+        Self(time)
+    }
+}
+
+impl From<SystemTimeMicros> for SystemTime {
+    fn from(value: SystemTimeMicros) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for SystemTimeMicros {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let since_epoch = self
+            .0
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| S::Error::custom("can't encode a time before the Unix epoch"))?;
+        let micros = u64::try_from(since_epoch.as_micros()).map_err(|_| {
+            S::Error::custom("time too far after the Unix epoch to encode in microseconds")
+        })?;
+
+        micros.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SystemTimeMicros {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let micros = u64::deserialize(deserializer)?;
+
+        UNIX_EPOCH
+            .checked_add(Duration::from_micros(micros))
+            .map(Self)
+            .ok_or_else(|| {
+                D::Error::invalid_value(
+                    Unexpected::Unsigned(micros),
+                    &"a timestamp in the range of `SystemTime`",
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{serialized::Context, to_bytes, Error, SystemTimeMicros, Type, BE, LE};
+
+    #[test]
+    fn system_time_micros() {
+        assert_eq!(SystemTimeMicros::signature(), "t");
+
+        // 2023-11-14 22:13:20.123456 UTC, as found in e.g systemd's `KernelTimestamp`.
+        let micros = 1_700_000_000_123_456u64;
+        let time = UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789);
+        let truncated = UNIX_EPOCH + Duration::from_micros(micros);
+        for ctxt in [
+            Context::new_dbus(LE, 0),
+            Context::new_dbus(BE, 0),
+            #[cfg(feature = "gvariant")]
+            Context::new_gvariant(LE, 0),
+        ] {
+            let encoded = to_bytes(ctxt, &SystemTimeMicros::new(time)).unwrap();
+            assert_eq!(encoded.bytes(), to_bytes(ctxt, &micros).unwrap().bytes());
+            let decoded: SystemTimeMicros = encoded.deserialize().unwrap().0;
+            // Nanoseconds are dropped.
+            assert_eq!(decoded.as_system_time(), truncated);
+        }
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &SystemTimeMicros::from(UNIX_EPOCH)).unwrap();
+        assert_eq!(encoded.bytes(), 0u64.to_le_bytes());
+
+        // Times before the epoch or too far after it can't be encoded.
+        assert!(matches!(
+            to_bytes(
+                ctxt,
+                &SystemTimeMicros::new(UNIX_EPOCH - Duration::from_secs(1))
+            ),
+            Err(Error::Message(_)),
+        ));
+        if let Some(far) = UNIX_EPOCH.checked_add(Duration::from_micros(u64::MAX) * 2) {
+            assert!(matches!(
+                to_bytes(ctxt, &SystemTimeMicros::new(far)),
+                Err(Error::Message(_)),
+            ));
+        }
+
+        // Whether the largest timestamp can be decoded depends on the range of `SystemTime`.
+        let encoded = to_bytes(ctxt, &u64::MAX).unwrap();
+        match UNIX_EPOCH.checked_add(Duration::from_micros(u64::MAX)) {
+            Some(max) => {
+                let decoded: SystemTimeMicros = encoded.deserialize().unwrap().0;
+                assert_eq!(decoded.as_system_time(), max);
+            }
+            None => assert!(matches!(
+                encoded.deserialize::<SystemTimeMicros>(),
+                Err(Error::Message(_)),
+            )),
+        }
+    }
+}
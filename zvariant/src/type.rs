@@ -1,8 +1,10 @@
-use crate::{utils::*, Signature};
+use crate::{utils::*, Basic, Signature};
 use serde::de::{Deserialize, DeserializeSeed};
 use std::{
+    ffi::{CStr, CString},
     marker::PhantomData,
     net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    ops::Bound,
     path::{Path, PathBuf},
     rc::Rc,
     sync::{Arc, Mutex, RwLock},
@@ -19,6 +21,13 @@ use std::{
 /// container types, such as, arrays, slices, tuples, [`Vec`] and [`HashMap`]. For easy
 /// implementation for custom types, use `Type` derive macro from [zvariant_derive] crate.
 ///
+/// # Ordering of maps and sets
+///
+/// D-Bus dictionaries are unordered, so the entries of a [`HashMap`] (and the elements of a
+/// `HashSet`) are encoded in their iteration order, which varies between instances. [`BTreeMap`]
+/// and [`BTreeSet`] are always encoded in the order of their keys though, so equal ones produce
+/// byte-identical output. Use them when reproducible encoding matters, e.g for tests or caching.
+///
 /// If your type's signature cannot be determined statically, you should implement the
 /// [DynamicType] trait instead, which is otherwise automatically implemented if you implement this
 /// trait.
@@ -30,6 +39,8 @@ use std::{
 /// [basic types]: trait.Basic.html
 /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
 /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: std::collections::BTreeMap
+/// [`BTreeSet`]: std::collections::BTreeSet
 /// [zvariant_derive]: https://docs.rs/zvariant_derive/latest/zvariant_derive/
 pub trait Type {
     /// Get the signature for the implementing type.
@@ -156,6 +167,8 @@ macro_rules! array_type {
 
 array_type!([T]);
 array_type!(Vec<T>);
+array_type!(std::collections::LinkedList<T>);
+array_type!(std::collections::BTreeSet<T>);
 
 impl<T, S> Type for std::collections::HashSet<T, S>
 where
@@ -168,6 +181,8 @@ where
     }
 }
 
+// Serialized just like a slice. On deserialization, the serde impl of `ArrayVec` itself errors out
+// if the array has more than `CAP` elements.
 #[cfg(feature = "arrayvec")]
 impl<T, const CAP: usize> Type for arrayvec::ArrayVec<T, CAP>
 where
@@ -187,6 +202,32 @@ impl<const CAP: usize> Type for arrayvec::ArrayString<CAP> {
     }
 }
 
+#[cfg(feature = "smallvec")]
+impl<A> Type for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+    A::Item: Type,
+{
+    #[inline]
+    fn signature() -> Signature<'static> {
+        <[A::Item]>::signature()
+    }
+}
+
+// Serialized as an array of the elements, in their insertion order. On deserialization, the serde
+// impl of `IndexSet` itself keeps the first occurrence of duplicate elements, at its position.
+#[cfg(feature = "indexmap")]
+impl<T, S> Type for indexmap::IndexSet<T, S>
+where
+    T: Type + Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    fn signature() -> Signature<'static> {
+        <[T]>::signature()
+    }
+}
+
 // Empty type deserves empty signature
 impl Type for () {
     #[inline]
@@ -211,12 +252,20 @@ macro_rules! deref_impl {
 
 deref_impl!(T, <T: ?Sized + Type> Type for &T);
 deref_impl!(T, <T: ?Sized + Type> Type for &mut T);
+// `Cow<'_, [u8]>` is encoded as `ay`, like `Vec<u8>`, and through the same per-element path: serde
+// serializes `[u8]` as a sequence. The bytes are only written all at once with
+// `#[serde(with = "serde_bytes")]`. Either way, deserialization yields the `Cow::Owned` variant.
 deref_impl!(T, <T: ?Sized + Type + ToOwned> Type for Cow<'_, T>);
 deref_impl!(T, <T: ?Sized + Type> Type for Arc<T>);
 deref_impl!(T, <T: ?Sized + Type> Type for Mutex<T>);
 deref_impl!(T, <T: ?Sized + Type> Type for RwLock<T>);
 deref_impl!(T, <T: ?Sized + Type> Type for Box<T>);
 deref_impl!(T, <T: ?Sized + Type> Type for Rc<T>);
+// Serialized as the value they point to, through the helpers in the `weak` module.
+deref_impl!(T, <T: ?Sized + Type> Type for std::rc::Weak<T>);
+deref_impl!(T, <T: ?Sized + Type> Type for std::sync::Weak<T>);
+// Not a smart pointer, but serde (de)serializes it as the wrapped value all the same.
+deref_impl!(T, <T: Type> Type for std::cmp::Reverse<T>);
 
 #[cfg(all(feature = "gvariant", not(feature = "option-as-array")))]
 impl<T> Type for Option<T>
@@ -339,6 +388,17 @@ impl Type for Duration {
     }
 }
 
+// Encoded as a `(yv)` structure of a tag (0 for `Unbounded`, 1 for `Included` and 2 for `Excluded`)
+// and the bound value, which our (de)serializers convert from and to its serde representation as an
+// enum. Since the serializer has to find out the signature of the bound value by itself, only basic
+// types are supported.
+impl<T: Basic> Type for Bound<T> {
+    #[inline]
+    fn signature() -> Signature<'static> {
+        Signature::from_static_str_unchecked("(yv)")
+    }
+}
+
 impl Type for SystemTime {
     #[inline]
     fn signature() -> Signature<'static> {
@@ -358,6 +418,8 @@ impl Type for Ipv4Addr {
     }
 }
 
+// Note that the scope id, needed for link-local addresses, isn't part of an `Ipv6Addr`. Use
+// `Ipv6WithScope` to keep it.
 impl Type for Ipv6Addr {
     #[inline]
     fn signature() -> Signature<'static> {
@@ -398,6 +460,28 @@ impl Type for serde_bytes::ByteBuf {
     }
 }
 
+// Unlike `[u8; N]`, which is encoded as a structure of `N` bytes, this is encoded as a byte array.
+#[cfg(feature = "serde_bytes")]
+impl<const N: usize> Type for serde_bytes::ByteArray<N> {
+    fn signature() -> Signature<'static> {
+        Signature::from_static_str_unchecked("ay")
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Type for bytes::Bytes {
+    fn signature() -> Signature<'static> {
+        Signature::from_static_str_unchecked("ay")
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Type for bytes::BytesMut {
+    fn signature() -> Signature<'static> {
+        Signature::from_static_str_unchecked("ay")
+    }
+}
+
 #[allow(unused)]
 macro_rules! static_str_type {
     ($ty:ty) => {
@@ -409,9 +493,32 @@ macro_rules! static_str_type {
     };
 }
 
+// Paths are encoded as strings, through their serde implementation, which fails for paths that
+// are not valid UTF-8. This also applies to `Cow<'_, Path>`, whose deserialization always yields
+// the `Cow::Owned` variant.
 static_str_type!(Path);
 static_str_type!(PathBuf);
 
+// C strings serialize as bytes, which our serializers encode as strings when the signature asks for
+// one. Serialization fails for strings that are not valid UTF-8 and deserialization of a `CString`
+// fails for strings with an interior nul byte.
+static_str_type!(CStr);
+static_str_type!(CString);
+
+// On Unix, OS strings are encoded as strings too, as our serializers encode their bytes as one when
+// the signature asks for it. Serialization fails for strings that are not valid UTF-8. Like for
+// paths, this also applies to `Cow<'_, OsStr>`, whose deserialization yields `Cow::Owned`.
+#[cfg(unix)]
+static_str_type!(std::ffi::OsStr);
+#[cfg(unix)]
+static_str_type!(std::ffi::OsString);
+
+// Unlike the std paths, these are guaranteed to be valid UTF-8, so their serialization can't fail.
+#[cfg(feature = "camino")]
+static_str_type!(camino::Utf8Path);
+#[cfg(feature = "camino")]
+static_str_type!(camino::Utf8PathBuf);
+
 #[cfg(feature = "uuid")]
 impl Type for uuid::Uuid {
     fn signature() -> Signature<'static> {
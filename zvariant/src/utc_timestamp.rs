@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use serde::{
+    de::{Deserializer, Error as _, Unexpected},
+    ser::Serializer,
+    Deserialize, Serialize,
+};
+
+use crate::{Signature, Type};
+
+/// A point in time in UTC, encoded as a `(xu)` structure of seconds and nanoseconds.
+///
+/// [`chrono::DateTime`] itself implements [`Type`] as a string, since its serde implementation
+/// encodes it as an RFC 3339 string. Some D-Bus APIs instead use a count of seconds since the Unix
+/// epoch, and this type can be used for such APIs. It encodes the whole seconds since the epoch as
+/// an `x` (`i64`), followed by the nanoseconds within the second as a `u` (`u32`), so no precision
+/// is lost.
+///
+/// Deserialization fails if the encoded values are out of the range of [`DateTime<Utc>`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use zvariant::{serialized::Context, to_bytes, Type, UtcTimestamp, LE};
+///
+/// assert_eq!(UtcTimestamp::signature(), "(xu)");
+///
+/// let time = DateTime::<Utc>::from_timestamp(1_700_000_000, 500).unwrap();
+/// let ctxt = Context::new_dbus(LE, 0);
+/// let encoded = to_bytes(ctxt, &UtcTimestamp::from(time)).unwrap();
+/// assert_eq!(encoded.len(), 12);
+/// let decoded: UtcTimestamp = encoded.deserialize().unwrap().0;
+/// assert_eq!(DateTime::<Utc>::from(decoded), time);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcTimestamp(DateTime<Utc>);
+
+impl UtcTimestamp {
+    /// Create a new `UtcTimestamp` from a [`DateTime<Utc>`].
+    pub fn new(time: DateTime<Utc>) -> Self {
+        Self(time)
+    }
+
+    /// The point in time.
+    pub fn as_date_time(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
+
+impl Type for UtcTimestamp {
+    fn signature() -> Signature<'static> {
+        <(i64, u32)>::signature()
+    }
+}
+
+impl Serialize for UtcTimestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (self.0.timestamp(), self.0.timestamp_subsec_nanos()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UtcTimestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (secs, nanos) = <(i64, u32)>::deserialize(deserializer)?;
+
+        DateTime::from_timestamp(secs, nanos)
+            .map(Self)
+            .ok_or_else(|| {
+                D::Error::invalid_value(
+                    Unexpected::Signed(secs),
+                    &"a timestamp in the range of `DateTime<Utc>`",
+                )
+            })
+    }
+}
+
+impl From<DateTime<Utc>> for UtcTimestamp {
+    fn from(time: DateTime<Utc>) -> Self {
+        Self(time)
+    }
+}
+
+impl From<UtcTimestamp> for DateTime<Utc> {
+    fn from(value: UtcTimestamp) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use crate::{serialized::Context, to_bytes, Type, UtcTimestamp, LE};
+
+    fn round_trip(secs: i64, nanos: u32) {
+        let time = DateTime::<Utc>::from_timestamp(secs, nanos).unwrap();
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &UtcTimestamp::from(time)).unwrap();
+        let mut expected = secs.to_le_bytes().to_vec();
+        expected.extend_from_slice(&nanos.to_le_bytes());
+        assert_eq!(encoded.bytes(), expected);
+        let (decoded, parsed): (UtcTimestamp, _) = encoded.deserialize().unwrap();
+        assert_eq!(parsed, 12);
+        assert_eq!(DateTime::<Utc>::from(decoded), time);
+
+        #[cfg(feature = "gvariant")]
+        {
+            let ctxt = Context::new_gvariant(LE, 0);
+            let encoded = to_bytes(ctxt, &UtcTimestamp::from(time)).unwrap();
+            let decoded: UtcTimestamp = encoded.deserialize().unwrap().0;
+            assert_eq!(*decoded.as_date_time(), time);
+        }
+    }
+
+    #[test]
+    fn utc_timestamp() {
+        assert_eq!(UtcTimestamp::signature(), "(xu)");
+
+        // The epoch.
+        round_trip(0, 0);
+        // 2023-11-14T22:13:20Z.
+        round_trip(1_700_000_000, 0);
+        // Sub-second precision.
+        round_trip(1_700_000_000, 123_456_789);
+        // Before the epoch.
+        round_trip(-1, 999_999_999);
+
+        // Out of range values.
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &(i64::MAX, 0u32)).unwrap();
+        encoded.deserialize::<UtcTimestamp>().unwrap_err();
+        let encoded = to_bytes(ctxt, &(0i64, 2_000_000_000u32)).unwrap();
+        encoded.deserialize::<UtcTimestamp>().unwrap_err();
+    }
+}
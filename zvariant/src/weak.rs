@@ -0,0 +1,87 @@
+//! Serialization of weak references.
+//!
+//! A [`std::rc::Weak`] or [`std::sync::Weak`] has the same [`Type`](crate::Type) as the value it
+//! points to, and is serialized as that value after upgrading it. Since [`Serialize`] can't be
+//! implemented for them here, the functions in this module are meant to be used through serde's
+//! `serialize_with` attribute. Serializing a dangling weak reference, i-e one that can no longer be
+//! upgraded, is an error.
+//!
+//! Deserialization isn't supported, as there is nothing a new weak reference could point to.
+//!
+//! Note that serde's own implementation, available through its `rc` feature, serializes weak
+//! references as an `Option`, which doesn't match their [`Type`](crate::Type).
+//!
+//! # Examples
+//!
+//! ```
+//! use std::rc::{Rc, Weak};
+//!
+//! use serde::Serialize;
+//! use zvariant::{serialized::Context, to_bytes, Type, LE};
+//!
+//! #[derive(Serialize, Type)]
+//! struct Child {
+//!     name: String,
+//!     #[serde(serialize_with = "zvariant::weak::rc::serialize")]
+//!     parent: Weak<String>,
+//! }
+//!
+//! let parent = Rc::new(String::from("parent"));
+//! let child = Child {
+//!     name: "child".into(),
+//!     parent: Rc::downgrade(&parent),
+//! };
+//! assert_eq!(Child::signature(), "(ss)");
+//!
+//! let ctxt = Context::new_dbus(LE, 0);
+//! let encoded = to_bytes(ctxt, &child).unwrap();
+//! let decoded: (String, String) = encoded.deserialize().unwrap().0;
+//! assert_eq!(decoded, ("child".to_string(), "parent".to_string()));
+//!
+//! drop(parent);
+//! assert!(to_bytes(ctxt, &child).is_err());
+//! ```
+
+use serde::{ser::Error, Serialize, Serializer};
+
+fn dangling<E: Error>() -> E {
+    E::custom("can't serialize a dangling weak reference")
+}
+
+/// Serialization of [`std::rc::Weak`].
+pub mod rc {
+    use std::rc::Weak;
+
+    use super::*;
+
+    /// Serialize the value `weak` points to, or fail if it's dangling.
+    pub fn serialize<T, S>(weak: &Weak<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + Serialize,
+        S: Serializer,
+    {
+        match weak.upgrade() {
+            Some(value) => T::serialize(&value, serializer),
+            None => Err(dangling()),
+        }
+    }
+}
+
+/// Serialization of [`std::sync::Weak`].
+pub mod sync {
+    use std::sync::Weak;
+
+    use super::*;
+
+    /// Serialize the value `weak` points to, or fail if it's dangling.
+    pub fn serialize<T, S>(weak: &Weak<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + Serialize,
+        S: Serializer,
+    {
+        match weak.upgrade() {
+            Some(value) => T::serialize(&value, serializer),
+            None => Err(dangling()),
+        }
+    }
+}
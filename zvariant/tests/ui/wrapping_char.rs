@@ -0,0 +1,13 @@
+use std::num::Wrapping;
+
+use zvariant::Type;
+
+fn signature<T: Type>() -> String {
+    T::signature().to_string()
+}
+
+fn main() {
+    // Only integers can be wrapped.
+    signature::<Wrapping<u32>>();
+    signature::<Wrapping<char>>();
+}
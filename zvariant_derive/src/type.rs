@@ -165,12 +165,20 @@ fn impl_enum(
     data: DataEnum,
     zv: &TokenStream,
 ) -> Result<TokenStream, Error> {
+    // Variants that can't be constructed are never (de)serialized, so they don't contribute to
+    // the signature.
     let mut all_signatures: Vec<Result<TokenStream, Error>> = data
         .variants
         .iter()
+        .filter(|variant| !is_uninhabited(variant))
         .map(|variant| signature_for_variant(variant, &attrs, zv))
         .collect();
-    let signature = all_signatures.pop().unwrap()?;
+    let signature = all_signatures.pop().ok_or_else(|| {
+        Error::new(
+            name.span(),
+            "enums must have at least one constructible variant",
+        )
+    })??;
     // Ensure all variants of the enum have the same number and type of fields.
     for sig in all_signatures {
         if sig?.to_string() != signature.to_string() {
@@ -193,6 +201,19 @@ fn impl_enum(
     })
 }
 
+fn is_uninhabited(variant: &syn::Variant) -> bool {
+    variant.fields.iter().any(|field| match &field.ty {
+        syn::Type::Never(_) => true,
+        syn::Type::Path(ty) => {
+            ty.qself.is_none()
+                && ty.path.segments.last().is_some_and(|segment| {
+                    segment.ident == "Infallible" && segment.arguments.is_none()
+                })
+        }
+        _ => false,
+    })
+}
+
 fn signature_for_variant(
     variant: &syn::Variant,
     attrs: &[Attribute],
@@ -39,6 +39,29 @@ fn derive_enum() {
     assert_eq!(RequestNameFlags::signature(), "u")
 }
 
+#[test]
+fn derive_enum_uninhabited_variant() {
+    use std::convert::Infallible;
+
+    // Variants that can't be constructed don't affect the signature.
+    #[allow(dead_code)]
+    #[derive(Type)]
+    enum Reply {
+        Success(u32, String),
+        Never(Infallible),
+        Failure(u32, String),
+    }
+    assert_eq!(Reply::signature(), "(u(us))");
+
+    #[allow(dead_code)]
+    #[derive(Type)]
+    enum Status {
+        Unreachable(std::convert::Infallible),
+        Code(u8),
+    }
+    assert_eq!(Status::signature(), "(uy)");
+}
+
 #[test]
 fn derive_dict() {
     #[derive(SerializeDict, DeserializeDict, Type)]